@@ -0,0 +1,62 @@
+//! Borrowing owned values as their borrowed counterparts.
+
+use crate::types::{borrowed, owned};
+
+/// Borrows an owned value as its borrowed counterpart `B` without copying.
+///
+/// Implemented for owned types deriving `FromBorrowed`, which expose it through their `as_borrowed` method.
+pub(crate) trait ToBorrowed<'a, B> {
+    /// Returns `None` if a list holds more elements than the borrowed type's capacity.
+    fn to_borrowed(&'a self) -> Option<B>;
+}
+
+/// Values shared by owned and borrowed types, e.g. [`Ton`](crate::values::Ton), are cloned.
+impl<'a, T: Clone> ToBorrowed<'a, T> for T {
+    fn to_borrowed(&'a self) -> Option<T> {
+        Some(self.clone())
+    }
+}
+
+impl<'a, O, B, const N: usize> ToBorrowed<'a, heapless::vec::Vec<B, N>> for alloc::vec::Vec<O>
+where
+    O: ToBorrowed<'a, B>,
+{
+    fn to_borrowed(&'a self) -> Option<heapless::vec::Vec<B, N>> {
+        self.iter()
+            .try_fold(heapless::vec::Vec::new(), |mut vec, value| {
+                vec.push(value.to_borrowed()?).ok()?;
+
+                Some(vec)
+            })
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize> ToBorrowed<'a, borrowed::COctetString<'a, MIN, MAX>>
+    for owned::COctetString<MIN, MAX>
+{
+    fn to_borrowed(&'a self) -> Option<borrowed::COctetString<'a, MIN, MAX>> {
+        Some(self.as_borrowed())
+    }
+}
+
+impl<'a, const N: usize> ToBorrowed<'a, borrowed::EmptyOrFullCOctetString<'a, N>>
+    for owned::EmptyOrFullCOctetString<N>
+{
+    fn to_borrowed(&'a self) -> Option<borrowed::EmptyOrFullCOctetString<'a, N>> {
+        Some(self.as_borrowed())
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize> ToBorrowed<'a, borrowed::OctetString<'a, MIN, MAX>>
+    for owned::OctetString<MIN, MAX>
+{
+    fn to_borrowed(&'a self) -> Option<borrowed::OctetString<'a, MIN, MAX>> {
+        Some(self.as_borrowed())
+    }
+}
+
+impl<'a> ToBorrowed<'a, borrowed::AnyOctetString<'a>> for owned::AnyOctetString {
+    fn to_borrowed(&'a self) -> Option<borrowed::AnyOctetString<'a>> {
+        Some(self.as_borrowed())
+    }
+}
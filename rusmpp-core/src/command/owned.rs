@@ -1,6 +1,10 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
-use crate::{CommandId, CommandStatus, pdus::owned::Pdu};
+use crate::{
+    CommandId, CommandStatus,
+    pdus::owned::Pdu,
+    validate::{Validate, Violation},
+};
 
 /// `SMPP` command.
 ///
@@ -35,7 +39,8 @@ use crate::{CommandId, CommandStatus, pdus::owned::Pdu};
 /// | 01                            | addr_ton (0x01) |
 /// | 01                            | addr_npi (0x01) |
 /// | 00                            | addr_range (NULL) |
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::command::borrowed::Command<'a, N>, parts = crate::command::borrowed::CommandParts)]
 #[rusmpp(decode = owned)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -67,6 +72,15 @@ impl Default for Command {
     }
 }
 
+impl Validate for Command {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        self.pdu
+            .as_ref()
+            .map(Validate::validate)
+            .unwrap_or_default()
+    }
+}
+
 impl Command {
     pub fn new(status: CommandStatus, sequence_number: u32, pdu: impl Into<Pdu>) -> Self {
         Self::new_const(status, sequence_number, pdu.into())
@@ -167,4 +181,55 @@ mod tests {
     fn encode_decode() {
         crate::tests::owned::encode_decode_with_length_test_instances::<Command>();
     }
+
+    #[test]
+    fn from_borrowed() {
+        use crate::{
+            command::borrowed,
+            decode::borrowed::DecodeWithLength,
+            encode::{Length, owned::Encode},
+        };
+
+        for original in crate::tests::owned::test_commands() {
+            let mut buf = bytes::BytesMut::new();
+
+            original.encode(&mut buf);
+
+            let (decoded, _size) = borrowed::Command::<'_, 16>::decode(&buf, original.length())
+                .expect("Failed to decode");
+
+            assert_eq!(decoded.into_owned(), original);
+        }
+    }
+
+    #[test]
+    fn as_borrowed() {
+        for original in crate::tests::owned::test_commands() {
+            let borrowed = original.as_borrowed::<16>().expect("Failed to borrow");
+
+            assert_eq!(borrowed.into_owned(), original);
+        }
+    }
+
+    #[test]
+    fn as_borrowed_too_many_elements() {
+        use crate::{pdus::owned::SubmitSm, tlvs::owned::MessageSubmissionRequestTlvValue};
+
+        let command = Command::builder()
+            .status(CommandStatus::EsmeRok)
+            .sequence_number(1)
+            .pdu(
+                SubmitSm::builder()
+                    .tlvs(alloc::vec![
+                        MessageSubmissionRequestTlvValue::UserMessageReference(
+                            Default::default()
+                        );
+                        3
+                    ])
+                    .build(),
+            );
+
+        assert!(command.as_borrowed::<3>().is_some());
+        assert!(command.as_borrowed::<2>().is_none());
+    }
 }
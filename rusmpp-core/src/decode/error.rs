@@ -1,4 +1,4 @@
-use crate::fields::SmppField;
+use crate::{CommandStatus, fields::SmppField};
 
 /// An error that can occur when decoding `SMPP` values.
 #[derive(Debug)]
@@ -47,6 +47,19 @@ impl DecodeError {
         self.kind
     }
 
+    /// Returns the status of the `generic_nack` answering a command whose body failed to decode.
+    ///
+    /// Truncated bodies are answered with [`EsmeRinvcmdlen`](CommandStatus::EsmeRinvcmdlen),
+    /// other malformed bodies with [`EsmeRsyserr`](CommandStatus::EsmeRsyserr).
+    pub const fn generic_nack_status(&self) -> CommandStatus {
+        match self.kind {
+            DecodeErrorKind::UnexpectedEof | DecodeErrorKind::TooManyElements { .. } => {
+                CommandStatus::EsmeRinvcmdlen
+            }
+            _ => CommandStatus::EsmeRsyserr,
+        }
+    }
+
     #[inline]
     pub const fn unexpected_eof() -> Self {
         Self::new(DecodeErrorKind::UnexpectedEof)
@@ -46,6 +46,13 @@ pub mod types;
 
 pub mod tlvs;
 
+#[cfg(feature = "alloc")]
+pub(crate) mod borrow;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod validate;
+
 #[cfg(test)]
 pub(crate) mod tests;
 
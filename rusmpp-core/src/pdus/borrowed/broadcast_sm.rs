@@ -83,7 +83,7 @@ pub struct BroadcastSm<'a, const N: usize> {
     /// Broadcast request TLVs ([`BroadcastRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> BroadcastSm<'a, N> {
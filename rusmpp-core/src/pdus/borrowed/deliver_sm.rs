@@ -85,7 +85,7 @@ pub struct DeliverSm<'a, const N: usize> {
     /// Message delivery request TLVs ([`MessageDeliveryRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> DeliverSm<'a, N> {
@@ -313,7 +313,7 @@ impl<'a, const N: usize> DeliverSmBuilder<'a, N> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{tests::TestInstance, types::borrowed::AnyOctetString};
+    use crate::{tests::TestInstance, tlvs::TlvTag, types::borrowed::AnyOctetString};
 
     use super::*;
 
@@ -388,4 +388,35 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn tlv_accessors() {
+        let mut deliver_sm = DeliverSm::<'static, 2>::default();
+
+        assert_eq!(deliver_sm.receipted_message_id(), None);
+
+        deliver_sm
+            .set_receipted_message_id(COctetString::new(b"1\0").unwrap())
+            .unwrap();
+        deliver_sm
+            .set_receipted_message_id(COctetString::new(b"2\0").unwrap())
+            .unwrap();
+        deliver_sm
+            .set_user_message_reference(UserMessageReference::new(1))
+            .unwrap();
+
+        assert_eq!(
+            deliver_sm.receipted_message_id(),
+            Some(&COctetString::new(b"2\0").unwrap())
+        );
+        assert_eq!(deliver_sm.tlvs().len(), 2);
+
+        assert!(deliver_sm.set_source_port(1).is_err());
+
+        deliver_sm.remove_tlv(TlvTag::ReceiptedMessageId);
+
+        assert_eq!(deliver_sm.receipted_message_id(), None);
+        assert!(deliver_sm.set_source_port(1).is_ok());
+        assert_eq!(deliver_sm.source_port(), Some(&1));
+    }
 }
@@ -19,7 +19,7 @@ pub struct QueryBroadcastSmResp<'a, const N: usize> {
     /// Query broadcast response TLVs ([`QueryBroadcastResponseTlvValue`]).
     #[rusmpp(length = "unchecked")]
     #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-    tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+    pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
 }
 
 impl<'a, const N: usize> QueryBroadcastSmResp<'a, N> {
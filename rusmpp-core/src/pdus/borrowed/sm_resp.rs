@@ -21,7 +21,7 @@ macro_rules! sm_resp {
             /// Message delivery response TLVs ([`MessageDeliveryResponseTlvValue`])
             #[rusmpp(length = "unchecked")]
             #[cfg_attr(feature = "arbitrary", arbitrary(default))]
-            tlvs: heapless::vec::Vec<Tlv<'a>, N>,
+            pub(crate) tlvs: heapless::vec::Vec<Tlv<'a>, N>,
         }
 
         impl<'a, const N: usize> $name<'a, N> {
@@ -1,9 +1,12 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
+    tlvs::TlvTag,
     tlvs::owned::{Tlv, TlvValue},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::*,
 };
 
@@ -16,7 +19,8 @@ use crate::{
 /// Proxy Server.
 ///
 /// Note: There is no associated alert_notification_resp PDU.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::AlertNotification<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -97,6 +101,36 @@ impl From<AlertNotification> for Pdu {
     }
 }
 
+impl Validate for AlertNotification {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_address(
+            &mut violations,
+            SmppField::esme_addr,
+            self.esme_addr_ton,
+            self.esme_addr_npi,
+            self.esme_addr.as_str(),
+        );
+
+        validate::check_tlv(
+            &mut violations,
+            self.ms_availability_status.as_ref(),
+            TlvTag::MsAvailabilityStatus,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct AlertNotificationBuilder {
     inner: AlertNotification,
@@ -1,10 +1,16 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
+use crate::{
+    pdus::owned::Pdu,
+    types::owned::COctetString,
+    validate::{Validate, Violation},
+    values::*,
+};
 
 macro_rules! bind {
     ($name:ident) => {
-        #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+        #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+        #[from_borrowed(crate::pdus::borrowed::$name<'a>)]
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -68,6 +74,12 @@ macro_rules! bind {
             }
         }
 
+        impl Validate for $name {
+            fn validate(&self) -> alloc::vec::Vec<Violation> {
+                alloc::vec::Vec::new()
+            }
+        }
+
         ::pastey::paste! {
             #[derive(Debug, Default)]
             pub struct [<$name Builder>] {
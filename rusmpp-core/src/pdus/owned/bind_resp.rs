@@ -1,15 +1,20 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     pdus::owned::Pdu,
+    tlvs::TlvTag,
     tlvs::owned::{Tlv, TlvValue},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::*,
 };
 
 macro_rules! bind_resp {
     ($name:ident) => {
-        #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+        #[derive(
+            Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed,
+        )]
+        #[from_borrowed(crate::pdus::borrowed::$name<'a>)]
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -66,6 +71,20 @@ macro_rules! bind_resp {
             }
         }
 
+        impl Validate for $name {
+            fn validate(&self) -> alloc::vec::Vec<Violation> {
+                let mut violations = alloc::vec::Vec::new();
+
+                validate::check_tlv(
+                    &mut violations,
+                    self.sc_interface_version.as_ref(),
+                    TlvTag::ScInterfaceVersion,
+                );
+
+                violations
+            }
+        }
+
         ::pastey::paste! {
             #[derive(Debug, Default)]
             pub struct [<$name Builder>] {
@@ -1,15 +1,18 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{BroadcastRequestTlvValue, Tlv},
     types::owned::{COctetString, EmptyOrFullCOctetString},
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 
 /// This operation is issued by the ESME to submit a message to the Message Centre for
 /// broadcast to a specified geographical area or set of geographical areas.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::BroadcastSm<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -83,7 +86,7 @@ pub struct BroadcastSm {
     pub sm_default_msg_id: u8,
     /// Broadcast request TLVs ([`BroadcastRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl BroadcastSm {
@@ -147,6 +150,28 @@ impl From<BroadcastSm> for Pdu {
     }
 }
 
+impl Validate for BroadcastSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            BroadcastRequestTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct BroadcastSmBuilder {
     inner: BroadcastSm,
@@ -200,6 +225,21 @@ impl BroadcastSmBuilder {
         self
     }
 
+    /// Sets the `schedule_delivery_time` from an [`SmppTime`].
+    pub fn schedule_delivery_smpp_time(
+        mut self,
+        schedule_delivery_time: impl Into<SmppTime>,
+    ) -> Self {
+        self.inner.schedule_delivery_time = schedule_delivery_time.into().into();
+        self
+    }
+
+    /// Sets the `validity_period` from an [`SmppTime`].
+    pub fn validity_period_smpp_time(mut self, validity_period: impl Into<SmppTime>) -> Self {
+        self.inner.validity_period = validity_period.into().into();
+        self
+    }
+
     pub fn replace_if_present_flag(
         mut self,
         replace_if_present_flag: ReplaceIfPresentFlag,
@@ -1,9 +1,11 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{CancelBroadcastTlvValue, Tlv},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 /// This command is issued by the ESME to cancel a broadcast message which has been
@@ -23,7 +25,8 @@ use crate::{
 ///
 /// Where the original broadcast_sm ‘source address’ was defaulted to NULL, then the source
 /// address in the cancel_broadcast_sm command should also be NULL.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::CancelBroadcastSm<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -62,7 +65,7 @@ pub struct CancelBroadcastSm {
     pub source_addr: COctetString<1, 21>,
     /// Cancel broadcast  TLVs ([`CancelBroadcastTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl CancelBroadcastSm {
@@ -113,6 +116,28 @@ impl From<CancelBroadcastSm> for Pdu {
     }
 }
 
+impl Validate for CancelBroadcastSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            CancelBroadcastTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CancelBroadcastSmBuilder {
     inner: CancelBroadcastSm,
@@ -1,8 +1,10 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 
@@ -18,7 +20,8 @@ use crate::{
 /// destination addresses (and service_type if specified) are cancelled.
 /// Where the original submit_sm, data_sm or submit_multi ‘source address’ is defaulted to
 /// NULL, then the source address in the cancel_sm command should also be NULL.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::CancelSm<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -132,6 +135,30 @@ impl From<CancelSm> for Pdu {
     }
 }
 
+impl Validate for CancelSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_address(
+            &mut violations,
+            SmppField::destination_addr,
+            self.dest_addr_ton,
+            self.dest_addr_npi,
+            self.destination_addr.as_str(),
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct CancelSmBuilder {
     inner: CancelSm,
@@ -1,16 +1,19 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 /// The data_sm operation is similar to the submit_sm in that it provides a means to submit a
 /// mobile-terminated message. However, data_sm is intended for packet-based applications
 /// such as WAP in that it features a reduced PDU body containing fields relevant to WAP or
 /// packet-based applications.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::DataSm<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -63,7 +66,7 @@ pub struct DataSm {
     pub data_coding: DataCoding,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl DataSm {
@@ -125,6 +128,46 @@ impl From<DataSm> for Pdu {
     }
 }
 
+impl Validate for DataSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_address(
+            &mut violations,
+            SmppField::destination_addr,
+            self.dest_addr_ton,
+            self.dest_addr_npi,
+            self.destination_addr.as_str(),
+        );
+
+        validate::check_user_data(
+            &mut violations,
+            self.esm_class,
+            self.data_coding,
+            &[],
+            validate::message_payload(&self.tlvs),
+        );
+
+        validate::check_receipt(&mut violations, self.esm_class, self.registered_delivery);
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            MessageSubmissionRequestTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DataSmBuilder {
     inner: DataSm,
@@ -1,16 +1,19 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     encode::Length,
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{MessageDeliveryRequestTlvValue, Tlv},
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 
 /// This operation is used by an ESME to submit a short message to the MC for onward
 /// transmission to a specified short message entity (SME).
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::DeliverSm<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -85,7 +88,7 @@ pub struct DeliverSm {
     short_message: OctetString<0, 255>,
     /// Message delivery request TLVs ([`MessageDeliveryRequestTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl DeliverSm {
@@ -183,6 +186,53 @@ impl From<DeliverSm> for Pdu {
     }
 }
 
+impl Validate for DeliverSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_address(
+            &mut violations,
+            SmppField::destination_addr,
+            self.dest_addr_ton,
+            self.dest_addr_npi,
+            self.destination_addr.as_str(),
+        );
+
+        validate::check_length(
+            &mut violations,
+            SmppField::sm_length,
+            self.sm_length as usize,
+            self.short_message.len(),
+        );
+
+        validate::check_user_data(
+            &mut violations,
+            self.esm_class,
+            self.data_coding,
+            &self.short_message,
+            validate::message_payload(&self.tlvs),
+        );
+
+        validate::check_receipt(&mut violations, self.esm_class, self.registered_delivery);
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            MessageDeliveryRequestTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DeliverSmBuilder {
     inner: DeliverSm,
@@ -256,6 +306,21 @@ impl DeliverSmBuilder {
         self
     }
 
+    /// Sets the `schedule_delivery_time` from an [`SmppTime`].
+    pub fn schedule_delivery_smpp_time(
+        mut self,
+        schedule_delivery_time: impl Into<SmppTime>,
+    ) -> Self {
+        self.inner.schedule_delivery_time = schedule_delivery_time.into().into();
+        self
+    }
+
+    /// Sets the `validity_period` from an [`SmppTime`].
+    pub fn validity_period_smpp_time(mut self, validity_period: impl Into<SmppTime>) -> Self {
+        self.inner.validity_period = validity_period.into().into();
+        self
+    }
+
     pub fn registered_delivery(mut self, registered_delivery: RegisteredDelivery) -> Self {
         self.inner.registered_delivery = registered_delivery;
         self
@@ -308,7 +373,7 @@ impl DeliverSmBuilder {
 mod tests {
     use std::str::FromStr;
 
-    use crate::{tests::TestInstance, types::owned::AnyOctetString};
+    use crate::{tests::TestInstance, tlvs::TlvTag, types::owned::AnyOctetString};
 
     use super::*;
 
@@ -379,4 +444,60 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn validate() {
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass {
+                message_type: MessageType::ShortMessageContainsMCDeliveryReceipt,
+                ..Default::default()
+            })
+            .short_message(OctetString::from_static_slice(b"id:1 stat:DELIVRD").unwrap())
+            .build();
+
+        assert!(deliver_sm.is_valid());
+
+        let deliver_sm = DeliverSm {
+            registered_delivery: RegisteredDelivery::request_all(),
+            ..deliver_sm
+        };
+
+        assert_eq!(
+            deliver_sm.validate(),
+            [Violation::RegisteredDeliveryOnReceipt {
+                message_type: MessageType::ShortMessageContainsMCDeliveryReceipt,
+            }]
+        );
+    }
+
+    #[test]
+    fn tlv_accessors() {
+        let mut deliver_sm = DeliverSm::default();
+
+        assert_eq!(deliver_sm.receipted_message_id(), None);
+
+        deliver_sm.push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+            COctetString::from_str("1").unwrap(),
+        ));
+        deliver_sm.push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+            COctetString::from_str("2").unwrap(),
+        ));
+        deliver_sm.set_user_message_reference(UserMessageReference::new(1));
+        deliver_sm.set_receipted_message_id(COctetString::from_str("3").unwrap());
+
+        assert_eq!(
+            deliver_sm.receipted_message_id(),
+            Some(&COctetString::from_str("3").unwrap())
+        );
+        assert_eq!(
+            deliver_sm.user_message_reference(),
+            Some(&UserMessageReference::new(1))
+        );
+        assert_eq!(deliver_sm.tlvs().len(), 2);
+
+        deliver_sm.remove_tlv(TlvTag::ReceiptedMessageId);
+
+        assert_eq!(deliver_sm.receipted_message_id(), None);
+        assert_eq!(deliver_sm.tlvs().len(), 1);
+    }
 }
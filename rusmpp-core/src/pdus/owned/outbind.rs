@@ -1,6 +1,10 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString};
+use crate::{
+    pdus::owned::Pdu,
+    types::owned::COctetString,
+    validate::{Validate, Violation},
+};
 
 /// Authentication PDU used by a Message Centre to Outbind to
 /// an ESME to inform it that messages are present in the MC.
@@ -8,7 +12,8 @@ use crate::{pdus::owned::Pdu, types::owned::COctetString};
 /// ESME. If the ESME authenticates the request, it will respond
 /// with a bind_receiver or bind_transceiver to begin the process
 /// of binding into the MC.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::Outbind<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -44,6 +49,12 @@ impl From<Outbind> for Pdu {
     }
 }
 
+impl Validate for Outbind {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        alloc::vec::Vec::new()
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct OutbindBuilder {
     inner: Outbind,
@@ -1,4 +1,5 @@
 use bytes::BytesMut;
+use rusmpp_macros::FromBorrowed;
 
 use crate::{
     CommandId,
@@ -8,12 +9,14 @@ use crate::{
     },
     encode::Length,
     types::owned::AnyOctetString,
+    validate::{Validate, Violation},
 };
 
 use super::*;
 
 /// `SMPP` PDU.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::Pdu<'a, N>)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
@@ -269,6 +272,47 @@ impl Length for Pdu {
     }
 }
 
+impl Validate for Pdu {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        match self {
+            Pdu::BindTransmitter(body) => body.validate(),
+            Pdu::BindTransmitterResp(body) => body.validate(),
+            Pdu::BindReceiver(body) => body.validate(),
+            Pdu::BindReceiverResp(body) => body.validate(),
+            Pdu::BindTransceiver(body) => body.validate(),
+            Pdu::BindTransceiverResp(body) => body.validate(),
+            Pdu::Outbind(body) => body.validate(),
+            Pdu::AlertNotification(body) => body.validate(),
+            Pdu::SubmitSm(body) => body.validate(),
+            Pdu::SubmitSmResp(body) => body.validate(),
+            Pdu::QuerySm(body) => body.validate(),
+            Pdu::QuerySmResp(body) => body.validate(),
+            Pdu::DeliverSm(body) => body.validate(),
+            Pdu::DeliverSmResp(body) => body.validate(),
+            Pdu::DataSm(body) => body.validate(),
+            Pdu::DataSmResp(body) => body.validate(),
+            Pdu::CancelSm(body) => body.validate(),
+            Pdu::ReplaceSm(body) => body.validate(),
+            Pdu::SubmitMulti(body) => body.validate(),
+            Pdu::SubmitMultiResp(body) => body.validate(),
+            Pdu::BroadcastSm(body) => body.validate(),
+            Pdu::BroadcastSmResp(body) => body.validate(),
+            Pdu::QueryBroadcastSm(body) => body.validate(),
+            Pdu::QueryBroadcastSmResp(body) => body.validate(),
+            Pdu::CancelBroadcastSm(body) => body.validate(),
+            Pdu::Unbind
+            | Pdu::UnbindResp
+            | Pdu::EnquireLink
+            | Pdu::EnquireLinkResp
+            | Pdu::GenericNack
+            | Pdu::CancelSmResp
+            | Pdu::ReplaceSmResp
+            | Pdu::CancelBroadcastSmResp
+            | Pdu::Other { .. } => alloc::vec::Vec::new(),
+        }
+    }
+}
+
 impl crate::encode::Encode for Pdu {
     fn encode(&self, dst: &mut [u8]) -> usize {
         match self {
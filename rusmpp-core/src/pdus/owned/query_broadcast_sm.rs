@@ -1,9 +1,12 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
+    tlvs::TlvTag,
     tlvs::owned::{Tlv, TlvValue},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::*,
 };
 
@@ -20,7 +23,8 @@ use crate::{
 /// user_message_reference value is present in the Message Center, the details of the most
 /// recently submitted message with the specified user_message_reference value will be
 /// returned in the query_broadcast_sm_resp.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::QueryBroadcastSm<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -105,6 +109,28 @@ impl From<QueryBroadcastSm> for Pdu {
     }
 }
 
+impl Validate for QueryBroadcastSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_tlv(
+            &mut violations,
+            self.user_message_reference.as_ref(),
+            TlvTag::UserMessageReference,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QueryBroadcastSmBuilder {
     inner: QueryBroadcastSm,
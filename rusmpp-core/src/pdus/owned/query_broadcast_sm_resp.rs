@@ -1,12 +1,14 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     pdus::owned::Pdu,
     tlvs::owned::{QueryBroadcastResponseTlvValue, Tlv},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::QueryBroadcastSmResp<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -19,7 +21,7 @@ pub struct QueryBroadcastSmResp {
     pub message_id: COctetString<1, 65>,
     /// Query broadcast response TLVs ([`QueryBroadcastResponseTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl QueryBroadcastSmResp {
@@ -59,6 +61,20 @@ impl From<QueryBroadcastSmResp> for Pdu {
     }
 }
 
+impl Validate for QueryBroadcastSmResp {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            QueryBroadcastResponseTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QueryBroadcastSmRespBuilder {
     inner: QueryBroadcastSmResp,
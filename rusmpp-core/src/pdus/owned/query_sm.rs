@@ -1,13 +1,20 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
-use crate::{pdus::owned::Pdu, types::owned::COctetString, values::*};
+use crate::{
+    fields::SmppField,
+    pdus::owned::Pdu,
+    types::owned::COctetString,
+    validate::{self, Validate, Violation},
+    values::*,
+};
 
 /// This command is issued by the ESME to query the status of a previously submitted short
 /// message.
 /// The matching mechanism is based on the MC assigned message_id and source address.
 /// Where the original submit_sm, data_sm or submit_multi ‘source address’ was defaulted to
 /// NULL, then the source address in the query_sm command should also be set to NULL.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::QuerySm<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -72,6 +79,22 @@ impl From<QuerySm> for Pdu {
     }
 }
 
+impl Validate for QuerySm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QuerySmBuilder {
     inner: QuerySm,
@@ -1,12 +1,14 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     pdus::owned::Pdu,
     types::owned::{COctetString, EmptyOrFullCOctetString},
+    validate::{Validate, Violation},
     values::*,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::QuerySmResp<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -60,6 +62,12 @@ impl From<QuerySmResp> for Pdu {
     }
 }
 
+impl Validate for QuerySmResp {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        alloc::vec::Vec::new()
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct QuerySmRespBuilder {
     inner: QuerySmResp,
@@ -80,6 +88,12 @@ impl QuerySmRespBuilder {
         self
     }
 
+    /// Sets the `final_date` from an [`SmppTime`].
+    pub fn final_date_smpp_time(mut self, final_date: impl Into<SmppTime>) -> Self {
+        self.inner.final_date = final_date.into().into();
+        self
+    }
+
     pub fn message_state(mut self, message_state: MessageState) -> Self {
         self.inner.message_state = message_state;
         self
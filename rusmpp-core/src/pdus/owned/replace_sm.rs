@@ -1,10 +1,13 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     encode::Length,
+    fields::SmppField,
     pdus::owned::Pdu,
+    tlvs::TlvTag,
     tlvs::owned::{Tlv, TlvValue},
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 
@@ -14,7 +17,8 @@ use crate::{
 ///
 /// Where the original submit_sm ‘source address’ was defaulted to NULL, then the source
 /// address in the replace_sm command should also be NULL.
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::ReplaceSm<'a>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -168,6 +172,39 @@ impl From<ReplaceSm> for Pdu {
     }
 }
 
+impl Validate for ReplaceSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_length(
+            &mut violations,
+            SmppField::sm_length,
+            self.sm_length as usize,
+            self.short_message.len(),
+        );
+
+        if self.message_payload.is_some() && !self.short_message.is_empty() {
+            violations.push(Violation::ShortMessageAndMessagePayload);
+        }
+
+        validate::check_tlv(
+            &mut violations,
+            self.message_payload.as_ref(),
+            TlvTag::MessagePayload,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ReplaceSmBuilder {
     inner: ReplaceSm,
@@ -211,6 +248,21 @@ impl ReplaceSmBuilder {
         self
     }
 
+    /// Sets the `schedule_delivery_time` from an [`SmppTime`].
+    pub fn schedule_delivery_smpp_time(
+        mut self,
+        schedule_delivery_time: impl Into<SmppTime>,
+    ) -> Self {
+        self.inner.schedule_delivery_time = schedule_delivery_time.into().into();
+        self
+    }
+
+    /// Sets the `validity_period` from an [`SmppTime`].
+    pub fn validity_period_smpp_time(mut self, validity_period: impl Into<SmppTime>) -> Self {
+        self.inner.validity_period = validity_period.into().into();
+        self
+    }
+
     pub fn registered_delivery(mut self, registered_delivery: RegisteredDelivery) -> Self {
         self.inner.registered_delivery = registered_delivery;
         self
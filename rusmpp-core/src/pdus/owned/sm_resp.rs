@@ -1,14 +1,18 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     pdus::owned::Pdu,
     tlvs::owned::{MessageDeliveryResponseTlvValue, Tlv},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
 };
 
 macro_rules! sm_resp {
     ($name:ident) => {
-        #[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+        #[derive(
+            Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed,
+        )]
+        #[from_borrowed(crate::pdus::borrowed::$name<'a, N>)]
         #[rusmpp(decode = owned, test = skip)]
         #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
         #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -20,7 +24,7 @@ macro_rules! sm_resp {
             message_id: COctetString<1, 65>,
             /// Message delivery response TLVs ([`MessageDeliveryResponseTlvValue`])
             #[rusmpp(length = "unchecked")]
-            tlvs: alloc::vec::Vec<Tlv>,
+            pub(crate) tlvs: alloc::vec::Vec<Tlv>,
         }
 
         impl $name {
@@ -56,6 +60,20 @@ macro_rules! sm_resp {
             }
         }
 
+        impl Validate for $name {
+            fn validate(&self) -> alloc::vec::Vec<Violation> {
+                let mut violations = alloc::vec::Vec::new();
+
+                validate::check_tlvs(
+                    &mut violations,
+                    &self.tlvs,
+                    MessageDeliveryResponseTlvValue::supports_tag,
+                );
+
+                violations
+            }
+        }
+
         ::pastey::paste! {
             #[derive(Debug, Default)]
             pub struct [<$name Builder>] {
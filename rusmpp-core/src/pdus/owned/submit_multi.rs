@@ -1,14 +1,17 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     encode::Length,
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::SubmitMulti<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -94,7 +97,7 @@ pub struct SubmitMulti {
     short_message: OctetString<0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitMulti {
@@ -213,6 +216,62 @@ impl From<SubmitMulti> for Pdu {
     }
 }
 
+impl Validate for SubmitMulti {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_length(
+            &mut violations,
+            SmppField::number_of_dests,
+            self.number_of_dests as usize,
+            self.dest_address.len(),
+        );
+
+        for dest_address in self.dest_address.iter() {
+            if let DestAddress::SmeAddress(address) = dest_address {
+                validate::check_address(
+                    &mut violations,
+                    SmppField::destination_addr,
+                    address.dest_addr_ton,
+                    address.dest_addr_npi,
+                    address.destination_addr.as_str(),
+                );
+            }
+        }
+
+        validate::check_length(
+            &mut violations,
+            SmppField::sm_length,
+            self.sm_length as usize,
+            self.short_message.len(),
+        );
+
+        validate::check_user_data(
+            &mut violations,
+            self.esm_class,
+            self.data_coding,
+            &self.short_message,
+            validate::message_payload(&self.tlvs),
+        );
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            MessageSubmissionRequestTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SubmitMultiBuilder {
     inner: SubmitMulti,
@@ -286,6 +345,21 @@ impl SubmitMultiBuilder {
         self
     }
 
+    /// Sets the `schedule_delivery_time` from an [`SmppTime`].
+    pub fn schedule_delivery_smpp_time(
+        mut self,
+        schedule_delivery_time: impl Into<SmppTime>,
+    ) -> Self {
+        self.inner.schedule_delivery_time = schedule_delivery_time.into().into();
+        self
+    }
+
+    /// Sets the `validity_period` from an [`SmppTime`].
+    pub fn validity_period_smpp_time(mut self, validity_period: impl Into<SmppTime>) -> Self {
+        self.inner.validity_period = validity_period.into().into();
+        self
+    }
+
     pub fn registered_delivery(mut self, registered_delivery: RegisteredDelivery) -> Self {
         self.inner.registered_delivery = registered_delivery;
         self
@@ -1,13 +1,16 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
     values::owned::*,
 };
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::SubmitMultiResp<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -29,7 +32,7 @@ pub struct SubmitMultiResp {
     unsuccess_sme: alloc::vec::Vec<UnsuccessSme>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitMultiResp {
@@ -100,6 +103,37 @@ impl From<SubmitMultiResp> for Pdu {
     }
 }
 
+impl Validate for SubmitMultiResp {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_length(
+            &mut violations,
+            SmppField::no_unsuccess,
+            self.no_unsuccess as usize,
+            self.unsuccess_sme.len(),
+        );
+
+        for unsuccess_sme in self.unsuccess_sme.iter() {
+            validate::check_address(
+                &mut violations,
+                SmppField::destination_addr,
+                unsuccess_sme.dest_addr_ton,
+                unsuccess_sme.dest_addr_npi,
+                unsuccess_sme.destination_addr.as_str(),
+            );
+        }
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            MessageSubmissionResponseTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SubmitMultiRespBuilder {
     inner: SubmitMultiResp,
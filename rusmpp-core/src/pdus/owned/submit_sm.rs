@@ -1,16 +1,19 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     encode::Length,
+    fields::SmppField,
     pdus::owned::Pdu,
     tlvs::owned::{MessageSubmissionRequestTlvValue, Tlv},
     types::owned::{COctetString, EmptyOrFullCOctetString, OctetString},
+    validate::{self, Validate, Violation},
     values::{owned::*, *},
 };
 
 /// This operation is used by an ESME to submit a short message to the MC for onward
 /// transmission to a specified short message entity (SME).
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::SubmitSm<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -83,7 +86,7 @@ pub struct SubmitSm {
     short_message: OctetString<0, 255>,
     /// Message submission request TLVs ([`MessageSubmissionRequestTlvValue`]).
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitSm {
@@ -227,6 +230,51 @@ impl From<SubmitSm> for Pdu {
     }
 }
 
+impl Validate for SubmitSm {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_address(
+            &mut violations,
+            SmppField::source_addr,
+            self.source_addr_ton,
+            self.source_addr_npi,
+            self.source_addr.as_str(),
+        );
+
+        validate::check_address(
+            &mut violations,
+            SmppField::destination_addr,
+            self.dest_addr_ton,
+            self.dest_addr_npi,
+            self.destination_addr.as_str(),
+        );
+
+        validate::check_length(
+            &mut violations,
+            SmppField::sm_length,
+            self.sm_length as usize,
+            self.short_message.len(),
+        );
+
+        validate::check_user_data(
+            &mut violations,
+            self.esm_class,
+            self.data_coding,
+            &self.short_message,
+            validate::message_payload(&self.tlvs),
+        );
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            MessageSubmissionRequestTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct SubmitSmBuilder {
     inner: SubmitSm,
@@ -300,6 +348,21 @@ impl SubmitSmBuilder {
         self
     }
 
+    /// Sets the `schedule_delivery_time` from an [`SmppTime`].
+    pub fn schedule_delivery_smpp_time(
+        mut self,
+        schedule_delivery_time: impl Into<SmppTime>,
+    ) -> Self {
+        self.inner.schedule_delivery_time = schedule_delivery_time.into().into();
+        self
+    }
+
+    /// Sets the `validity_period` from an [`SmppTime`].
+    pub fn validity_period_smpp_time(mut self, validity_period: impl Into<SmppTime>) -> Self {
+        self.inner.validity_period = validity_period.into().into();
+        self
+    }
+
     pub fn registered_delivery(mut self, registered_delivery: RegisteredDelivery) -> Self {
         self.inner.registered_delivery = registered_delivery;
         self
@@ -459,4 +522,43 @@ mod tests {
         assert_eq!(submit_sm.short_message(), &short_message);
         assert_eq!(submit_sm.sm_length(), short_message.length() as u8);
     }
+
+    #[test]
+    fn validate() {
+        use crate::{
+            fields::SmppField,
+            tlvs::{TlvTag, owned::TlvValue},
+        };
+
+        let mut submit_sm = SubmitSm::builder()
+            .source_addr_ton(Ton::Alphanumeric)
+            .source_addr(COctetString::from_static_slice(b"Rusmpp\0").unwrap())
+            .dest_addr_ton(Ton::International)
+            .dest_addr_npi(Npi::Isdn)
+            .destination_addr(COctetString::from_static_slice(b"491701234567\0").unwrap())
+            .data_coding(DataCoding::Ia5)
+            .short_message(OctetString::from_static_slice(b"Short Message").unwrap())
+            .build();
+
+        assert!(submit_sm.is_valid());
+
+        submit_sm.sm_length = 3;
+        submit_sm.tlvs.push(Tlv::new(TlvValue::ScInterfaceVersion(
+            InterfaceVersion::Smpp5_0,
+        )));
+
+        assert_eq!(
+            submit_sm.validate(),
+            [
+                Violation::LengthMismatch {
+                    field: SmppField::sm_length,
+                    declared: 3,
+                    actual: 13,
+                },
+                Violation::IllegalTlv {
+                    tag: TlvTag::ScInterfaceVersion,
+                },
+            ]
+        );
+    }
 }
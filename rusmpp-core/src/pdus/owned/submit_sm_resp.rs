@@ -1,12 +1,14 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     pdus::owned::Pdu,
     tlvs::owned::{MessageSubmissionResponseTlvValue, Tlv},
     types::owned::COctetString,
+    validate::{self, Validate, Violation},
 };
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::pdus::borrowed::SubmitSmResp<'a, N>)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -18,7 +20,7 @@ pub struct SubmitSmResp {
     message_id: COctetString<1, 65>,
     /// Message submission response TLVs ([`MessageSubmissionResponseTlvValue`])
     #[rusmpp(length = "unchecked")]
-    tlvs: alloc::vec::Vec<Tlv>,
+    pub(crate) tlvs: alloc::vec::Vec<Tlv>,
 }
 
 impl SubmitSmResp {
@@ -62,6 +64,20 @@ impl From<SubmitSmResp> for Pdu {
     }
 }
 
+impl Validate for SubmitSmResp {
+    fn validate(&self) -> alloc::vec::Vec<Violation> {
+        let mut violations = alloc::vec::Vec::new();
+
+        validate::check_tlvs(
+            &mut violations,
+            &self.tlvs,
+            MessageSubmissionResponseTlvValue::supports_tag,
+        );
+
+        violations
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SubmitSmRespBuilder {
     inner: SubmitSmResp,
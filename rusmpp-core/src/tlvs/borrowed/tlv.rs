@@ -65,6 +65,37 @@ impl<'a> Tlv<'a> {
     pub const fn value(&'_ self) -> Option<&'_ TlvValue<'_>> {
         self.value.as_ref()
     }
+
+    /// Replaces the first TLV with the same tag as `tlv` and removes any duplicates, or pushes `tlv` if none is present.
+    ///
+    /// Fails if `tlv` has to be pushed and `tlvs` is full.
+    pub(crate) fn replace_or_push<const N: usize>(
+        tlvs: &mut heapless::vec::Vec<Tlv<'a>, N>,
+        tlv: Tlv<'a>,
+    ) -> Result<(), Tlv<'a>> {
+        let tag = tlv.tag();
+        let mut tlv = Some(tlv);
+
+        tlvs.retain_mut(|existing| {
+            if existing.tag() != tag {
+                return true;
+            }
+
+            match tlv.take() {
+                Some(tlv) => {
+                    *existing = tlv;
+
+                    true
+                }
+                None => false,
+            }
+        });
+
+        match tlv {
+            Some(tlv) => tlvs.push(tlv),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<'a> From<TlvValue<'a>> for Tlv<'a> {
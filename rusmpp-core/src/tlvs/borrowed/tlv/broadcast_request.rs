@@ -11,6 +11,7 @@ use crate::{
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[tlv_value(pdus(crate::pdus::borrowed::BroadcastSm<'a, N>))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub enum BroadcastRequestTlvValue<'a> {
     BroadcastAreaIdentifier(BroadcastAreaIdentifier<'a>),
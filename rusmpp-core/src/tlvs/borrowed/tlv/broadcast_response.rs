@@ -12,6 +12,7 @@ use crate::{
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[tlv_value(pdus(crate::pdus::borrowed::BroadcastSmResp<'a, N>))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub enum BroadcastResponseTlvValue<'a> {
     BroadcastErrorStatus(CommandStatus),
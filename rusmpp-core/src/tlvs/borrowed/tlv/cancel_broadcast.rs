@@ -11,6 +11,7 @@ use crate::{
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[tlv_value(pdus(crate::pdus::borrowed::CancelBroadcastSm<'a, N>))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub enum CancelBroadcastTlvValue<'a> {
     /// Specifies the content type of the message.
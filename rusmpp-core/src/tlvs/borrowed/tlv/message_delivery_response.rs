@@ -11,6 +11,10 @@ use crate::{
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[tlv_value(pdus(
+    crate::pdus::borrowed::DeliverSmResp<'a, N>,
+    crate::pdus::borrowed::DataSmResp<'a, N>,
+))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub enum MessageDeliveryResponseTlvValue<'a> {
     AdditionalStatusInfoText(COctetString<'a, 1, 256>),
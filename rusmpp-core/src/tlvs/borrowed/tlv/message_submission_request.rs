@@ -11,6 +11,11 @@ use crate::{
 
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[tlv_value(pdus(
+    crate::pdus::borrowed::SubmitSm<'a, N>,
+    crate::pdus::borrowed::DataSm<'a, N>,
+    crate::pdus::borrowed::SubmitMulti<'a, N>,
+))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 pub enum MessageSubmissionRequestTlvValue<'a> {
     AlertOnMessageDelivery(AlertOnMessageDelivery),
@@ -1,4 +1,4 @@
-use rusmpp_macros::Rusmpp;
+use rusmpp_macros::{FromBorrowed, Rusmpp};
 
 use crate::{
     encode::Length,
@@ -30,7 +30,8 @@ mod query_broadcast_response;
 pub use query_broadcast_response::*;
 
 /// See module level documentation.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Rusmpp, FromBorrowed)]
+#[from_borrowed(crate::tlvs::borrowed::Tlv<'a>, parts = crate::tlvs::borrowed::TlvParts)]
 #[rusmpp(decode = owned, test = skip)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
@@ -66,6 +67,31 @@ impl Tlv {
     pub const fn value(&self) -> Option<&TlvValue> {
         self.value.as_ref()
     }
+
+    /// Replaces the first TLV with the same tag as `tlv` and removes any duplicates, or pushes `tlv` if none is present.
+    pub(crate) fn replace_or_push(tlvs: &mut alloc::vec::Vec<Tlv>, tlv: Tlv) {
+        let tag = tlv.tag();
+        let mut tlv = Some(tlv);
+
+        tlvs.retain_mut(|existing| {
+            if existing.tag() != tag {
+                return true;
+            }
+
+            match tlv.take() {
+                Some(tlv) => {
+                    *existing = tlv;
+
+                    true
+                }
+                None => false,
+            }
+        });
+
+        if let Some(tlv) = tlv {
+            tlvs.push(tlv);
+        }
+    }
 }
 
 impl From<TlvValue> for Tlv {
@@ -1,4 +1,4 @@
-use rusmpp_macros::TlvValue;
+use rusmpp_macros::{FromBorrowed, TlvValue};
 
 use crate::{
     tlvs::{
@@ -10,7 +10,9 @@ use crate::{
 };
 
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue, FromBorrowed)]
+#[tlv_value(pdus(crate::pdus::owned::BroadcastSm))]
+#[from_borrowed(crate::tlvs::borrowed::BroadcastRequestTlvValue<'a>)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
 pub enum BroadcastRequestTlvValue {
@@ -1,4 +1,4 @@
-use rusmpp_macros::TlvValue;
+use rusmpp_macros::{FromBorrowed, TlvValue};
 
 use crate::{
     CommandStatus,
@@ -11,7 +11,9 @@ use crate::{
 };
 
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, TlvValue, FromBorrowed)]
+#[tlv_value(pdus(crate::pdus::owned::BroadcastSmResp))]
+#[from_borrowed(crate::tlvs::borrowed::BroadcastResponseTlvValue<'a>)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
 pub enum BroadcastResponseTlvValue {
@@ -1,4 +1,5 @@
 use bytes::BytesMut;
+use rusmpp_macros::FromBorrowed;
 
 use crate::{
     CommandStatus,
@@ -14,7 +15,8 @@ use crate::{
 
 /// See module level documentation.
 #[non_exhaustive]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, FromBorrowed)]
+#[from_borrowed(crate::tlvs::borrowed::TlvValue<'a>)]
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
 #[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
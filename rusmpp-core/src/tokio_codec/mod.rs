@@ -4,11 +4,12 @@ use core::num::TryFromIntError;
 
 use bytes::Buf;
 use tokio_util::{
-    bytes::{BufMut, BytesMut},
+    bytes::{BufMut, Bytes, BytesMut},
     codec::{Decoder, Encoder},
 };
 
 use crate::{
+    CommandId, CommandStatus,
     command::owned::Command,
     decode::owned::DecodeWithLength,
     encode::{Length, owned::Encode},
@@ -18,6 +19,9 @@ use crate::{
 #[cfg(test)]
 mod tests;
 
+/// Length of the command header (command_length, command_id, command_status, sequence_number).
+const HEADER_LENGTH: usize = 16;
+
 #[derive(Debug)]
 enum DecodeState {
     /// Decoding the command length.
@@ -60,6 +64,12 @@ impl CommandCodec {
         self
     }
 
+    /// Converts this codec into a [`RecoverableCommandCodec`] with the same maximum length.
+    #[inline]
+    pub const fn recoverable(self) -> RecoverableCommandCodec {
+        RecoverableCommandCodec { inner: self }
+    }
+
     /// Sets the decoder state to decode the command length.
     #[inline]
     const fn decode_length(&mut self) {
@@ -154,6 +164,24 @@ pub enum DecodeError {
     InvalidLength(TryFromIntError),
 }
 
+impl DecodeError {
+    /// Returns the status of the `generic_nack` answering the command that failed to decode.
+    ///
+    /// Invalid command lengths are answered with [`EsmeRinvcmdlen`](CommandStatus::EsmeRinvcmdlen),
+    /// malformed bodies as described in [`generic_nack_status`](crate::decode::DecodeError::generic_nack_status).
+    ///
+    /// Returns `None` for I/O errors, the peer can not be answered.
+    pub const fn generic_nack_status(&self) -> Option<CommandStatus> {
+        match self {
+            DecodeError::MinLength { .. }
+            | DecodeError::MaxLength { .. }
+            | DecodeError::InvalidLength(_) => Some(CommandStatus::EsmeRinvcmdlen),
+            DecodeError::Decode(err) => Some(err.generic_nack_status()),
+            DecodeError::Io(_) => None,
+        }
+    }
+}
+
 impl From<std::io::Error> for DecodeError {
     fn from(e: std::io::Error) -> Self {
         DecodeError::Io(e)
@@ -200,13 +228,12 @@ impl core::error::Error for DecodeError {
     }
 }
 
-impl Decoder for CommandCodec {
-    type Item = Command;
-    type Error = DecodeError;
-
-    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-        const HEADER_LENGTH: usize = 16;
-
+impl CommandCodec {
+    /// Decodes the next frame (Header without length + Body).
+    ///
+    /// The returned frame is exactly `command_length - 4` bytes long and is removed from `src`,
+    /// whether its body can be decoded or not.
+    fn decode_frame(&mut self, src: &mut BytesMut) -> Result<Option<BytesMut>, DecodeError> {
         loop {
             match self.state {
                 DecodeState::Length => {
@@ -264,25 +291,226 @@ impl Decoder for CommandCodec {
 
                     debug!(target: "rusmpp::codec::decode", command_length, decode_length=pdu_length, decoding=?crate::formatter::Formatter(&src[..pdu_length]), "Decoding");
 
-                    let (command, _size) = match Command::decode(src, pdu_length) {
-                        Ok((command, size)) => {
-                            debug!(target: "rusmpp::codec::decode", command=?command, command_length, decoded_length=size, "Decoded");
+                    self.decode_length();
 
-                            (command, size)
-                        }
-                        Err(err) => {
-                            error!(target: "rusmpp::codec::decode", ?err);
+                    return Ok(Some(src.split_to(pdu_length)));
+                }
+            }
+        }
+    }
+}
+
+impl Decoder for CommandCodec {
+    type Item = Command;
+    type Error = DecodeError;
 
-                            self.decode_length();
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(mut frame) = self.decode_frame(src)? else {
+            return Ok(None);
+        };
 
-                            return Err(DecodeError::Decode(err));
-                        }
-                    };
+        let pdu_length = frame.len();
 
-                    self.decode_length();
+        match Command::decode(&mut frame, pdu_length) {
+            Ok((command, _size)) => {
+                debug!(target: "rusmpp::codec::decode", command=?command, command_length=pdu_length + 4, decoded_length=_size, "Decoded");
 
-                    return Ok(Some(command));
-                }
+                Ok(Some(command))
+            }
+            Err(err) => {
+                error!(target: "rusmpp::codec::decode", ?err);
+
+                Err(DecodeError::Decode(err))
+            }
+        }
+    }
+}
+
+/// A command with a valid header whose body could not be decoded.
+///
+/// Returned by the [`RecoverableCommandCodec`] instead of failing the stream,
+/// so the peer can be answered with a `generic_nack` for the [`sequence_number`](Self::sequence_number).
+#[derive(Debug)]
+pub struct MalformedCommand {
+    command_length: usize,
+    id: CommandId,
+    status: CommandStatus,
+    sequence_number: u32,
+    body: Bytes,
+    error: crate::decode::DecodeError,
+}
+
+impl MalformedCommand {
+    /// Creates a new [`MalformedCommand`] from a frame (Header without length + Body).
+    fn new(mut frame: Bytes, error: crate::decode::DecodeError) -> Self {
+        let command_length = frame.len() + 4;
+
+        Self {
+            command_length,
+            id: CommandId::from(frame.get_u32()),
+            status: CommandStatus::from(frame.get_u32()),
+            sequence_number: frame.get_u32(),
+            body: frame,
+            error,
+        }
+    }
+
+    /// The `command_length` of the header, including the header itself.
+    #[inline]
+    pub const fn command_length(&self) -> usize {
+        self.command_length
+    }
+
+    /// The [`CommandId`] of the header.
+    #[inline]
+    pub const fn id(&self) -> CommandId {
+        self.id
+    }
+
+    /// The [`CommandStatus`] of the header.
+    #[inline]
+    pub const fn status(&self) -> CommandStatus {
+        self.status
+    }
+
+    /// The `sequence_number` of the header.
+    #[inline]
+    pub const fn sequence_number(&self) -> u32 {
+        self.sequence_number
+    }
+
+    /// The raw body of the command, without the header.
+    #[inline]
+    pub const fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// The error that occurred while decoding the body.
+    #[inline]
+    pub const fn error(&self) -> &crate::decode::DecodeError {
+        &self.error
+    }
+
+    /// Returns the status of the `generic_nack` answering this command.
+    ///
+    /// See [`DecodeError::generic_nack_status`](crate::decode::DecodeError::generic_nack_status).
+    pub const fn generic_nack_status(&self) -> CommandStatus {
+        self.error.generic_nack_status()
+    }
+}
+
+impl core::fmt::Display for MalformedCommand {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Malformed command. id: {:?}, sequence_number: {}, error: {}",
+            self.id, self.sequence_number, self.error
+        )
+    }
+}
+
+impl core::error::Error for MalformedCommand {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+
+    fn cause(&self) -> Option<&dyn core::error::Error> {
+        self.source()
+    }
+}
+
+/// Codec for encoding and decoding `SMPP` PDUs, recovering from malformed command bodies.
+///
+/// Unlike the [`CommandCodec`], a command whose header is valid but whose body can not be decoded
+/// does not fail the stream. The command is skipped and returned as a [`MalformedCommand`],
+/// the next command is decoded as usual.
+///
+/// Invalid command lengths still fail the stream, the frame boundary is unknown.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::tokio_codec::{CommandCodec, RecoverableCommandCodec};
+/// let codec: RecoverableCommandCodec = CommandCodec::new().with_max_length(1024).recoverable();
+/// ```
+#[derive(Debug, Default)]
+pub struct RecoverableCommandCodec {
+    inner: CommandCodec,
+}
+
+impl RecoverableCommandCodec {
+    /// Creates a new [`RecoverableCommandCodec`] with a default maximum length of `8192` bytes.
+    #[inline]
+    pub const fn new() -> Self {
+        CommandCodec::new().recoverable()
+    }
+
+    #[inline]
+    pub const fn max_length(&self) -> Option<usize> {
+        self.inner.max_length()
+    }
+
+    #[inline]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.inner = self.inner.with_max_length(max_length);
+        self
+    }
+
+    #[inline]
+    pub fn without_max_length(mut self) -> Self {
+        self.inner = self.inner.without_max_length();
+        self
+    }
+}
+
+impl From<CommandCodec> for RecoverableCommandCodec {
+    fn from(codec: CommandCodec) -> Self {
+        codec.recoverable()
+    }
+}
+
+impl Encoder<&Command> for RecoverableCommandCodec {
+    type Error = EncodeError;
+
+    fn encode(&mut self, command: &Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.inner.encode(command, dst)
+    }
+}
+
+impl Encoder<Command> for RecoverableCommandCodec {
+    type Error = EncodeError;
+
+    fn encode(&mut self, command: Command, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.inner.encode(&command, dst)
+    }
+}
+
+impl Decoder for RecoverableCommandCodec {
+    type Item = Result<Command, MalformedCommand>;
+    type Error = DecodeError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(frame) = self.inner.decode_frame(src)? else {
+            return Ok(None);
+        };
+
+        let pdu_length = frame.len();
+
+        // The decoder consumes its input, decode a copy and keep the frame to report the raw body on failure.
+        let mut decoding = frame.clone();
+
+        match Command::decode(&mut decoding, pdu_length) {
+            Ok((command, _size)) => {
+                debug!(target: "rusmpp::codec::decode", command=?command, command_length=pdu_length + 4, decoded_length=_size, "Decoded");
+
+                Ok(Some(Ok(command)))
+            }
+            Err(err) => {
+                let malformed = MalformedCommand::new(frame.freeze(), err);
+
+                error!(target: "rusmpp::codec::decode", command_length=malformed.command_length(), id=?malformed.id(), sequence_number=malformed.sequence_number(), err=?malformed.error(), "Skipping malformed command");
+
+                Ok(Some(Err(malformed)))
             }
         }
     }
@@ -10,13 +10,13 @@ use tokio::{io::AsyncWriteExt, net::TcpStream};
 use tokio_util::codec::{Framed, FramedRead, FramedWrite};
 
 use crate::{
-    CommandStatus,
+    CommandId, CommandStatus,
     command::owned::Command,
     encode::{Encode, Length},
     pdus::owned::*,
     tests::owned::test_commands,
     tlvs::owned::{BroadcastRequestTlvValue, MessageSubmissionRequestTlvValue},
-    tokio_codec::{CommandCodec, DecodeError},
+    tokio_codec::{CommandCodec, DecodeError, RecoverableCommandCodec},
     types::owned::{AnyOctetString, COctetString, OctetString},
     values::{owned::*, *},
 };
@@ -104,6 +104,100 @@ async fn min_length() {
     }
 }
 
+#[tokio::test]
+async fn recoverable_should_skip_malformed_command() {
+    let (mut writer, reader) = tokio::io::duplex(1024);
+
+    let first = Command::new(CommandStatus::EsmeRok, 1, Pdu::EnquireLink);
+    let last = Command::new(CommandStatus::EsmeRok, 3, SubmitSm::default());
+
+    // A submit_sm with a valid header and a body that is not null terminated.
+    let mut malformed = Vec::new();
+    malformed.extend_from_slice(&19_u32.to_be_bytes());
+    malformed.extend_from_slice(&u32::from(CommandId::SubmitSm).to_be_bytes());
+    malformed.extend_from_slice(&u32::from(CommandStatus::EsmeRok).to_be_bytes());
+    malformed.extend_from_slice(&2_u32.to_be_bytes());
+    malformed.extend_from_slice(b"abc");
+
+    let mut framed_writer = FramedWrite::new(Vec::new(), CommandCodec::new());
+    framed_writer.send(&first).await.unwrap();
+    framed_writer.get_mut().extend_from_slice(&malformed);
+    framed_writer.send(&last).await.unwrap();
+
+    writer.write_all(framed_writer.get_ref()).await.unwrap();
+
+    let mut framed_reader = FramedRead::new(reader, RecoverableCommandCodec::new());
+
+    let command = framed_reader.next().await.unwrap().unwrap().unwrap();
+    assert_eq!(command, first);
+
+    let malformed = framed_reader.next().await.unwrap().unwrap().unwrap_err();
+    assert_eq!(malformed.command_length(), 19);
+    assert_eq!(malformed.id(), CommandId::SubmitSm);
+    assert_eq!(malformed.status(), CommandStatus::EsmeRok);
+    assert_eq!(malformed.sequence_number(), 2);
+    assert_eq!(&malformed.body()[..], b"abc");
+    assert_eq!(malformed.generic_nack_status(), CommandStatus::EsmeRsyserr);
+
+    let command = framed_reader.next().await.unwrap().unwrap().unwrap();
+    assert_eq!(command, last);
+}
+
+#[tokio::test]
+async fn recoverable_min_length() {
+    let (mut writer, reader) = tokio::io::duplex(1024);
+
+    writer.write_all(&15_u32.to_be_bytes()).await.unwrap();
+    writer.write_all(&[0; 12]).await.unwrap();
+
+    let mut framed_reader = FramedRead::new(reader, RecoverableCommandCodec::new());
+
+    assert!(matches!(
+        framed_reader.next().await.unwrap().unwrap_err(),
+        DecodeError::MinLength {
+            actual: 15,
+            min: 16
+        }
+    ));
+}
+
+#[test]
+fn generic_nack_status() {
+    let cases = [
+        (
+            DecodeError::MinLength {
+                actual: 15,
+                min: 16,
+            },
+            Some(CommandStatus::EsmeRinvcmdlen),
+        ),
+        (
+            DecodeError::MaxLength {
+                actual: 2048,
+                max: 1024,
+            },
+            Some(CommandStatus::EsmeRinvcmdlen),
+        ),
+        (
+            DecodeError::Decode(crate::decode::DecodeError::unexpected_eof()),
+            Some(CommandStatus::EsmeRinvcmdlen),
+        ),
+        (
+            DecodeError::Decode(crate::decode::DecodeError::too_many_elements(2)),
+            Some(CommandStatus::EsmeRinvcmdlen),
+        ),
+        (
+            DecodeError::Decode(crate::decode::DecodeError::unsupported_key(0)),
+            Some(CommandStatus::EsmeRsyserr),
+        ),
+        (DecodeError::Io(std::io::ErrorKind::BrokenPipe.into()), None),
+    ];
+
+    for (error, status) in cases {
+        assert_eq!(error.generic_nack_status(), status, "{error}");
+    }
+}
+
 /// Connect to localhost:2775 and send a command.
 ///
 /// I use this function to throw random commands at a server and catch them in wireshark.
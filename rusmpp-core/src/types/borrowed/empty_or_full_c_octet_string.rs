@@ -117,6 +117,15 @@ impl<'a, const N: usize> EmptyOrFullCOctetString<'a, N> {
         Ok(Self { bytes })
     }
 
+    /// Create a new [`EmptyOrFullCOctetString`] from `&[u8]` without any checks.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn new_unchecked(bytes: &'a [u8]) -> Self {
+        Self::_ASSERT_VALID;
+
+        Self { bytes }
+    }
+
     /// Returns the bytes of the [`EmptyOrFullCOctetString`].
     #[inline]
     pub const fn bytes(&self) -> &[u8] {
@@ -109,6 +109,15 @@ impl<'a, const MIN: usize, const MAX: usize> OctetString<'a, MIN, MAX> {
         Ok(Self { bytes })
     }
 
+    /// Create a new [`OctetString`] from `&[u8]` without any checks.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn new_unchecked(bytes: &'a [u8]) -> Self {
+        Self::_ASSERT_VALID;
+
+        Self { bytes }
+    }
+
     /// Returns the bytes of the [`OctetString`].
     #[inline]
     pub const fn bytes(&self) -> &[u8] {
@@ -4,6 +4,7 @@ use bytes::{BufMut, Bytes, BytesMut};
 use crate::{
     decode::{DecodeError, owned::DecodeWithLength},
     encode::{Encode, Length, owned::Encode as BEncode},
+    types::borrowed,
 };
 
 /// No fixed size [`OctetString`](struct@crate::types::owned::octet_string::OctetString).
@@ -250,6 +251,30 @@ impl From<Vec<u8>> for AnyOctetString {
     }
 }
 
+impl<'a> From<borrowed::AnyOctetString<'a>> for AnyOctetString {
+    fn from(value: borrowed::AnyOctetString<'a>) -> Self {
+        Self {
+            bytes: Bytes::copy_from_slice(value.bytes()),
+        }
+    }
+}
+
+impl<'a> borrowed::AnyOctetString<'a> {
+    /// Converts [`Self`] into an owned [`AnyOctetString`], copying the bytes.
+    #[inline]
+    pub fn into_owned(self) -> AnyOctetString {
+        From::from(self)
+    }
+}
+
+impl AnyOctetString {
+    /// Returns a borrowed view of the [`AnyOctetString`] without copying.
+    #[inline]
+    pub fn as_borrowed(&self) -> borrowed::AnyOctetString<'_> {
+        borrowed::AnyOctetString::new(&self.bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,6 +6,7 @@ use bytes::{BufMut, Bytes, BytesMut};
 use crate::{
     decode::{COctetStringDecodeError, DecodeError, owned::Decode},
     encode::{Encode, Length, owned::Encode as BEncode},
+    types::borrowed,
     types::c_octet_string::Error,
 };
 
@@ -411,6 +412,32 @@ impl<const MIN: usize, const MAX: usize> TryFrom<Vec<u8>> for COctetString<MIN,
     }
 }
 
+impl<'a, const MIN: usize, const MAX: usize> From<borrowed::COctetString<'a, MIN, MAX>>
+    for COctetString<MIN, MAX>
+{
+    fn from(value: borrowed::COctetString<'a, MIN, MAX>) -> Self {
+        Self {
+            bytes: Bytes::copy_from_slice(value.bytes()),
+        }
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize> borrowed::COctetString<'a, MIN, MAX> {
+    /// Converts [`Self`] into an owned [`COctetString`], copying the bytes.
+    #[inline]
+    pub fn into_owned(self) -> COctetString<MIN, MAX> {
+        From::from(self)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> COctetString<MIN, MAX> {
+    /// Returns a borrowed view of the [`COctetString`] without copying.
+    #[inline]
+    pub fn as_borrowed(&self) -> borrowed::COctetString<'_, MIN, MAX> {
+        borrowed::COctetString::new_unchecked(&self.bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -448,6 +475,15 @@ mod tests {
         crate::tests::owned::encode_decode_test_instances::<COctetString<3, 5>>();
     }
 
+    #[test]
+    fn as_borrowed_into_owned() {
+        let string = COctetString::<1, 6>::from_static_slice(b"Hello\0").unwrap();
+        let borrowed = string.as_borrowed();
+
+        assert_eq!(borrowed.bytes(), b"Hello\0");
+        assert_eq!(borrowed.into_owned(), string);
+    }
+
     mod new {
         use super::*;
 
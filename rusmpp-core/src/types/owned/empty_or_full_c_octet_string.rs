@@ -6,6 +6,7 @@ use bytes::{BufMut, Bytes, BytesMut};
 use crate::{
     decode::{COctetStringDecodeError, DecodeError, owned::Decode},
     encode::{Encode, Length, owned::Encode as BEncode},
+    types::borrowed,
     types::empty_or_full_c_octet_string::Error,
 };
 
@@ -365,6 +366,32 @@ impl<const N: usize> TryFrom<Vec<u8>> for EmptyOrFullCOctetString<N> {
     }
 }
 
+impl<'a, const N: usize> From<borrowed::EmptyOrFullCOctetString<'a, N>>
+    for EmptyOrFullCOctetString<N>
+{
+    fn from(value: borrowed::EmptyOrFullCOctetString<'a, N>) -> Self {
+        Self {
+            bytes: Bytes::copy_from_slice(value.bytes()),
+        }
+    }
+}
+
+impl<'a, const N: usize> borrowed::EmptyOrFullCOctetString<'a, N> {
+    /// Converts [`Self`] into an owned [`EmptyOrFullCOctetString`], copying the bytes.
+    #[inline]
+    pub fn into_owned(self) -> EmptyOrFullCOctetString<N> {
+        From::from(self)
+    }
+}
+
+impl<const N: usize> EmptyOrFullCOctetString<N> {
+    /// Returns a borrowed view of the [`EmptyOrFullCOctetString`] without copying.
+    #[inline]
+    pub fn as_borrowed(&self) -> borrowed::EmptyOrFullCOctetString<'_, N> {
+        borrowed::EmptyOrFullCOctetString::new_unchecked(&self.bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,6 +5,7 @@ use bytes::{BufMut, Bytes, BytesMut};
 use crate::{
     decode::{DecodeError, OctetStringDecodeError, owned::DecodeWithLength},
     encode::{Encode, Length, owned::Encode as BEncode},
+    types::borrowed,
     types::octet_string::Error,
 };
 
@@ -362,6 +363,32 @@ impl<const MIN: usize, const MAX: usize> TryFrom<Vec<u8>> for OctetString<MIN, M
     }
 }
 
+impl<'a, const MIN: usize, const MAX: usize> From<borrowed::OctetString<'a, MIN, MAX>>
+    for OctetString<MIN, MAX>
+{
+    fn from(value: borrowed::OctetString<'a, MIN, MAX>) -> Self {
+        Self {
+            bytes: Bytes::copy_from_slice(value.bytes()),
+        }
+    }
+}
+
+impl<'a, const MIN: usize, const MAX: usize> borrowed::OctetString<'a, MIN, MAX> {
+    /// Converts [`Self`] into an owned [`OctetString`], copying the bytes.
+    #[inline]
+    pub fn into_owned(self) -> OctetString<MIN, MAX> {
+        From::from(self)
+    }
+}
+
+impl<const MIN: usize, const MAX: usize> OctetString<MIN, MAX> {
+    /// Returns a borrowed view of the [`OctetString`] without copying.
+    #[inline]
+    pub fn as_borrowed(&self) -> borrowed::OctetString<'_, MIN, MAX> {
+        borrowed::OctetString::new_unchecked(&self.bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,385 @@
+//! Semantic validation of `SMPP` PDUs.
+//!
+//! The type system enforces the length and format of every single field, but the `SMPP` protocol
+//! also defines rules spanning multiple fields, e.g. `short_message` and `message_payload` must not be set at the same time.
+//!
+//! [`Validate`] checks these rules and reports every broken one as a [`Violation`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use rusmpp_core::{
+//! #     fields::SmppField,
+//! #     pdus::owned::SubmitSm,
+//! #     tlvs::owned::MessageSubmissionRequestTlvValue,
+//! #     types::owned::{AnyOctetString, COctetString, OctetString},
+//! #     validate::{Validate, Violation},
+//! #     values::{owned::MessagePayload, Ton},
+//! # };
+//! let submit_sm = SubmitSm::builder()
+//!     .dest_addr_ton(Ton::International)
+//!     .destination_addr(COctetString::from_static_slice(b"NOT-A-NUMBER\0").unwrap())
+//!     .short_message(OctetString::from_static_slice(b"Hi, I am a short message.").unwrap())
+//!     .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(MessagePayload::new(
+//!         AnyOctetString::from_static_slice(b"Hi, I am a message payload."),
+//!     )))
+//!     .build();
+//!
+//! let violations = submit_sm.validate();
+//!
+//! assert!(violations.contains(&Violation::ShortMessageAndMessagePayload));
+//! assert!(violations.contains(&Violation::InvalidAddress {
+//!     field: SmppField::destination_addr,
+//!     ton: Ton::International,
+//!     npi: Default::default(),
+//! }));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{
+    fields::SmppField,
+    tlvs::{
+        TlvTag,
+        owned::{Tlv, TlvValue},
+    },
+    values::{DataCoding, EsmClass, GsmFeatures, MessageType, Npi, RegisteredDelivery, Ton},
+};
+
+/// Checks the cross-field rules of the `SMPP` protocol.
+pub trait Validate {
+    /// Returns every rule violated by `self`.
+    ///
+    /// An empty list means that `self` is valid.
+    fn validate(&self) -> Vec<Violation>;
+
+    /// Returns `true` if `self` does not violate any rule.
+    fn is_valid(&self) -> bool {
+        self.validate().is_empty()
+    }
+}
+
+/// A violated `SMPP` rule.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// A length field does not match the length of the value it describes.
+    ///
+    /// e.g. `sm_length` and `short_message`, `number_of_dests` and `dest_address`.
+    LengthMismatch {
+        field: SmppField,
+        declared: usize,
+        actual: usize,
+    },
+    /// Both `short_message` and the `message_payload` TLV are set.
+    ShortMessageAndMessagePayload,
+    /// The UDHI bit of the `esm_class` is set, but the user data does not start with a valid user data header.
+    InvalidUdh,
+    /// The user data can not be encoded using the declared `data_coding`.
+    ///
+    /// e.g. non ASCII bytes with [`DataCoding::Ia5`] or an odd number of bytes with [`DataCoding::Ucs2`].
+    InvalidDataCoding { data_coding: DataCoding },
+    /// A delivery receipt or an intermediate notification requests a delivery receipt itself.
+    RegisteredDeliveryOnReceipt { message_type: MessageType },
+    /// An address does not match its type of number and numbering plan indicator.
+    InvalidAddress {
+        field: SmppField,
+        ton: Ton,
+        npi: Npi,
+    },
+    /// A TLV is not allowed in the PDU.
+    IllegalTlv { tag: TlvTag },
+}
+
+impl core::fmt::Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Violation::LengthMismatch {
+                field,
+                declared,
+                actual,
+            } => write!(
+                f,
+                "Length mismatch. field: {field:?}, declared: {declared}, actual: {actual}"
+            ),
+            Violation::ShortMessageAndMessagePayload => {
+                write!(f, "short_message and message_payload are both set")
+            }
+            Violation::InvalidUdh => {
+                write!(f, "UDHI is set but the user data header is invalid")
+            }
+            Violation::InvalidDataCoding { data_coding } => {
+                write!(f, "User data does not match data_coding: {data_coding:?}")
+            }
+            Violation::RegisteredDeliveryOnReceipt { message_type } => write!(
+                f,
+                "registered_delivery is set on a receipt. message_type: {message_type:?}"
+            ),
+            Violation::InvalidAddress { field, ton, npi } => write!(
+                f,
+                "Address does not match its format. field: {field:?}, ton: {ton:?}, npi: {npi:?}"
+            ),
+            Violation::IllegalTlv { tag } => write!(f, "Illegal TLV. tag: {tag:?}"),
+        }
+    }
+}
+
+impl core::error::Error for Violation {}
+
+/// Maximum length of an alphanumeric address.
+const MAX_ALPHANUMERIC_ADDRESS_LENGTH: usize = 11;
+
+/// Maximum length of an international (E.164) number.
+const MAX_INTERNATIONAL_ADDRESS_LENGTH: usize = 15;
+
+pub(crate) fn check_length(
+    violations: &mut Vec<Violation>,
+    field: SmppField,
+    declared: usize,
+    actual: usize,
+) {
+    if declared != actual {
+        violations.push(Violation::LengthMismatch {
+            field,
+            declared,
+            actual,
+        });
+    }
+}
+
+/// Checks that `address` (without the null terminator) matches `ton` and `npi`.
+///
+/// Empty addresses are always valid, the `MC` uses its defaults.
+pub(crate) fn check_address(
+    violations: &mut Vec<Violation>,
+    field: SmppField,
+    ton: Ton,
+    npi: Npi,
+    address: &str,
+) {
+    if address.is_empty() {
+        return;
+    }
+
+    let digits = address.bytes().all(|byte| byte.is_ascii_digit());
+
+    let valid = match ton {
+        Ton::Alphanumeric => address.len() <= MAX_ALPHANUMERIC_ADDRESS_LENGTH,
+        Ton::International => digits && address.len() <= MAX_INTERNATIONAL_ADDRESS_LENGTH,
+        Ton::National | Ton::SubscriberNumber => digits,
+        _ => !matches!(npi, Npi::Isdn) || digits,
+    };
+
+    if !valid {
+        violations.push(Violation::InvalidAddress { field, ton, npi });
+    }
+}
+
+pub(crate) fn check_tlvs(
+    violations: &mut Vec<Violation>,
+    tlvs: &[Tlv],
+    supports_tag: fn(TlvTag) -> bool,
+) {
+    violations.extend(
+        tlvs.iter()
+            .map(Tlv::tag)
+            .filter(|tag| !supports_tag(*tag))
+            .map(|tag| Violation::IllegalTlv { tag }),
+    );
+}
+
+/// Checks a PDU field holding a single TLV with the `expected` tag.
+pub(crate) fn check_tlv(violations: &mut Vec<Violation>, tlv: Option<&Tlv>, expected: TlvTag) {
+    if let Some(tag) = tlv.map(Tlv::tag).filter(|tag| *tag != expected) {
+        violations.push(Violation::IllegalTlv { tag });
+    }
+}
+
+pub(crate) fn message_payload(tlvs: &[Tlv]) -> Option<&[u8]> {
+    tlvs.iter().find_map(|tlv| match tlv.value() {
+        Some(TlvValue::MessagePayload(payload)) => Some(payload.value.as_ref()),
+        _ => None,
+    })
+}
+
+/// Checks `short_message` against the `message_payload`, the UDHI bit and the `data_coding`.
+pub(crate) fn check_user_data(
+    violations: &mut Vec<Violation>,
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    short_message: &[u8],
+    message_payload: Option<&[u8]>,
+) {
+    // The message payload supersedes the short message
+    let user_data = match message_payload {
+        Some(payload) => {
+            if !short_message.is_empty() {
+                violations.push(Violation::ShortMessageAndMessagePayload);
+            }
+
+            payload
+        }
+        None => short_message,
+    };
+
+    let udhi = matches!(
+        esm_class.gsm_features,
+        GsmFeatures::UdhiIndicator | GsmFeatures::SetUdhiAndReplyPath
+    );
+
+    let text = if udhi {
+        match udh_length(user_data) {
+            Some(length) => &user_data[length..],
+            None => {
+                violations.push(Violation::InvalidUdh);
+
+                return;
+            }
+        }
+    } else {
+        user_data
+    };
+
+    let valid = match data_coding {
+        DataCoding::Ia5 => text.is_ascii(),
+        DataCoding::Ucs2 => text.len() % 2 == 0,
+        _ => true,
+    };
+
+    if !valid {
+        violations.push(Violation::InvalidDataCoding { data_coding });
+    }
+}
+
+/// Checks that receipts and notifications do not request a receipt themselves.
+pub(crate) fn check_receipt(
+    violations: &mut Vec<Violation>,
+    esm_class: EsmClass,
+    registered_delivery: RegisteredDelivery,
+) {
+    let receipt = matches!(
+        esm_class.message_type,
+        MessageType::ShortMessageContainsMCDeliveryReceipt
+            | MessageType::ShortMessageContainsIntermediateDeliveryNotification
+    );
+
+    if receipt && u8::from(registered_delivery) != 0 {
+        violations.push(Violation::RegisteredDeliveryOnReceipt {
+            message_type: esm_class.message_type,
+        });
+    }
+}
+
+/// Returns the length of the user data header including the length octet,
+/// if `user_data` starts with a structurally valid header.
+fn udh_length(user_data: &[u8]) -> Option<usize> {
+    let (&length, rest) = user_data.split_first()?;
+
+    if length == 0 {
+        return None;
+    }
+
+    let mut elements = rest.get(..length as usize)?;
+
+    while let [_, element_length, rest @ ..] = elements {
+        elements = rest.get(*element_length as usize..)?;
+    }
+
+    elements.is_empty().then_some(length as usize + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn udh() {
+        assert_eq!(udh_length(&[]), None);
+        assert_eq!(udh_length(&[0x05, 0x00, 0x03, 0x01, 0x02, 0x01]), Some(6));
+        assert_eq!(udh_length(&[0x05, 0x00, 0x03, 0x01, 0x02]), None);
+        assert_eq!(udh_length(&[0x05, 0x00, 0x04, 0x01, 0x02, 0x01]), None);
+        assert_eq!(udh_length(&[0x02, 0x00]), None);
+        assert_eq!(
+            udh_length(&[0x06, 0x08, 0x04, 0x12, 0x34, 0x03, 0x01, b'H', b'i']),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn address() {
+        let check = |ton, npi, address| {
+            let mut violations = Vec::new();
+
+            check_address(&mut violations, SmppField::source_addr, ton, npi, address);
+
+            violations.is_empty()
+        };
+
+        assert!(check(Ton::International, Npi::Isdn, ""));
+        assert!(check(Ton::International, Npi::Isdn, "491701234567"));
+        assert!(!check(Ton::International, Npi::Isdn, "+491701234567"));
+        assert!(!check(Ton::International, Npi::Isdn, "4917012345678901"));
+        assert!(check(Ton::National, Npi::Unknown, "01701234567"));
+        assert!(!check(Ton::National, Npi::Unknown, "0170-1234567"));
+        assert!(check(Ton::Alphanumeric, Npi::Unknown, "Rusmpp"));
+        assert!(!check(Ton::Alphanumeric, Npi::Unknown, "RusmppSender"));
+        assert!(check(Ton::Unknown, Npi::Unknown, "Rusmpp"));
+        assert!(!check(Ton::Unknown, Npi::Isdn, "Rusmpp"));
+    }
+
+    #[test]
+    fn user_data() {
+        let check = |esm_class, data_coding, short_message: &[u8], payload: Option<&[u8]>| {
+            let mut violations = Vec::new();
+
+            check_user_data(
+                &mut violations,
+                esm_class,
+                data_coding,
+                short_message,
+                payload,
+            );
+
+            violations
+        };
+
+        let udhi = EsmClass::default().with_udhi_indicator();
+
+        assert!(check(EsmClass::default(), DataCoding::Ia5, b"Hi", None).is_empty());
+        assert!(check(EsmClass::default(), DataCoding::Ia5, b"", Some(b"Hi")).is_empty());
+        assert_eq!(
+            check(EsmClass::default(), DataCoding::Ia5, b"Hi", Some(b"Hi")),
+            [Violation::ShortMessageAndMessagePayload]
+        );
+        assert_eq!(
+            check(EsmClass::default(), DataCoding::Ia5, "Hé".as_bytes(), None),
+            [Violation::InvalidDataCoding {
+                data_coding: DataCoding::Ia5
+            }]
+        );
+        assert_eq!(
+            check(
+                EsmClass::default(),
+                DataCoding::Ucs2,
+                &[0x00, 0x48, 0x00],
+                None
+            ),
+            [Violation::InvalidDataCoding {
+                data_coding: DataCoding::Ucs2
+            }]
+        );
+        assert!(
+            check(
+                udhi,
+                DataCoding::Ucs2,
+                &[0x05, 0x00, 0x03, 0x01, 0x02, 0x01, 0x00, 0x48],
+                None
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            check(udhi, DataCoding::Ucs2, &[0x00, 0x48], None),
+            [Violation::InvalidUdh]
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use super::{DataCoding, TypeOfMessage};
+
+/// Structured view of the [`DataCoding`] as defined by 3GPP TS 23.038 (GSM 03.38).
+///
+/// The coding group is selected by the high nibble of the `data_coding`.
+/// The `0000xxxx` group carries the character sets defined by SMPP, see [`DataCodingScheme::Smpp`].
+///
+/// Reserved bits are ignored while decoding and encoded as `0`.
+///
+/// # Example
+///
+/// ```rust
+/// # use rusmpp_core::values::{Alphabet, CharacterSet, DataCoding, DataCodingScheme, MessageClass};
+/// // Flash SMS using the GSM 7-bit default alphabet.
+/// let scheme = DataCodingScheme::General {
+///     compressed: false,
+///     auto_deletion: false,
+///     message_class: Some(MessageClass::Class0),
+///     alphabet: Alphabet::Gsm7Bit,
+/// };
+///
+/// let data_coding = DataCoding::from(scheme);
+///
+/// assert_eq!(u8::from(data_coding), 0b00010000);
+/// assert_eq!(data_coding.scheme(), scheme);
+/// assert_eq!(scheme.character_set(), Some(CharacterSet::Gsm7Bit));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum DataCodingScheme {
+    /// `0000xxxx`: The character sets defined by SMPP.
+    Smpp(DataCoding),
+    /// `00xxxxxx` and `01xxxxxx`: General data coding indication.
+    ///
+    /// Uncompressed general data codings without a message class and without automatic deletion
+    /// overlap with the `0000xxxx` group and are decoded as [`DataCodingScheme::Smpp`].
+    General {
+        /// The text is compressed.
+        compressed: bool,
+        /// The message is marked for automatic deletion after being read.
+        auto_deletion: bool,
+        message_class: Option<MessageClass>,
+        alphabet: Alphabet,
+    },
+    /// `1100xxxx`, `1101xxxx` and `1110xxxx`: Message waiting indication.
+    MessageWaiting {
+        group: MessageWaitingGroup,
+        /// Sets the indication active or inactive.
+        active: bool,
+        indication: TypeOfMessage,
+    },
+    /// `1111xxxx`: Data coding/message class.
+    MessageClass {
+        message_class: MessageClass,
+        /// Only [`Alphabet::Gsm7Bit`] and [`Alphabet::EightBit`] are defined in this group,
+        /// other alphabets are encoded as [`Alphabet::Gsm7Bit`].
+        alphabet: Alphabet,
+    },
+    /// `1000xxxx` to `1011xxxx`: Reserved coding groups.
+    Reserved(u8),
+}
+
+impl DataCodingScheme {
+    /// Returns the character set of the short message user data.
+    ///
+    /// Returns `None` for reserved alphabets, coding groups and SMPP character sets.
+    ///
+    /// [`DataCoding::McSpecific`] is interpreted as [`CharacterSet::Gsm7Bit`].
+    pub const fn character_set(&self) -> Option<CharacterSet> {
+        match self {
+            Self::Smpp(data_coding) => match data_coding {
+                DataCoding::McSpecific => Some(CharacterSet::Gsm7Bit),
+                DataCoding::Ia5 => Some(CharacterSet::Ia5),
+                DataCoding::OctetUnspecified | DataCoding::OctetUnspecified2 => {
+                    Some(CharacterSet::Octet)
+                }
+                DataCoding::Latin1 => Some(CharacterSet::Latin1),
+                DataCoding::Jis => Some(CharacterSet::Jis),
+                DataCoding::Cyrillic => Some(CharacterSet::Cyrillic),
+                DataCoding::LatinHebrew => Some(CharacterSet::LatinHebrew),
+                DataCoding::Ucs2 => Some(CharacterSet::Ucs2),
+                DataCoding::PictogramEncoding => Some(CharacterSet::Pictogram),
+                DataCoding::Iso2022JpMusicCodes => Some(CharacterSet::Iso2022JpMusicCodes),
+                DataCoding::ExtendedKanjiJis => Some(CharacterSet::ExtendedKanjiJis),
+                DataCoding::Ksc5601 => Some(CharacterSet::Ksc5601),
+                _ => None,
+            },
+            Self::General { alphabet, .. } | Self::MessageClass { alphabet, .. } => {
+                alphabet.character_set()
+            }
+            Self::MessageWaiting { group, .. } => match group {
+                MessageWaitingGroup::Discard | MessageWaitingGroup::StoreGsm7Bit => {
+                    Some(CharacterSet::Gsm7Bit)
+                }
+                MessageWaitingGroup::StoreUcs2 => Some(CharacterSet::Ucs2),
+            },
+            Self::Reserved(_) => None,
+        }
+    }
+
+    /// Returns the message class, if any.
+    pub const fn message_class(&self) -> Option<MessageClass> {
+        match self {
+            Self::General { message_class, .. } => *message_class,
+            Self::MessageClass { message_class, .. } => Some(*message_class),
+            _ => None,
+        }
+    }
+
+    /// Checks if the text is compressed.
+    pub const fn is_compressed(&self) -> bool {
+        matches!(
+            self,
+            Self::General {
+                compressed: true,
+                ..
+            }
+        )
+    }
+}
+
+impl From<u8> for DataCodingScheme {
+    fn from(value: u8) -> Self {
+        match value >> 4 {
+            0b0000 => Self::Smpp(DataCoding::from(value)),
+            0b0001..=0b0111 => Self::General {
+                compressed: value & 0b00100000 != 0,
+                auto_deletion: value & 0b01000000 != 0,
+                message_class: match value & 0b00010000 {
+                    0 => None,
+                    _ => Some(MessageClass::from_bits(value)),
+                },
+                alphabet: Alphabet::from_bits(value >> 2),
+            },
+            0b1100 => Self::message_waiting(MessageWaitingGroup::Discard, value),
+            0b1101 => Self::message_waiting(MessageWaitingGroup::StoreGsm7Bit, value),
+            0b1110 => Self::message_waiting(MessageWaitingGroup::StoreUcs2, value),
+            0b1111 => Self::MessageClass {
+                message_class: MessageClass::from_bits(value),
+                alphabet: match value & 0b00000100 {
+                    0 => Alphabet::Gsm7Bit,
+                    _ => Alphabet::EightBit,
+                },
+            },
+            _ => Self::Reserved(value),
+        }
+    }
+}
+
+impl DataCodingScheme {
+    fn message_waiting(group: MessageWaitingGroup, value: u8) -> Self {
+        Self::MessageWaiting {
+            group,
+            active: value & 0b00001000 != 0,
+            indication: TypeOfMessage::from(value & 0b00000011),
+        }
+    }
+}
+
+impl From<DataCodingScheme> for u8 {
+    fn from(value: DataCodingScheme) -> Self {
+        match value {
+            DataCodingScheme::Smpp(data_coding) => u8::from(data_coding),
+            DataCodingScheme::General {
+                compressed,
+                auto_deletion,
+                message_class,
+                alphabet,
+            } => {
+                let class = match message_class {
+                    Some(message_class) => 0b00010000 | message_class as u8,
+                    None => 0,
+                };
+
+                ((auto_deletion as u8) << 6)
+                    | ((compressed as u8) << 5)
+                    | class
+                    | ((alphabet as u8) << 2)
+            }
+            DataCodingScheme::MessageWaiting {
+                group,
+                active,
+                indication,
+            } => group as u8 | ((active as u8) << 3) | (u8::from(indication) & 0b00000011),
+            DataCodingScheme::MessageClass {
+                message_class,
+                alphabet,
+            } => {
+                let eight_bit = matches!(alphabet, Alphabet::EightBit) as u8;
+
+                0b11110000 | (eight_bit << 2) | message_class as u8
+            }
+            DataCodingScheme::Reserved(value) => value,
+        }
+    }
+}
+
+impl From<DataCoding> for DataCodingScheme {
+    fn from(value: DataCoding) -> Self {
+        Self::from(u8::from(value))
+    }
+}
+
+impl From<DataCodingScheme> for DataCoding {
+    fn from(value: DataCodingScheme) -> Self {
+        Self::from(u8::from(value))
+    }
+}
+
+impl DataCoding {
+    /// Returns the structured [`DataCodingScheme`] view of this data coding.
+    pub fn scheme(self) -> DataCodingScheme {
+        DataCodingScheme::from(self)
+    }
+}
+
+/// The class of a short message, defining where the message is stored.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum MessageClass {
+    /// Displayed immediately and not stored (flash SMS).
+    Class0 = 0b00,
+    /// Mobile equipment specific.
+    Class1 = 0b01,
+    /// SIM specific.
+    Class2 = 0b10,
+    /// Terminal equipment specific.
+    Class3 = 0b11,
+}
+
+impl MessageClass {
+    const fn from_bits(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Class0,
+            0b01 => Self::Class1,
+            0b10 => Self::Class2,
+            _ => Self::Class3,
+        }
+    }
+}
+
+/// The alphabet of the 3GPP TS 23.038 coding groups.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum Alphabet {
+    /// GSM 7-bit default alphabet.
+    Gsm7Bit = 0b00,
+    /// 8-bit data.
+    EightBit = 0b01,
+    /// UCS2 (16-bit).
+    Ucs2 = 0b10,
+    Reserved = 0b11,
+}
+
+impl Alphabet {
+    const fn from_bits(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Gsm7Bit,
+            0b01 => Self::EightBit,
+            0b10 => Self::Ucs2,
+            _ => Self::Reserved,
+        }
+    }
+
+    /// Returns the associated [`CharacterSet`], `None` for [`Alphabet::Reserved`].
+    pub const fn character_set(&self) -> Option<CharacterSet> {
+        match self {
+            Self::Gsm7Bit => Some(CharacterSet::Gsm7Bit),
+            Self::EightBit => Some(CharacterSet::Octet),
+            Self::Ucs2 => Some(CharacterSet::Ucs2),
+            Self::Reserved => None,
+        }
+    }
+}
+
+/// The message waiting indication coding groups.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum MessageWaitingGroup {
+    /// The indication is shown and the message may be discarded, GSM 7-bit default alphabet.
+    Discard = 0b11000000,
+    /// The indication is shown and the message is stored, GSM 7-bit default alphabet.
+    StoreGsm7Bit = 0b11010000,
+    /// The indication is shown and the message is stored, UCS2.
+    StoreUcs2 = 0b11100000,
+}
+
+/// The character set of the short message user data.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum CharacterSet {
+    /// GSM 7-bit default alphabet.
+    Gsm7Bit,
+    /// IA5 (CCITT T.50)/ASCII (ANSI X3.4).
+    Ia5,
+    /// Octet unspecified (8-bit binary).
+    Octet,
+    /// Latin 1 (ISO-8859-1).
+    Latin1,
+    /// JIS (X 0208-1990).
+    Jis,
+    /// Cyrillic (ISO-8859-5).
+    Cyrillic,
+    /// Latin/Hebrew (ISO-8859-8).
+    LatinHebrew,
+    /// UCS2 (ISO/IEC-10646).
+    Ucs2,
+    /// Pictogram Encoding.
+    Pictogram,
+    /// ISO-2022-JP (Music Codes).
+    Iso2022JpMusicCodes,
+    /// Extended Kanji JIS (X 0212-1990).
+    ExtendedKanjiJis,
+    /// KS C 5601.
+    Ksc5601,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u8() {
+        assert_eq!(
+            DataCodingScheme::from(0b00001000),
+            DataCodingScheme::Smpp(DataCoding::Ucs2)
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0b01111001),
+            DataCodingScheme::General {
+                compressed: true,
+                auto_deletion: true,
+                message_class: Some(MessageClass::Class1),
+                alphabet: Alphabet::Ucs2,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0b11011010),
+            DataCodingScheme::MessageWaiting {
+                group: MessageWaitingGroup::StoreGsm7Bit,
+                active: true,
+                indication: TypeOfMessage::ElectronicMailMessageWaiting,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0b11110110),
+            DataCodingScheme::MessageClass {
+                message_class: MessageClass::Class2,
+                alphabet: Alphabet::EightBit,
+            }
+        );
+
+        assert_eq!(
+            DataCodingScheme::from(0b10000001),
+            DataCodingScheme::Reserved(0b10000001)
+        );
+    }
+
+    #[test]
+    fn to_u8_round_trip() {
+        for value in 0..=u8::MAX {
+            // Reserved bits are dropped, e.g. the message class bits if the class is not meaningful.
+            let expected = match value >> 4 {
+                0b0001..=0b0111 if value & 0b00010000 == 0 => value & !0b00000011,
+                0b1100..=0b1110 => value & !0b00000100,
+                0b1111 => value & !0b00001000,
+                _ => value,
+            };
+
+            assert_eq!(u8::from(DataCodingScheme::from(value)), expected);
+        }
+    }
+
+    #[test]
+    fn character_set() {
+        assert_eq!(
+            DataCoding::McSpecific.scheme().character_set(),
+            Some(CharacterSet::Gsm7Bit)
+        );
+        assert_eq!(
+            DataCoding::GsmMessageClassControl.scheme().character_set(),
+            Some(CharacterSet::Ucs2)
+        );
+        assert_eq!(
+            DataCoding::from(0b00010100).scheme().character_set(),
+            Some(CharacterSet::Octet)
+        );
+        assert_eq!(DataCoding::from(0b00001011).scheme().character_set(), None);
+        assert_eq!(DataCoding::from(0b00011100).scheme().character_set(), None);
+    }
+
+    #[test]
+    fn message_class() {
+        assert_eq!(
+            DataCoding::from(0b11110000).scheme().message_class(),
+            Some(MessageClass::Class0)
+        );
+        assert_eq!(
+            DataCoding::from(0b00010011).scheme().message_class(),
+            Some(MessageClass::Class3)
+        );
+        assert_eq!(DataCoding::Latin1.scheme().message_class(), None);
+    }
+}
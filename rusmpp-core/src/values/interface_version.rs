@@ -1,5 +1,7 @@
 use rusmpp_macros::Rusmpp;
 
+use crate::{CommandId, tlvs::TlvTag};
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Rusmpp)]
 #[rusmpp(from_into = skip)]
@@ -14,6 +16,81 @@ pub enum InterfaceVersion {
     Other(u8),
 }
 
+impl InterfaceVersion {
+    /// Returns `true` if this version is `SMPP v5.0` or later.
+    const fn is_v5(self) -> bool {
+        match self {
+            InterfaceVersion::Smpp5_0 => true,
+            InterfaceVersion::Other(value) => value > 0x50,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the command is defined in this interface version.
+    ///
+    /// [`BroadcastSm`](CommandId::BroadcastSm), [`QueryBroadcastSm`](CommandId::QueryBroadcastSm),
+    /// [`CancelBroadcastSm`](CommandId::CancelBroadcastSm) and their responses were introduced in `SMPP v5.0`.
+    pub const fn supports_command(self, id: CommandId) -> bool {
+        self.is_v5()
+            || !matches!(
+                id,
+                CommandId::BroadcastSm
+                    | CommandId::BroadcastSmResp
+                    | CommandId::QueryBroadcastSm
+                    | CommandId::QueryBroadcastSmResp
+                    | CommandId::CancelBroadcastSm
+                    | CommandId::CancelBroadcastSmResp
+            )
+    }
+
+    /// Returns `true` if the command may carry TLVs in this interface version.
+    ///
+    /// [`ReplaceSm`](CommandId::ReplaceSm), [`SubmitSmResp`](CommandId::SubmitSmResp),
+    /// [`SubmitMultiResp`](CommandId::SubmitMultiResp) and [`DeliverSmResp`](CommandId::DeliverSmResp)
+    /// have no TLVs before `SMPP v5.0`.
+    pub const fn supports_tlvs(self, id: CommandId) -> bool {
+        self.is_v5()
+            || !matches!(
+                id,
+                CommandId::ReplaceSm
+                    | CommandId::SubmitSmResp
+                    | CommandId::SubmitMultiResp
+                    | CommandId::DeliverSmResp
+            )
+    }
+
+    /// Returns `true` if the TLV is defined in this interface version.
+    ///
+    /// The congestion, broadcast, billing, network, node and number portability TLVs were introduced in `SMPP v5.0`.
+    /// Unknown tags ([`TlvTag::Other`]) are always considered supported.
+    pub const fn supports_tlv(self, tag: TlvTag) -> bool {
+        self.is_v5()
+            || !matches!(
+                tag,
+                TlvTag::CongestionState
+                    | TlvTag::BroadcastChannelIndicator
+                    | TlvTag::BroadcastContentType
+                    | TlvTag::BroadcastContentTypeInfo
+                    | TlvTag::BroadcastMessageClass
+                    | TlvTag::BroadcastRepNum
+                    | TlvTag::BroadcastFrequencyInterval
+                    | TlvTag::BroadcastAreaIdentifier
+                    | TlvTag::BroadcastErrorStatus
+                    | TlvTag::BroadcastAreaSuccess
+                    | TlvTag::BroadcastEndTime
+                    | TlvTag::BroadcastServiceGroup
+                    | TlvTag::BillingIdentification
+                    | TlvTag::SourceNetworkId
+                    | TlvTag::DestNetworkId
+                    | TlvTag::SourceNodeId
+                    | TlvTag::DestNodeId
+                    | TlvTag::DestAddrNpResolution
+                    | TlvTag::DestAddrNpInformation
+                    | TlvTag::DestAddrNpCountry
+            )
+    }
+}
+
 impl From<InterfaceVersion> for u8 {
     fn from(value: InterfaceVersion) -> Self {
         match value {
@@ -46,4 +123,37 @@ mod tests {
         crate::tests::owned::encode_decode_test_instances::<InterfaceVersion>();
         crate::tests::borrowed::encode_decode_test_instances::<InterfaceVersion>();
     }
+
+    #[test]
+    fn supports_command() {
+        assert!(InterfaceVersion::Smpp5_0.supports_command(CommandId::BroadcastSm));
+        assert!(InterfaceVersion::Other(0x51).supports_command(CommandId::CancelBroadcastSm));
+
+        assert!(InterfaceVersion::Smpp3_4.supports_command(CommandId::SubmitSm));
+        assert!(InterfaceVersion::Smpp3_4.supports_command(CommandId::DataSm));
+        assert!(!InterfaceVersion::Smpp3_4.supports_command(CommandId::BroadcastSm));
+        assert!(!InterfaceVersion::Smpp3_4.supports_command(CommandId::QueryBroadcastSmResp));
+    }
+
+    #[test]
+    fn supports_tlvs() {
+        assert!(InterfaceVersion::Smpp5_0.supports_tlvs(CommandId::ReplaceSm));
+        assert!(InterfaceVersion::Smpp5_0.supports_tlvs(CommandId::DeliverSmResp));
+
+        assert!(InterfaceVersion::Smpp3_4.supports_tlvs(CommandId::SubmitSm));
+        assert!(InterfaceVersion::Smpp3_4.supports_tlvs(CommandId::DataSmResp));
+        assert!(!InterfaceVersion::Smpp3_4.supports_tlvs(CommandId::ReplaceSm));
+        assert!(!InterfaceVersion::Smpp3_4.supports_tlvs(CommandId::SubmitSmResp));
+    }
+
+    #[test]
+    fn supports_tlv() {
+        assert!(InterfaceVersion::Smpp5_0.supports_tlv(TlvTag::BillingIdentification));
+
+        assert!(InterfaceVersion::Smpp3_4.supports_tlv(TlvTag::MessagePayload));
+        assert!(InterfaceVersion::Smpp3_4.supports_tlv(TlvTag::UssdServiceOp));
+        assert!(InterfaceVersion::Smpp3_4.supports_tlv(TlvTag::Other(0x1400)));
+        assert!(!InterfaceVersion::Smpp3_4.supports_tlv(TlvTag::CongestionState));
+        assert!(!InterfaceVersion::Smpp3_4.supports_tlv(TlvTag::DestNetworkId));
+    }
 }
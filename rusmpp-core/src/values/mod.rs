@@ -51,6 +51,11 @@ pub use congestion_state::CongestionState;
 mod data_coding;
 pub use data_coding::DataCoding;
 
+mod data_coding_scheme;
+pub use data_coding_scheme::{
+    Alphabet, CharacterSet, DataCodingScheme, MessageClass, MessageWaitingGroup,
+};
+
 mod delivery_failure_reason;
 pub use delivery_failure_reason::DeliveryFailureReason;
 
@@ -131,6 +136,9 @@ pub use service_type::GenericServiceType;
 mod set_dpf;
 pub use set_dpf::SetDpf;
 
+mod smpp_time;
+pub use smpp_time::{AbsoluteTime, RelativeTime, SmppTime, SmppTimeError};
+
 mod sub_address;
 pub use sub_address::SubaddressTag;
 
@@ -0,0 +1,782 @@
+//! `SMPP` time format.
+//!
+//! Used by `schedule_delivery_time`, `validity_period` and `final_date`.
+//! Times are encoded as `YYMMDDhhmmsstnnp` where `p` is `+` or `-` for absolute times
+//! and `R` for relative times.
+
+use core::time::Duration;
+
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+const SECONDS_PER_QUARTER_HOUR: i64 = 15 * SECONDS_PER_MINUTE as i64;
+
+/// Days in a month of a relative time.
+const DAYS_PER_MONTH: u64 = 30;
+/// Days in a year of a relative time.
+const DAYS_PER_YEAR: u64 = 365;
+
+/// Days between `1970-01-01` and `2000-01-01`.
+const DAYS_UNTIL_2000: i64 = 10_957;
+/// Days between `1970-01-01` and `2100-01-01`.
+const DAYS_UNTIL_2100: i64 = 47_482;
+
+/// An error that can occur when creating or parsing an [`SmppTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmppTimeError {
+    /// The time is not 16 characters long (optionally followed by a null terminator).
+    InvalidLength { actual: usize },
+    /// A character that must be a digit is not.
+    NotADigit { index: usize },
+    /// The last character is not `+`, `-` or `R`.
+    InvalidIndicator { indicator: u8 },
+    /// A field is outside of its valid range.
+    OutOfRange { field: &'static str, value: u32 },
+    /// The duration can not be represented as an [`SmppTime`].
+    DurationOutOfRange,
+}
+
+impl core::fmt::Display for SmppTimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { actual } => {
+                write!(f, "Invalid length. actual: {actual}, expected: 16")
+            }
+            Self::NotADigit { index } => write!(f, "Not a digit at index: {index}"),
+            Self::InvalidIndicator { indicator } => {
+                write!(
+                    f,
+                    "Invalid indicator: {indicator:#04X}, expected: '+', '-' or 'R'"
+                )
+            }
+            Self::OutOfRange { field, value } => {
+                write!(f, "Out of range. field: {field}, value: {value}")
+            }
+            Self::DurationOutOfRange => write!(f, "Duration out of range"),
+        }
+    }
+}
+
+impl core::error::Error for SmppTimeError {}
+
+/// An absolute or relative `SMPP` time.
+///
+/// # Example
+///
+/// ```rust
+/// # use core::time::Duration;
+/// # use rusmpp_core::values::{AbsoluteTime, RelativeTime, SmppTime};
+/// let time: SmppTime = "250314153000408+".parse().unwrap();
+///
+/// assert_eq!(
+///     time,
+///     SmppTime::Absolute(AbsoluteTime::new(2025, 3, 14, 15, 30, 0, 4, 8).unwrap())
+/// );
+///
+/// let time = SmppTime::try_from(Duration::from_secs(2 * 3600 + 30 * 60)).unwrap();
+///
+/// assert_eq!(time, SmppTime::Relative(RelativeTime::new(0, 0, 0, 2, 30, 0).unwrap()));
+/// assert_eq!(time.to_string(), "000000023000000R");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub enum SmppTime {
+    Absolute(AbsoluteTime),
+    Relative(RelativeTime),
+}
+
+impl SmppTime {
+    /// Parses an [`SmppTime`] from `YYMMDDhhmmsstnnp`, optionally followed by a null terminator.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SmppTimeError> {
+        let bytes = match bytes {
+            [bytes @ .., 0] => bytes,
+            _ => bytes,
+        };
+
+        let bytes: &[u8; 16] = bytes.try_into().map_err(|_| SmppTimeError::InvalidLength {
+            actual: bytes.len(),
+        })?;
+
+        match bytes[15] {
+            b'R' => RelativeTime::from_bytes(bytes).map(Self::Relative),
+            b'+' | b'-' => AbsoluteTime::from_bytes(bytes).map(Self::Absolute),
+            indicator => Err(SmppTimeError::InvalidIndicator { indicator }),
+        }
+    }
+
+    /// Formats the [`SmppTime`] as `YYMMDDhhmmsstnnp` without a null terminator.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        match self {
+            Self::Absolute(time) => time.to_bytes(),
+            Self::Relative(time) => time.to_bytes(),
+        }
+    }
+
+    /// Returns `true` if this is an [`SmppTime::Absolute`] time.
+    pub const fn is_absolute(&self) -> bool {
+        matches!(self, Self::Absolute(_))
+    }
+
+    /// Returns `true` if this is an [`SmppTime::Relative`] time.
+    pub const fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative(_))
+    }
+}
+
+impl From<AbsoluteTime> for SmppTime {
+    fn from(value: AbsoluteTime) -> Self {
+        Self::Absolute(value)
+    }
+}
+
+impl From<RelativeTime> for SmppTime {
+    fn from(value: RelativeTime) -> Self {
+        Self::Relative(value)
+    }
+}
+
+/// Creates a [`SmppTime::Relative`] time from a [`Duration`].
+///
+/// See [`RelativeTime::from_duration`].
+impl TryFrom<Duration> for SmppTime {
+    type Error = SmppTimeError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        RelativeTime::from_duration(value).map(Self::Relative)
+    }
+}
+
+impl core::str::FromStr for SmppTime {
+    type Err = SmppTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl core::fmt::Display for SmppTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bytes = self.to_bytes();
+
+        f.write_str(core::str::from_utf8(&bytes).expect("SmppTime is ascii by definition"))
+    }
+}
+
+/// An absolute `SMPP` time `YYMMDDhhmmsstnn(+|-)`.
+///
+/// `nn` is the offset from UTC in quarter hours, `+` meaning ahead of UTC.
+/// The two digit year is interpreted as `2000..=2099`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct AbsoluteTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    tenths: u8,
+    offset: i8,
+}
+
+impl AbsoluteTime {
+    /// Creates a new [`AbsoluteTime`].
+    ///
+    /// `year` must be in `2000..=2099`, `tenths` in `0..=9` and `offset` (in quarter hours) in `-48..=48`.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        tenths: u8,
+        offset: i8,
+    ) -> Result<Self, SmppTimeError> {
+        if year < 2000 || year > 2099 {
+            return Err(out_of_range("year", year as u32));
+        }
+
+        if month < 1 || month > 12 {
+            return Err(out_of_range("month", month as u32));
+        }
+
+        if day < 1 || day > days_in_month(year, month) {
+            return Err(out_of_range("day", day as u32));
+        }
+
+        if hour > 23 {
+            return Err(out_of_range("hour", hour as u32));
+        }
+
+        if minute > 59 {
+            return Err(out_of_range("minute", minute as u32));
+        }
+
+        if second > 59 {
+            return Err(out_of_range("second", second as u32));
+        }
+
+        if tenths > 9 {
+            return Err(out_of_range("tenths", tenths as u32));
+        }
+
+        if offset < -48 || offset > 48 {
+            return Err(out_of_range("offset", offset.unsigned_abs() as u32));
+        }
+
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            tenths,
+            offset,
+        })
+    }
+
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub const fn tenths(&self) -> u8 {
+        self.tenths
+    }
+
+    /// Returns the offset from UTC in quarter hours.
+    pub const fn offset(&self) -> i8 {
+        self.offset
+    }
+
+    /// Creates a new [`AbsoluteTime`] from a [`Duration`] since the Unix epoch (`1970-01-01T00:00:00Z`),
+    /// expressed in the given UTC `offset` (in quarter hours).
+    ///
+    /// Sub-tenth precision is truncated.
+    pub fn from_unix_duration(duration: Duration, offset: i8) -> Result<Self, SmppTimeError> {
+        if !(-48..=48).contains(&offset) {
+            return Err(out_of_range("offset", offset.unsigned_abs() as u32));
+        }
+
+        let seconds = i64::try_from(duration.as_secs())
+            .ok()
+            .and_then(|seconds| seconds.checked_add(offset as i64 * SECONDS_PER_QUARTER_HOUR))
+            .ok_or(SmppTimeError::DurationOutOfRange)?;
+
+        let days = seconds.div_euclid(SECONDS_PER_DAY as i64);
+
+        if !(DAYS_UNTIL_2000..DAYS_UNTIL_2100).contains(&days) {
+            return Err(SmppTimeError::DurationOutOfRange);
+        }
+
+        let (year, month, day) = civil_from_days(days);
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY as i64) as u64;
+
+        Self::new(
+            year,
+            month,
+            day,
+            (seconds / SECONDS_PER_HOUR) as u8,
+            (seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE) as u8,
+            (seconds % SECONDS_PER_MINUTE) as u8,
+            (duration.subsec_millis() / 100) as u8,
+            offset,
+        )
+    }
+
+    /// Returns the [`Duration`] since the Unix epoch (`1970-01-01T00:00:00Z`).
+    pub fn to_unix_duration(&self) -> Duration {
+        let days = days_from_civil(self.year, self.month, self.day);
+
+        let seconds = days * SECONDS_PER_DAY as i64
+            + self.hour as i64 * SECONDS_PER_HOUR as i64
+            + self.minute as i64 * SECONDS_PER_MINUTE as i64
+            + self.second as i64
+            - self.offset as i64 * SECONDS_PER_QUARTER_HOUR;
+
+        // The earliest representable time is `2000-01-01T00:00:00+12:00`, which is after the epoch.
+        Duration::from_secs(seconds as u64) + Duration::from_millis(self.tenths as u64 * 100)
+    }
+
+    fn from_bytes(bytes: &[u8; 16]) -> Result<Self, SmppTimeError> {
+        let offset = two_digits(bytes, 13)? as i8;
+
+        let offset = match bytes[15] {
+            b'+' => offset,
+            b'-' => -offset,
+            indicator => return Err(SmppTimeError::InvalidIndicator { indicator }),
+        };
+
+        Self::new(
+            2000 + two_digits(bytes, 0)? as u16,
+            two_digits(bytes, 2)?,
+            two_digits(bytes, 4)?,
+            two_digits(bytes, 6)?,
+            two_digits(bytes, 8)?,
+            two_digits(bytes, 10)?,
+            digit(bytes, 12)?,
+            offset,
+        )
+    }
+
+    fn to_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+
+        write_two_digits(&mut bytes, 0, (self.year - 2000) as u8);
+        write_two_digits(&mut bytes, 2, self.month);
+        write_two_digits(&mut bytes, 4, self.day);
+        write_two_digits(&mut bytes, 6, self.hour);
+        write_two_digits(&mut bytes, 8, self.minute);
+        write_two_digits(&mut bytes, 10, self.second);
+        bytes[12] = b'0' + self.tenths;
+        write_two_digits(&mut bytes, 13, self.offset.unsigned_abs());
+        bytes[15] = if self.offset < 0 { b'-' } else { b'+' };
+
+        bytes
+    }
+}
+
+/// A relative `SMPP` time `YYMMDDhhmmss000R`.
+///
+/// When converting to and from a [`Duration`], a year is `365` days and a month is `30` days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+#[cfg_attr(feature = "serde-deserialize-unchecked", derive(::serde::Deserialize))]
+pub struct RelativeTime {
+    years: u8,
+    months: u8,
+    days: u8,
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+}
+
+impl RelativeTime {
+    /// Creates a new [`RelativeTime`].
+    ///
+    /// Every field must be in `0..=99`.
+    pub const fn new(
+        years: u8,
+        months: u8,
+        days: u8,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+    ) -> Result<Self, SmppTimeError> {
+        if years > 99 {
+            return Err(out_of_range("years", years as u32));
+        }
+
+        if months > 99 {
+            return Err(out_of_range("months", months as u32));
+        }
+
+        if days > 99 {
+            return Err(out_of_range("days", days as u32));
+        }
+
+        if hours > 99 {
+            return Err(out_of_range("hours", hours as u32));
+        }
+
+        if minutes > 99 {
+            return Err(out_of_range("minutes", minutes as u32));
+        }
+
+        if seconds > 99 {
+            return Err(out_of_range("seconds", seconds as u32));
+        }
+
+        Ok(Self {
+            years,
+            months,
+            days,
+            hours,
+            minutes,
+            seconds,
+        })
+    }
+
+    pub const fn years(&self) -> u8 {
+        self.years
+    }
+
+    pub const fn months(&self) -> u8 {
+        self.months
+    }
+
+    pub const fn days(&self) -> u8 {
+        self.days
+    }
+
+    pub const fn hours(&self) -> u8 {
+        self.hours
+    }
+
+    pub const fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    pub const fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Creates a new normalized [`RelativeTime`] from a [`Duration`].
+    ///
+    /// Sub-second precision is truncated.
+    /// Fails if the duration is `100` years or longer.
+    pub const fn from_duration(duration: Duration) -> Result<Self, SmppTimeError> {
+        let seconds = duration.as_secs();
+        let days = seconds / SECONDS_PER_DAY;
+
+        if days / DAYS_PER_YEAR > 99 {
+            return Err(SmppTimeError::DurationOutOfRange);
+        }
+
+        Ok(Self {
+            years: (days / DAYS_PER_YEAR) as u8,
+            months: (days % DAYS_PER_YEAR / DAYS_PER_MONTH) as u8,
+            days: (days % DAYS_PER_YEAR % DAYS_PER_MONTH) as u8,
+            hours: (seconds % SECONDS_PER_DAY / SECONDS_PER_HOUR) as u8,
+            minutes: (seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE) as u8,
+            seconds: (seconds % SECONDS_PER_MINUTE) as u8,
+        })
+    }
+
+    /// Returns the [`Duration`] of this [`RelativeTime`].
+    pub const fn to_duration(&self) -> Duration {
+        let days = self.years as u64 * DAYS_PER_YEAR
+            + self.months as u64 * DAYS_PER_MONTH
+            + self.days as u64;
+
+        Duration::from_secs(
+            days * SECONDS_PER_DAY
+                + self.hours as u64 * SECONDS_PER_HOUR
+                + self.minutes as u64 * SECONDS_PER_MINUTE
+                + self.seconds as u64,
+        )
+    }
+
+    fn from_bytes(bytes: &[u8; 16]) -> Result<Self, SmppTimeError> {
+        // The `tnn` part must be `000`, but we only require digits here.
+        digit(bytes, 12)?;
+        two_digits(bytes, 13)?;
+
+        Self::new(
+            two_digits(bytes, 0)?,
+            two_digits(bytes, 2)?,
+            two_digits(bytes, 4)?,
+            two_digits(bytes, 6)?,
+            two_digits(bytes, 8)?,
+            two_digits(bytes, 10)?,
+        )
+    }
+
+    fn to_bytes(self) -> [u8; 16] {
+        let mut bytes = *b"000000000000000R";
+
+        write_two_digits(&mut bytes, 0, self.years);
+        write_two_digits(&mut bytes, 2, self.months);
+        write_two_digits(&mut bytes, 4, self.days);
+        write_two_digits(&mut bytes, 6, self.hours);
+        write_two_digits(&mut bytes, 8, self.minutes);
+        write_two_digits(&mut bytes, 10, self.seconds);
+
+        bytes
+    }
+}
+
+impl From<RelativeTime> for Duration {
+    fn from(value: RelativeTime) -> Self {
+        value.to_duration()
+    }
+}
+
+impl TryFrom<Duration> for RelativeTime {
+    type Error = SmppTimeError;
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Self::from_duration(value)
+    }
+}
+
+const fn out_of_range(field: &'static str, value: u32) -> SmppTimeError {
+    SmppTimeError::OutOfRange { field, value }
+}
+
+fn digit(bytes: &[u8; 16], index: usize) -> Result<u8, SmppTimeError> {
+    match bytes[index] {
+        byte @ b'0'..=b'9' => Ok(byte - b'0'),
+        _ => Err(SmppTimeError::NotADigit { index }),
+    }
+}
+
+fn two_digits(bytes: &[u8; 16], index: usize) -> Result<u8, SmppTimeError> {
+    Ok(digit(bytes, index)? * 10 + digit(bytes, index + 1)?)
+}
+
+fn write_two_digits(bytes: &mut [u8; 16], index: usize, value: u8) {
+    bytes[index] = b'0' + value / 10;
+    bytes[index + 1] = b'0' + value % 10;
+}
+
+const fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since `1970-01-01` of a proleptic Gregorian date.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+const fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    let year = year as i64 - (month <= 2) as i64;
+    let month = month as i64;
+
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of the given days since `1970-01-01`.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+const fn civil_from_days(days: i64) -> (u16, u8, u8) {
+    let days = days + 719_468;
+
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    (year as u16, month as u8, day as u8)
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use super::*;
+    use crate::types::owned::EmptyOrFullCOctetString;
+
+    impl From<SmppTime> for EmptyOrFullCOctetString<17> {
+        fn from(value: SmppTime) -> Self {
+            let mut bytes = alloc::vec::Vec::with_capacity(17);
+
+            bytes.extend_from_slice(&value.to_bytes());
+            bytes.push(0);
+
+            Self::from_vec(bytes).expect("SmppTime is a valid EmptyOrFullCOctetString<17>")
+        }
+    }
+
+    /// Fails with [`SmppTimeError::InvalidLength`] if the string is empty.
+    impl TryFrom<&EmptyOrFullCOctetString<17>> for SmppTime {
+        type Error = SmppTimeError;
+
+        fn try_from(value: &EmptyOrFullCOctetString<17>) -> Result<Self, Self::Error> {
+            Self::from_bytes(value.as_ref())
+        }
+    }
+}
+
+/// Fails with [`SmppTimeError::InvalidLength`] if the string is empty.
+impl TryFrom<&crate::types::borrowed::EmptyOrFullCOctetString<'_, 17>> for SmppTime {
+    type Error = SmppTimeError;
+
+    fn try_from(
+        value: &crate::types::borrowed::EmptyOrFullCOctetString<'_, 17>,
+    ) -> Result<Self, Self::Error> {
+        Self::from_bytes(value.bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_format_absolute() {
+        let time: SmppTime = "250314153000408+".parse().unwrap();
+
+        assert_eq!(
+            time,
+            SmppTime::Absolute(AbsoluteTime::new(2025, 3, 14, 15, 30, 0, 4, 8).unwrap())
+        );
+        assert_eq!(time.to_bytes(), *b"250314153000408+");
+
+        let time = SmppTime::from_bytes(b"991231235959948-\0").unwrap();
+
+        assert_eq!(
+            time,
+            SmppTime::Absolute(AbsoluteTime::new(2099, 12, 31, 23, 59, 59, 9, -48).unwrap())
+        );
+        assert_eq!(time.to_bytes(), *b"991231235959948-");
+    }
+
+    #[test]
+    fn parse_format_relative() {
+        let time: SmppTime = "010203040506000R".parse().unwrap();
+
+        assert_eq!(
+            time,
+            SmppTime::Relative(RelativeTime::new(1, 2, 3, 4, 5, 6).unwrap())
+        );
+        assert_eq!(time.to_bytes(), *b"010203040506000R");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            SmppTime::from_bytes(b""),
+            Err(SmppTimeError::InvalidLength { actual: 0 })
+        );
+        assert_eq!(
+            "25031415300040+".parse::<SmppTime>(),
+            Err(SmppTimeError::InvalidLength { actual: 15 })
+        );
+        assert_eq!(
+            "2503141530004a8+".parse::<SmppTime>(),
+            Err(SmppTimeError::NotADigit { index: 13 })
+        );
+        assert_eq!(
+            "250314153000408Z".parse::<SmppTime>(),
+            Err(SmppTimeError::InvalidIndicator { indicator: b'Z' })
+        );
+        assert_eq!(
+            "250230153000408+".parse::<SmppTime>(),
+            Err(SmppTimeError::OutOfRange {
+                field: "day",
+                value: 30
+            })
+        );
+        assert_eq!(
+            "250314153000449+".parse::<SmppTime>(),
+            Err(SmppTimeError::OutOfRange {
+                field: "offset",
+                value: 49
+            })
+        );
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(AbsoluteTime::new(2024, 2, 29, 0, 0, 0, 0, 0).is_ok());
+        assert!(AbsoluteTime::new(2000, 2, 29, 0, 0, 0, 0, 0).is_ok());
+        assert!(AbsoluteTime::new(2025, 2, 29, 0, 0, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn absolute_unix_duration() {
+        // 2025-03-14T13:30:00.4Z
+        let duration = Duration::from_millis(1_741_959_000_400);
+
+        let time = AbsoluteTime::from_unix_duration(duration, 8).unwrap();
+
+        assert_eq!(
+            time,
+            AbsoluteTime::new(2025, 3, 14, 15, 30, 0, 4, 8).unwrap()
+        );
+        assert_eq!(time.to_unix_duration(), duration);
+
+        let time = AbsoluteTime::from_unix_duration(duration, -4).unwrap();
+
+        assert_eq!(
+            time,
+            AbsoluteTime::new(2025, 3, 14, 12, 30, 0, 4, -4).unwrap()
+        );
+        assert_eq!(time.to_unix_duration(), duration);
+
+        assert_eq!(
+            AbsoluteTime::new(2000, 1, 1, 0, 0, 0, 0, 0)
+                .unwrap()
+                .to_unix_duration(),
+            Duration::from_secs(946_684_800)
+        );
+
+        assert_eq!(
+            AbsoluteTime::from_unix_duration(Duration::ZERO, 0),
+            Err(SmppTimeError::DurationOutOfRange)
+        );
+    }
+
+    #[test]
+    fn relative_duration() {
+        let time = RelativeTime::from_duration(Duration::from_secs(
+            400 * SECONDS_PER_DAY + 3 * SECONDS_PER_HOUR + 2 * SECONDS_PER_MINUTE + 1,
+        ))
+        .unwrap();
+
+        assert_eq!(time, RelativeTime::new(1, 1, 5, 3, 2, 1).unwrap());
+        assert_eq!(
+            Duration::from(time),
+            Duration::from_secs(
+                400 * SECONDS_PER_DAY + 3 * SECONDS_PER_HOUR + 2 * SECONDS_PER_MINUTE + 1
+            )
+        );
+
+        assert_eq!(
+            RelativeTime::from_duration(Duration::from_secs(100 * 365 * SECONDS_PER_DAY)),
+            Err(SmppTimeError::DurationOutOfRange)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn empty_or_full_c_octet_string() {
+        use crate::types::owned::EmptyOrFullCOctetString;
+
+        let time: SmppTime = "250314153000408+".parse().unwrap();
+        let string = EmptyOrFullCOctetString::<17>::from(time);
+
+        assert_eq!(string.as_str(), "250314153000408+");
+        assert_eq!(SmppTime::try_from(&string), Ok(time));
+
+        assert_eq!(
+            SmppTime::try_from(&EmptyOrFullCOctetString::<17>::empty()),
+            Err(SmppTimeError::InvalidLength { actual: 0 })
+        );
+    }
+}
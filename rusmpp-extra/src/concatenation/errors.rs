@@ -1,8 +1,11 @@
-//! Errors related to concatenated message creation.
+//! Errors related to concatenated message creation and reassembly.
 
-use rusmpp_core::types::OctetStringError;
+use rusmpp_core::{types::OctetStringError, udhs::errors::ConcatenatedShortMessageError};
 
-use crate::concatenation::{MAX_PARTS, MIN_PARTS};
+use crate::{
+    concatenation::{MAX_PARTS, MIN_PARTS},
+    encoding::errors::DecodeError,
+};
 
 /// Errors that can occur during multipart message creation.
 #[derive(Debug, thiserror::Error)]
@@ -51,3 +54,21 @@ impl<E> MultipartError<E> {
         }
     }
 }
+
+/// Errors that can occur during multipart message reassembly.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ReassemblyError {
+    #[error("Invalid concatenation information: {0}")]
+    Concatenation(
+        #[from]
+        #[source]
+        ConcatenatedShortMessageError,
+    ),
+    #[error("Decode error: {0}")]
+    Decode(
+        #[from]
+        #[source]
+        DecodeError,
+    ),
+}
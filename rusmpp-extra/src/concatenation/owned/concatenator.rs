@@ -29,4 +29,34 @@ pub trait Concatenator {
         max_message_size: usize,
         part_header_size: usize,
     ) -> Result<(Concatenation, DataCoding), Self::Error>;
+
+    /// Splits the encoded message into concatenated parts, their associated [`DataCoding`]
+    /// and the user data header information elements required to decode each part.
+    ///
+    /// The information elements are encoded as `IEI`, `IEDL`, `IED` without the user data header length,
+    /// and must be added to the user data header of every part, next to the concatenation information element.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message to encode and concatenate.
+    /// * `max_message_size` - The maximum size of each message part.
+    /// * `part_header_size` - The size of the header for each part, *`NOT`* including the returned information elements.
+    ///
+    /// # Notes
+    ///
+    /// * The notes of [`Concatenator::concatenate`] apply, with the header sizes including the returned information elements.
+    ///     - A [`Concatenation::Single`] with information elements is preceded by a user data header of `1 + information_elements.len()` bytes.
+    ///
+    /// The default implementation calls [`Concatenator::concatenate`] and returns no information elements.
+    fn concatenate_with_information_elements(
+        &self,
+        message: &str,
+        max_message_size: usize,
+        part_header_size: usize,
+    ) -> Result<(Concatenation, DataCoding, alloc::vec::Vec<u8>), Self::Error> {
+        self.concatenate(message, max_message_size, part_header_size)
+            .map(|(concatenation, data_coding)| {
+                (concatenation, data_coding, alloc::vec::Vec::new())
+            })
+    }
 }
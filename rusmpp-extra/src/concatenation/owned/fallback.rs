@@ -34,4 +34,29 @@ where
             }
         }
     }
+
+    fn concatenate_with_information_elements(
+        &self,
+        message: &str,
+        max_message_size: usize,
+        part_header_size: usize,
+    ) -> Result<(Concatenation, DataCoding, alloc::vec::Vec<u8>), Self::Error> {
+        match self.first.concatenate_with_information_elements(
+            message,
+            max_message_size,
+            part_header_size,
+        ) {
+            Ok(result) => Ok(result),
+            Err(first_err) => {
+                match self.second.concatenate_with_information_elements(
+                    message,
+                    max_message_size,
+                    part_header_size,
+                ) {
+                    Ok(result) => Ok(result),
+                    Err(second_err) => Err(FallbackError::new(first_err, second_err)),
+                }
+            }
+        }
+    }
 }
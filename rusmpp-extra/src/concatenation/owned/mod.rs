@@ -9,4 +9,10 @@ pub use concatenator::Concatenator;
 mod multipart;
 pub use multipart::{SubmitSmMultipartBuilder, SubmitSmMultipartExt};
 
+mod reassembler;
+pub use reassembler::{ExpiredMessage, ReassembledMessage, Reassembler};
+
 mod fallback;
+
+#[cfg(test)]
+mod tests;
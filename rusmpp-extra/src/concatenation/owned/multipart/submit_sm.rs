@@ -10,7 +10,11 @@ use crate::{
         errors::MultipartError,
         owned::{Concatenation, Concatenator},
     },
-    encoding::{gsm7bit::Gsm7BitUnpacked, latin1::Latin1, ucs2::Ucs2},
+    encoding::{
+        gsm7bit::{Gsm7BitPacked, Gsm7BitUnpacked},
+        latin1::Latin1,
+        ucs2::Ucs2,
+    },
     fallback::Fallback,
 };
 
@@ -78,6 +82,11 @@ impl<'a, E> SubmitSmMultipartBuilder<'a, E> {
         self.encoder(Gsm7BitUnpacked::new())
     }
 
+    /// Sets the [`Gsm7BitPacked`] encoder.
+    pub fn gsm7bit_packed(self) -> SubmitSmMultipartBuilder<'a, Gsm7BitPacked> {
+        self.encoder(Gsm7BitPacked::new())
+    }
+
     /// Sets the [`Ucs2`] encoder.
     pub fn ucs2(self) -> SubmitSmMultipartBuilder<'a, Ucs2> {
         self.encoder(Ucs2::new())
@@ -106,9 +115,9 @@ where
 {
     /// Builds the multipart [`SubmitSm`] messages.
     pub fn build(self) -> Result<Vec<SubmitSm>, MultipartError<E::Error>> {
-        let (concatenation, data_coding) = self
+        let (concatenation, data_coding, information_elements) = self
             .encoder
-            .concatenate(
+            .concatenate_with_information_elements(
                 self.short_message,
                 self.max_short_message_size,
                 self.concatenation_type.udh_length(),
@@ -116,7 +125,7 @@ where
             .map_err(MultipartError::concatenation)?;
 
         match concatenation {
-            Concatenation::Single(bytes) => {
+            Concatenation::Single(bytes) if information_elements.is_empty() => {
                 let short_message = OctetString::from_vec(bytes)?;
 
                 let sm = self
@@ -126,6 +135,23 @@ where
 
                 Ok(alloc::vec![sm])
             }
+            Concatenation::Single(bytes) => {
+                let mut payload = Vec::with_capacity(1 + information_elements.len() + bytes.len());
+
+                payload.push(information_elements.len() as u8);
+                payload.extend_from_slice(&information_elements);
+                payload.extend_from_slice(&bytes);
+
+                let short_message = OctetString::from_vec(payload)?;
+
+                let sm = self
+                    .sm
+                    .with_udhi_indicator()
+                    .with_short_message(short_message)
+                    .with_data_coding(data_coding);
+
+                Ok(alloc::vec![sm])
+            }
             Concatenation::Concatenated(parts) => {
                 if parts.len() < MIN_PARTS {
                     return Err(MultipartError::min_part_count(parts.len()));
@@ -152,9 +178,17 @@ where
                             */
                             .concatenated_short_message_unchecked(total_parts, index as u8 + 1);
 
-                        let mut payload = Vec::with_capacity(udh.udh_length() + part.len());
+                        let mut payload = Vec::with_capacity(
+                            udh.udh_length() + information_elements.len() + part.len(),
+                        );
+
+                        let udh_bytes = udh.udh_bytes();
+                        let udh_bytes = udh_bytes.as_bytes();
 
-                        payload.extend_from_slice(udh.udh_bytes().as_bytes());
+                        // The user data header length covers the concatenation and the additional information elements.
+                        payload.push(udh_bytes[0] + information_elements.len() as u8);
+                        payload.extend_from_slice(&udh_bytes[1..]);
+                        payload.extend_from_slice(&information_elements);
                         payload.extend_from_slice(&part);
 
                         let short_message = OctetString::from_vec(payload)?;
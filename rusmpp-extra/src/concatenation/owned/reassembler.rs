@@ -0,0 +1,407 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{ops::Sub, time::Duration};
+
+use rusmpp_core::{
+    pdus::owned::{DataSm, DeliverSm},
+    tlvs::owned::{Tlv, TlvValue},
+    types::owned::COctetString,
+    udhs::{
+        UdhId,
+        concatenation::{ConcatenatedShortMessage8Bit, ConcatenatedShortMessage16Bit},
+    },
+    values::{DataCoding, EsmClass, Npi, Ton},
+};
+
+use crate::{
+    concatenation::errors::ReassemblyError,
+    encoding::{
+        errors::DecodeError,
+        gsm7bit::Gsm7BitNationalAlphabet,
+        owned::{DataCodingDecoder, has_udhi, national_alphabet, payload, split_udh},
+    },
+};
+
+/// A message reassembled from one or more `deliver_sm` or `data_sm` parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReassembledMessage {
+    /// Type of number of the source address.
+    pub source_addr_ton: Ton,
+    /// Numbering plan indicator of the source address.
+    pub source_addr_npi: Npi,
+    /// Source address of the message.
+    pub source_addr: COctetString<1, 21>,
+    /// Destination address of the message.
+    pub destination_addr: COctetString<1, 21>,
+    /// Data coding of the message.
+    pub data_coding: DataCoding,
+    /// Reference number of the concatenated message, `None` if the message was not concatenated.
+    pub reference: Option<u16>,
+    /// Total number of parts of the message.
+    pub total_parts: u8,
+    /// The concatenated user data of all parts, without user data headers.
+    ///
+    /// [`Gsm7BitPacked`](crate::encoding::gsm7bit::Gsm7BitPacked) user data is unpacked to septets.
+    pub user_data: Vec<u8>,
+    /// The decoded message.
+    pub message: String,
+}
+
+/// An incomplete concatenated message that was dropped by [`Reassembler::expire`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiredMessage {
+    /// Source address of the message.
+    pub source_addr: COctetString<1, 21>,
+    /// Reference number of the concatenated message.
+    pub reference: u16,
+    /// Total number of parts of the message.
+    pub total_parts: u8,
+    /// Number of parts received before the message expired.
+    pub received_parts: u8,
+}
+
+/// Reassembles concatenated `deliver_sm` and `data_sm` messages.
+///
+/// Parts are grouped by source address, reference number and total number of parts.
+/// The concatenation information is taken from the user data header if the UDHI bit of the `esm_class` is set,
+/// otherwise from the `sar_msg_ref_num`, `sar_total_segments` and `sar_segment_seqnum` TLVs.
+/// Messages without concatenation information are yielded as they are pushed.
+///
+/// Parts may arrive in any order. Duplicate parts are ignored.
+///
+/// GSM 7-bit messages are decoded with the national language shift tables announced in the user data header of their parts, if any.
+///
+/// The reassembler does not read the clock. The caller passes the current time `T`
+/// (e.g. `std::time::Instant` or `tokio::time::Instant`) to [`push_deliver_sm`](Reassembler::push_deliver_sm),
+/// [`push_data_sm`](Reassembler::push_data_sm) and [`expire`](Reassembler::expire),
+/// and should call [`expire`](Reassembler::expire) periodically to drop incomplete messages.
+#[derive(Debug)]
+pub struct Reassembler<T> {
+    timeout: Duration,
+    decoder: DataCodingDecoder,
+    groups: BTreeMap<GroupKey, Group<T>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct GroupKey {
+    source_addr: COctetString<1, 21>,
+    reference: u16,
+    total_parts: u8,
+}
+
+#[derive(Debug)]
+struct Group<T> {
+    created_at: T,
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    destination_addr: COctetString<1, 21>,
+    data_coding: DataCoding,
+    /// The national language shift tables announced by the parts.
+    national_alphabet: Option<Gsm7BitNationalAlphabet>,
+    parts: Vec<Option<Vec<u8>>>,
+    received_parts: u8,
+}
+
+/// Concatenation information of a single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PartInfo {
+    reference: u16,
+    total_parts: u8,
+    part_number: u8,
+}
+
+/// The fields of a `deliver_sm` or `data_sm` relevant to reassembly.
+struct Part<'a> {
+    source_addr_ton: Ton,
+    source_addr_npi: Npi,
+    source_addr: &'a COctetString<1, 21>,
+    destination_addr: &'a COctetString<1, 21>,
+    esm_class: EsmClass,
+    data_coding: DataCoding,
+    short_message: &'a [u8],
+    tlvs: &'a [Tlv],
+}
+
+impl<T> Reassembler<T>
+where
+    T: Copy + Sub<Output = Duration>,
+{
+    /// Creates a new [`Reassembler`] that drops incomplete messages after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            decoder: DataCodingDecoder::new(),
+            groups: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the [`DataCodingDecoder`] used to decode reassembled messages.
+    pub fn with_decoder(mut self, decoder: DataCodingDecoder) -> Self {
+        self.decoder = decoder;
+        self
+    }
+
+    /// Returns the timeout after which incomplete messages are dropped.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the number of incomplete messages waiting for more parts.
+    pub fn pending(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Pushes a [`DeliverSm`] part.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(message))` if the message is complete.
+    /// - `Ok(None)` if more parts are required or the part is a duplicate.
+    /// - `Err(ReassemblyError)` if the part is invalid or the completed message could not be decoded.
+    pub fn push_deliver_sm(
+        &mut self,
+        deliver_sm: &DeliverSm,
+        now: T,
+    ) -> Result<Option<ReassembledMessage>, ReassemblyError> {
+        self.push(
+            Part {
+                source_addr_ton: deliver_sm.source_addr_ton,
+                source_addr_npi: deliver_sm.source_addr_npi,
+                source_addr: &deliver_sm.source_addr,
+                destination_addr: &deliver_sm.destination_addr,
+                esm_class: deliver_sm.esm_class,
+                data_coding: deliver_sm.data_coding,
+                short_message: deliver_sm.short_message(),
+                tlvs: deliver_sm.tlvs(),
+            },
+            now,
+        )
+    }
+
+    /// Pushes a [`DataSm`] part.
+    ///
+    /// See [`Reassembler::push_deliver_sm`].
+    pub fn push_data_sm(
+        &mut self,
+        data_sm: &DataSm,
+        now: T,
+    ) -> Result<Option<ReassembledMessage>, ReassemblyError> {
+        self.push(
+            Part {
+                source_addr_ton: data_sm.source_addr_ton,
+                source_addr_npi: data_sm.source_addr_npi,
+                source_addr: &data_sm.source_addr,
+                destination_addr: &data_sm.destination_addr,
+                esm_class: data_sm.esm_class,
+                data_coding: data_sm.data_coding,
+                short_message: &[],
+                tlvs: data_sm.tlvs(),
+            },
+            now,
+        )
+    }
+
+    /// Drops the incomplete messages whose first part was pushed at least [`timeout`](Reassembler::timeout) before `now`.
+    pub fn expire(&mut self, now: T) -> Vec<ExpiredMessage> {
+        let timeout = self.timeout;
+        let mut expired = Vec::new();
+
+        self.groups.retain(|key, group| {
+            if now - group.created_at < timeout {
+                return true;
+            }
+
+            expired.push(ExpiredMessage {
+                source_addr: key.source_addr.clone(),
+                reference: key.reference,
+                total_parts: key.total_parts,
+                received_parts: group.received_parts,
+            });
+
+            false
+        });
+
+        expired
+    }
+
+    fn push(
+        &mut self,
+        part: Part<'_>,
+        now: T,
+    ) -> Result<Option<ReassembledMessage>, ReassemblyError> {
+        let data = payload(part.short_message, part.tlvs);
+
+        let (info, national_alphabet, user_data) = if has_udhi(part.esm_class) {
+            let (udh, user_data) = split_udh(data)?;
+
+            (udh_part_info(udh)?, national_alphabet(udh), user_data)
+        } else {
+            (sar_part_info(part.tlvs)?, None, data)
+        };
+
+        let user_data =
+            self.decoder
+                .unpack(part.data_coding, data.len() - user_data.len(), user_data);
+
+        let Some(info) = info.filter(|info| info.total_parts > 1) else {
+            return self
+                .reassembled(&part, national_alphabet, None, 1, user_data)
+                .map(Some);
+        };
+
+        let key = GroupKey {
+            source_addr: part.source_addr.clone(),
+            reference: info.reference,
+            total_parts: info.total_parts,
+        };
+
+        let group = self.groups.entry(key.clone()).or_insert_with(|| Group {
+            created_at: now,
+            source_addr_ton: part.source_addr_ton,
+            source_addr_npi: part.source_addr_npi,
+            destination_addr: part.destination_addr.clone(),
+            data_coding: part.data_coding,
+            national_alphabet: None,
+            parts: alloc::vec![None; info.total_parts as usize],
+            received_parts: 0,
+        });
+
+        let slot = &mut group.parts[info.part_number as usize - 1];
+
+        if slot.is_some() {
+            return Ok(None);
+        }
+
+        *slot = Some(user_data);
+        group.received_parts += 1;
+        group.national_alphabet = group.national_alphabet.or(national_alphabet);
+
+        if group.received_parts < info.total_parts {
+            return Ok(None);
+        }
+
+        let Some(group) = self.groups.remove(&key) else {
+            return Ok(None);
+        };
+
+        let user_data = group.parts.into_iter().flatten().flatten().collect();
+
+        let part = Part {
+            source_addr_ton: group.source_addr_ton,
+            source_addr_npi: group.source_addr_npi,
+            destination_addr: &group.destination_addr,
+            data_coding: group.data_coding,
+            ..part
+        };
+
+        self.reassembled(
+            &part,
+            group.national_alphabet,
+            Some(info.reference),
+            info.total_parts,
+            user_data,
+        )
+        .map(Some)
+    }
+
+    fn reassembled(
+        &self,
+        part: &Part<'_>,
+        national_alphabet: Option<Gsm7BitNationalAlphabet>,
+        reference: Option<u16>,
+        total_parts: u8,
+        user_data: Vec<u8>,
+    ) -> Result<ReassembledMessage, ReassemblyError> {
+        let message =
+            self.decoder
+                .decode_unpacked(part.data_coding, national_alphabet, &user_data)?;
+
+        Ok(ReassembledMessage {
+            source_addr_ton: part.source_addr_ton,
+            source_addr_npi: part.source_addr_npi,
+            source_addr: part.source_addr.clone(),
+            destination_addr: part.destination_addr.clone(),
+            data_coding: part.data_coding,
+            reference,
+            total_parts,
+            user_data,
+            message,
+        })
+    }
+}
+
+/// Finds the concatenation information element in the information elements of a user data header.
+fn udh_part_info(mut udh: &[u8]) -> Result<Option<PartInfo>, ReassemblyError> {
+    while let [id, length, rest @ ..] = udh {
+        let (value, next) = rest
+            .split_at_checked(*length as usize)
+            .ok_or(DecodeError::InvalidUdh)?;
+
+        match (UdhId::from(*id), value) {
+            (UdhId::ConcatenatedShortMessages8Bit, &[reference, total_parts, part_number]) => {
+                let udh = ConcatenatedShortMessage8Bit::new(reference, total_parts, part_number)?;
+
+                return Ok(Some(PartInfo {
+                    reference: udh.reference() as u16,
+                    total_parts: udh.total_parts(),
+                    part_number: udh.part_number(),
+                }));
+            }
+            (
+                UdhId::ConcatenatedShortMessages16Bit,
+                &[reference_hi, reference_lo, total_parts, part_number],
+            ) => {
+                let udh = ConcatenatedShortMessage16Bit::new(
+                    u16::from_be_bytes([reference_hi, reference_lo]),
+                    total_parts,
+                    part_number,
+                )?;
+
+                return Ok(Some(PartInfo {
+                    reference: udh.reference(),
+                    total_parts: udh.total_parts(),
+                    part_number: udh.part_number(),
+                }));
+            }
+            (UdhId::ConcatenatedShortMessages8Bit | UdhId::ConcatenatedShortMessages16Bit, _) => {
+                return Err(DecodeError::InvalidUdh.into());
+            }
+            _ => udh = next,
+        }
+    }
+
+    if !udh.is_empty() {
+        return Err(DecodeError::InvalidUdh.into());
+    }
+
+    Ok(None)
+}
+
+/// Reads the concatenation information from the `sar_msg_ref_num`, `sar_total_segments` and `sar_segment_seqnum` TLVs.
+///
+/// Returns `None` unless all three TLVs are present.
+fn sar_part_info(tlvs: &[Tlv]) -> Result<Option<PartInfo>, ReassemblyError> {
+    let (mut reference, mut total_parts, mut part_number) = (None, None, None);
+
+    for tlv in tlvs {
+        match tlv.value() {
+            Some(TlvValue::SarMsgRefNum(value)) => reference = Some(*value),
+            Some(TlvValue::SarTotalSegments(value)) => total_parts = Some(*value),
+            Some(TlvValue::SarSegmentSeqnum(value)) => part_number = Some(*value),
+            _ => {}
+        }
+    }
+
+    let (Some(reference), Some(total_parts), Some(part_number)) =
+        (reference, total_parts, part_number)
+    else {
+        return Ok(None);
+    };
+
+    let sar = ConcatenatedShortMessage16Bit::new(reference, total_parts, part_number)?;
+
+    Ok(Some(PartInfo {
+        reference: sar.reference(),
+        total_parts: sar.total_parts(),
+        part_number: sar.part_number(),
+    }))
+}
@@ -0,0 +1,581 @@
+use core::{str::FromStr, time::Duration};
+
+use rusmpp_core::{
+    pdus::owned::{DataSm, DeliverSm, SubmitSm},
+    tlvs::owned::{MessageDeliveryRequestTlvValue, MessageSubmissionRequestTlvValue},
+    types::owned::{AnyOctetString, COctetString, OctetString},
+    values::{DataCoding, EsmClass, GsmFeatures, owned::MessagePayload},
+};
+
+use crate::{
+    concatenation::{
+        errors::ReassemblyError,
+        owned::{ExpiredMessage, Reassembler, SubmitSmMultipartExt},
+    },
+    encoding::{
+        charset::Cyrillic,
+        errors::DecodeError,
+        gsm7bit::{Gsm7BitAlphabet, Gsm7BitPacked, Gsm7BitUnpacked},
+        owned::{DataCodingDecoder, DecodeExt},
+        ucs2::Ucs2,
+    },
+};
+
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+const fn at(secs: u64) -> Duration {
+    Duration::from_secs(secs)
+}
+
+fn udhi() -> EsmClass {
+    EsmClass {
+        gsm_features: GsmFeatures::UdhiIndicator,
+        ..Default::default()
+    }
+}
+
+fn source_addr(addr: &str) -> COctetString<1, 21> {
+    COctetString::from_str(addr).unwrap()
+}
+
+fn part(source: &str, udh: &[u8], text: &[u8]) -> DeliverSm {
+    let mut short_message = udh.to_vec();
+    short_message.extend_from_slice(text);
+
+    DeliverSm::builder()
+        .source_addr(source_addr(source))
+        .esm_class(udhi())
+        .short_message(OctetString::from_vec(short_message).unwrap())
+        .build()
+}
+
+fn udh_8bit(reference: u8, total_parts: u8, part_number: u8) -> [u8; 6] {
+    [0x05, 0x00, 0x03, reference, total_parts, part_number]
+}
+
+mod reassembler {
+    use super::*;
+
+    #[test]
+    fn single() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        let deliver_sm = DeliverSm::builder()
+            .source_addr(source_addr("123"))
+            .short_message(OctetString::from_static_slice(b"Hello").unwrap())
+            .build();
+
+        let message = reassembler
+            .push_deliver_sm(&deliver_sm, at(0))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Hello");
+        assert_eq!(message.reference, None);
+        assert_eq!(message.total_parts, 1);
+        assert_eq!(message.source_addr, source_addr("123"));
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn udh_8bit_out_of_order() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        assert!(
+            reassembler
+                .push_deliver_sm(&part("123", &udh_8bit(7, 3, 3), b"!"), at(0))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            reassembler
+                .push_deliver_sm(&part("123", &udh_8bit(7, 3, 1), b"Hello "), at(1))
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(reassembler.pending(), 1);
+
+        let message = reassembler
+            .push_deliver_sm(&part("123", &udh_8bit(7, 3, 2), b"world"), at(2))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Hello world!");
+        assert_eq!(message.user_data, b"Hello world!");
+        assert_eq!(message.reference, Some(7));
+        assert_eq!(message.total_parts, 3);
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn udh_16bit_with_other_information_elements() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        // Application port addressing 8-bit, followed by concatenation 16-bit.
+        let udh = |part_number| {
+            [
+                0x0A,
+                0x04,
+                0x02,
+                0x10,
+                0x20,
+                0x08,
+                0x04,
+                0x01,
+                0x02,
+                0x02,
+                part_number,
+            ]
+        };
+
+        assert!(
+            reassembler
+                .push_deliver_sm(&part("123", &udh(1), b"Hello "), at(0))
+                .unwrap()
+                .is_none()
+        );
+
+        let message = reassembler
+            .push_deliver_sm(&part("123", &udh(2), b"world"), at(0))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Hello world");
+        assert_eq!(message.reference, Some(0x0102));
+    }
+
+    #[test]
+    fn duplicates_are_ignored() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        assert!(
+            reassembler
+                .push_deliver_sm(&part("123", &udh_8bit(1, 2, 1), b"Hello "), at(0))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            reassembler
+                .push_deliver_sm(&part("123", &udh_8bit(1, 2, 1), b"Bye "), at(0))
+                .unwrap()
+                .is_none()
+        );
+
+        let message = reassembler
+            .push_deliver_sm(&part("123", &udh_8bit(1, 2, 2), b"world"), at(0))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Hello world");
+    }
+
+    #[test]
+    fn grouped_by_source_addr() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        for (source, text) in [("123", &b"Hello "[..]), ("456", &b"Bye "[..])] {
+            assert!(
+                reassembler
+                    .push_deliver_sm(&part(source, &udh_8bit(1, 2, 1), text), at(0))
+                    .unwrap()
+                    .is_none()
+            );
+        }
+
+        assert_eq!(reassembler.pending(), 2);
+
+        let message = reassembler
+            .push_deliver_sm(&part("456", &udh_8bit(1, 2, 2), b"world"), at(0))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Bye world");
+        assert_eq!(message.source_addr, source_addr("456"));
+        assert_eq!(reassembler.pending(), 1);
+    }
+
+    #[test]
+    fn sar_tlvs() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        let part = |part_number, text: &'static [u8]| {
+            DataSm::builder()
+                .source_addr(source_addr("123"))
+                .data_coding(DataCoding::Ucs2)
+                .push_tlv(MessageSubmissionRequestTlvValue::SarMsgRefNum(300))
+                .push_tlv(MessageSubmissionRequestTlvValue::SarTotalSegments(2))
+                .push_tlv(MessageSubmissionRequestTlvValue::SarSegmentSeqnum(
+                    part_number,
+                ))
+                .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(AnyOctetString::from_static_slice(text)),
+                ))
+                .build()
+        };
+
+        assert!(
+            reassembler
+                .push_data_sm(&part(2, &[0x00, 0x21]), at(0))
+                .unwrap()
+                .is_none()
+        );
+
+        let message = reassembler
+            .push_data_sm(&part(1, &[0x00, 0x48, 0x00, 0x69]), at(0))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Hi!");
+        assert_eq!(message.reference, Some(300));
+        assert_eq!(message.data_coding, DataCoding::Ucs2);
+    }
+
+    #[test]
+    fn deliver_sm_sar_tlvs() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        let part = |part_number, text: &'static [u8]| {
+            DeliverSm::builder()
+                .source_addr(source_addr("123"))
+                .short_message(OctetString::from_static_slice(text).unwrap())
+                .push_tlv(MessageDeliveryRequestTlvValue::SarMsgRefNum(1))
+                .push_tlv(MessageDeliveryRequestTlvValue::SarTotalSegments(2))
+                .push_tlv(MessageDeliveryRequestTlvValue::SarSegmentSeqnum(
+                    part_number,
+                ))
+                .build()
+        };
+
+        assert!(
+            reassembler
+                .push_deliver_sm(&part(1, b"Hello "), at(0))
+                .unwrap()
+                .is_none()
+        );
+
+        let message = reassembler
+            .push_deliver_sm(&part(2, b"world"), at(0))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(message.message, "Hello world");
+    }
+
+    #[test]
+    fn multipart_round_trip() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        let text = "Hello world! ".repeat(30);
+
+        let submit_sms = SubmitSm::builder()
+            .build()
+            .multipart(&text)
+            .reference_u16(42)
+            .build()
+            .unwrap();
+
+        assert!(submit_sms.len() > 1);
+
+        let mut messages = submit_sms
+            .iter()
+            .rev()
+            .filter_map(|sm| {
+                let deliver_sm = DeliverSm::builder()
+                    .source_addr(source_addr("123"))
+                    .esm_class(sm.esm_class)
+                    .data_coding(sm.data_coding)
+                    .short_message(sm.short_message().clone())
+                    .build();
+
+                reassembler.push_deliver_sm(&deliver_sm, at(0)).unwrap()
+            })
+            .collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(messages.len(), 1);
+
+        let message = messages.remove(0);
+
+        assert_eq!(message.message, text);
+        assert_eq!(message.reference, Some(42));
+    }
+
+    #[test]
+    fn multipart_round_trip_gsm7bit_packed() {
+        let decoder = DataCodingDecoder::new().with_gsm7bit_packed(Gsm7BitPacked::new());
+
+        let mut reassembler = Reassembler::new(TIMEOUT)
+            .with_decoder(DataCodingDecoder::new().with_gsm7bit_packed(Gsm7BitPacked::new()));
+
+        let mut decoded = alloc::string::String::new();
+
+        let text = "Hello {world}! ".repeat(30);
+
+        let submit_sms = SubmitSm::builder()
+            .build()
+            .multipart(&text)
+            .gsm7bit_packed()
+            .build()
+            .unwrap();
+
+        assert!(submit_sms.len() > 1);
+
+        let mut messages = submit_sms
+            .iter()
+            .filter_map(|sm| {
+                let deliver_sm = DeliverSm::builder()
+                    .source_addr(source_addr("123"))
+                    .esm_class(sm.esm_class)
+                    .data_coding(sm.data_coding)
+                    .short_message(sm.short_message().clone())
+                    .build();
+
+                decoded.push_str(&deliver_sm.decode_with(&decoder).unwrap());
+
+                reassembler.push_deliver_sm(&deliver_sm, at(0)).unwrap()
+            })
+            .collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages.remove(0).message, text);
+        assert_eq!(decoded, text);
+    }
+
+    #[test]
+    fn expire() {
+        let mut reassembler = Reassembler::new(TIMEOUT);
+
+        reassembler
+            .push_deliver_sm(&part("123", &udh_8bit(1, 3, 1), b"Hello "), at(0))
+            .unwrap();
+        reassembler
+            .push_deliver_sm(&part("123", &udh_8bit(1, 3, 2), b"world"), at(30))
+            .unwrap();
+        reassembler
+            .push_deliver_sm(&part("456", &udh_8bit(2, 2, 1), b"Bye "), at(30))
+            .unwrap();
+
+        assert!(reassembler.expire(at(59)).is_empty());
+
+        assert_eq!(
+            reassembler.expire(at(60)),
+            [ExpiredMessage {
+                source_addr: source_addr("123"),
+                reference: 1,
+                total_parts: 3,
+                received_parts: 2,
+            }]
+        );
+        assert_eq!(reassembler.pending(), 1);
+
+        // A late part starts a new message.
+        assert!(
+            reassembler
+                .push_deliver_sm(&part("123", &udh_8bit(1, 3, 3), b"!"), at(61))
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(reassembler.pending(), 2);
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn part_number_exceeds_total_parts() {
+            let mut reassembler = Reassembler::new(TIMEOUT);
+
+            let err = reassembler
+                .push_deliver_sm(&part("123", &udh_8bit(1, 2, 3), b"Hello"), at(0))
+                .unwrap_err();
+
+            assert!(matches!(err, ReassemblyError::Concatenation(_)));
+        }
+
+        #[test]
+        fn truncated_udh() {
+            let mut reassembler = Reassembler::new(TIMEOUT);
+
+            let err = reassembler
+                .push_deliver_sm(&part("123", &[0x05, 0x00, 0x03, 0x01], b""), at(0))
+                .unwrap_err();
+
+            assert!(matches!(
+                err,
+                ReassemblyError::Decode(DecodeError::InvalidUdh)
+            ));
+        }
+
+        #[test]
+        fn unsupported_data_coding() {
+            let mut reassembler = Reassembler::new(TIMEOUT);
+
+            let deliver_sm = DeliverSm::builder()
+                .data_coding(DataCoding::PictogramEncoding)
+                .short_message(OctetString::from_static_slice(b"Hello").unwrap())
+                .build();
+
+            let err = reassembler.push_deliver_sm(&deliver_sm, at(0)).unwrap_err();
+
+            assert!(matches!(
+                err,
+                ReassemblyError::Decode(DecodeError::UnsupportedDataCoding(
+                    DataCoding::PictogramEncoding
+                ))
+            ));
+        }
+    }
+}
+
+mod multipart {
+    use super::*;
+
+    #[test]
+    fn national_language_information_elements() {
+        let text = "Türkçe ğüşıöç ".repeat(20);
+
+        let submit_sms = SubmitSm::builder()
+            .build()
+            .multipart(&text)
+            .encoder(Gsm7BitUnpacked::new().with_alphabet(Gsm7BitAlphabet::turkish()))
+            .reference_u8(9)
+            .build()
+            .unwrap();
+
+        assert!(submit_sms.len() > 1);
+
+        let decoder = DataCodingDecoder::new().with_gsm7bit_unpacked(
+            Gsm7BitUnpacked::new().with_alphabet(Gsm7BitAlphabet::turkish()),
+        );
+
+        let mut reassembler = Reassembler::new(TIMEOUT).with_decoder(decoder);
+
+        let mut messages = alloc::vec::Vec::new();
+
+        for (index, sm) in submit_sms.iter().enumerate() {
+            let short_message = sm.short_message();
+
+            assert!(short_message.len() <= 140);
+            assert_eq!(
+                &short_message[..12],
+                [
+                    0x0B,
+                    0x00,
+                    0x03,
+                    0x09,
+                    submit_sms.len() as u8,
+                    index as u8 + 1,
+                    0x25,
+                    0x01,
+                    0x01,
+                    0x24,
+                    0x01,
+                    0x01
+                ]
+            );
+
+            let deliver_sm = DeliverSm::builder()
+                .esm_class(sm.esm_class)
+                .data_coding(sm.data_coding)
+                .short_message(short_message.clone())
+                .build();
+
+            messages.extend(reassembler.push_deliver_sm(&deliver_sm, at(0)).unwrap());
+        }
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message, text);
+    }
+
+    #[test]
+    fn national_language_round_trip_default_decoder() {
+        let text = "Olá, você está? ".repeat(20);
+
+        let submit_sms = SubmitSm::builder()
+            .build()
+            .multipart(&text)
+            .encoder(Gsm7BitPacked::new().with_alphabet(Gsm7BitAlphabet::portuguese()))
+            .build()
+            .unwrap();
+
+        assert!(submit_sms.len() > 1);
+
+        // The shift tables are taken from the user data headers, the decoder uses the default alphabet.
+        let mut reassembler = Reassembler::new(TIMEOUT)
+            .with_decoder(DataCodingDecoder::new().with_gsm7bit_packed(Gsm7BitPacked::new()));
+
+        let mut messages = alloc::vec::Vec::new();
+
+        for sm in &submit_sms {
+            let deliver_sm = DeliverSm::builder()
+                .esm_class(sm.esm_class)
+                .data_coding(sm.data_coding)
+                .short_message(sm.short_message().clone())
+                .build();
+
+            messages.extend(reassembler.push_deliver_sm(&deliver_sm, at(0)).unwrap());
+        }
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message, text);
+    }
+
+    #[test]
+    fn national_language_single() {
+        let submit_sms = SubmitSm::builder()
+            .build()
+            .multipart("Canción")
+            .encoder(Gsm7BitPacked::new().with_alphabet(Gsm7BitAlphabet::spanish()))
+            .build()
+            .unwrap();
+
+        assert_eq!(submit_sms.len(), 1);
+        assert_eq!(
+            submit_sms[0].esm_class.gsm_features,
+            GsmFeatures::UdhiIndicator
+        );
+        assert_eq!(
+            &submit_sms[0].short_message()[..4],
+            [0x03, 0x24, 0x01, 0x02]
+        );
+
+        let decoder = DataCodingDecoder::new()
+            .with_gsm7bit_packed(Gsm7BitPacked::new().with_alphabet(Gsm7BitAlphabet::spanish()));
+
+        assert_eq!(submit_sms[0].decode_with(&decoder).unwrap(), "Canción");
+    }
+
+    #[test]
+    fn charset_fallback() {
+        // cspell: disable-next-line
+        let text = "Привет, как дела? ".repeat(10);
+
+        let submit_sms = SubmitSm::builder()
+            .build()
+            .multipart(&text)
+            .encoder(Gsm7BitUnpacked::new())
+            .fallback(Cyrillic::new())
+            .fallback(Ucs2::new())
+            .build()
+            .unwrap();
+
+        assert_eq!(submit_sms.len(), 2);
+
+        let mut reassembler = Reassembler::new(TIMEOUT);
+        let mut messages = alloc::vec::Vec::new();
+
+        for sm in &submit_sms {
+            assert_eq!(sm.data_coding, DataCoding::Cyrillic);
+
+            let deliver_sm = DeliverSm::builder()
+                .esm_class(sm.esm_class)
+                .data_coding(sm.data_coding)
+                .short_message(sm.short_message().clone())
+                .build();
+
+            messages.extend(reassembler.push_deliver_sm(&deliver_sm, at(0)).unwrap());
+        }
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].message, text);
+    }
+}
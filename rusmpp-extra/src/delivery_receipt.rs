@@ -0,0 +1,151 @@
+//! Delivery receipt support.
+//!
+//! Parses and renders the de-facto delivery receipt format described in Appendix B of the `SMPP` specification:
+//!
+//! ```text
+//! id:IIIIIIIIII sub:SSS dlvrd:DDD submit date:YYMMDDhhmm done date:YYMMDDhhmm stat:DDDDDDD err:E text:...
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use rusmpp_core::values::MessageState;
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod owned;
+
+pub mod errors;
+use errors::DeliveryReceiptError;
+
+/// A date in a delivery receipt, formatted as `YYMMDDhhmm` or `YYMMDDhhmmss`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeliveryReceiptDate {
+    /// Two digit year (`00`-`99`).
+    pub year: u8,
+    /// Month (`01`-`12`).
+    pub month: u8,
+    /// Day (`01`-`31`).
+    pub day: u8,
+    /// Hour (`00`-`23`).
+    pub hour: u8,
+    /// Minute (`00`-`59`).
+    pub minute: u8,
+    /// Second (`00`-`59`), if present.
+    pub second: Option<u8>,
+}
+
+impl DeliveryReceiptDate {
+    /// Creates a new [`DeliveryReceiptDate`] without seconds.
+    pub const fn new(year: u8, month: u8, day: u8, hour: u8, minute: u8) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second: None,
+        }
+    }
+
+    /// Sets the seconds.
+    pub const fn with_second(mut self, second: u8) -> Self {
+        self.second = Some(second);
+        self
+    }
+
+    /// Parses a date from `YYMMDDhhmm` or `YYMMDDhhmmss` digits.
+    pub fn parse(value: &[u8]) -> Option<Self> {
+        if !(value.len() == 10 || value.len() == 12) || !value.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        let two = |index: usize| (value[index] - b'0') * 10 + (value[index + 1] - b'0');
+
+        let date = Self {
+            year: two(0),
+            month: two(2),
+            day: two(4),
+            hour: two(6),
+            minute: two(8),
+            second: (value.len() == 12).then(|| two(10)),
+        };
+
+        date.is_valid().then_some(date)
+    }
+
+    fn is_valid(&self) -> bool {
+        (1..=12).contains(&self.month)
+            && (1..=31).contains(&self.day)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second.is_none_or(|second| second < 60)
+    }
+}
+
+impl FromStr for DeliveryReceiptDate {
+    type Err = DeliveryReceiptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.as_bytes()).ok_or(DeliveryReceiptError::invalid_field("date"))
+    }
+}
+
+impl fmt::Display for DeliveryReceiptDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}{:02}{:02}{:02}{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )?;
+
+        if let Some(second) = self.second {
+            write!(f, "{second:02}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Maps a `stat` value to a [`MessageState`].
+///
+/// Both the abbreviated (`DELIVRD`) and the full (`DELIVERED`) forms are accepted, case-insensitively.
+pub fn parse_stat(stat: &str) -> Option<MessageState> {
+    const STATES: &[(&str, &str, MessageState)] = &[
+        ("SCHEDUL", "SCHEDULED", MessageState::Scheduled),
+        ("ENROUTE", "ENROUTE", MessageState::Enroute),
+        ("DELIVRD", "DELIVERED", MessageState::Delivered),
+        ("EXPIRED", "EXPIRED", MessageState::Expired),
+        ("DELETED", "DELETED", MessageState::Deleted),
+        ("UNDELIV", "UNDELIVERABLE", MessageState::Undeliverable),
+        ("ACCEPTD", "ACCEPTED", MessageState::Accepted),
+        ("UNKNOWN", "UNKNOWN", MessageState::Unknown),
+        ("REJECTD", "REJECTED", MessageState::Rejected),
+        ("SKIPPED", "SKIPPED", MessageState::Skipped),
+    ];
+
+    STATES
+        .iter()
+        .find(|(short, long, _)| {
+            stat.eq_ignore_ascii_case(short) || stat.eq_ignore_ascii_case(long)
+        })
+        .map(|(_, _, state)| *state)
+}
+
+/// Maps a [`MessageState`] to its abbreviated `stat` value.
+pub const fn format_stat(state: MessageState) -> &'static str {
+    match state {
+        MessageState::Scheduled => "SCHEDUL",
+        MessageState::Enroute => "ENROUTE",
+        MessageState::Delivered => "DELIVRD",
+        MessageState::Expired => "EXPIRED",
+        MessageState::Deleted => "DELETED",
+        MessageState::Undeliverable => "UNDELIV",
+        MessageState::Accepted => "ACCEPTD",
+        MessageState::Rejected => "REJECTD",
+        MessageState::Skipped => "SKIPPED",
+        MessageState::Unknown | MessageState::Other(_) => "UNKNOWN",
+    }
+}
+
+#[cfg(test)]
+mod tests;
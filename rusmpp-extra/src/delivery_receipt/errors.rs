@@ -0,0 +1,39 @@
+//! Errors related to delivery receipts.
+
+use rusmpp_core::types::OctetStringError;
+
+/// Errors that can occur while parsing or rendering a delivery receipt.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum DeliveryReceiptError {
+    /// A required field is missing.
+    #[error("Missing field: {field}")]
+    MissingField {
+        /// The name of the missing field.
+        field: &'static str,
+    },
+    /// A field has an invalid value.
+    #[error("Invalid field: {field}")]
+    InvalidField {
+        /// The name of the invalid field.
+        field: &'static str,
+    },
+    /// The rendered receipt does not fit into a short message.
+    #[error("Rendered receipt is not a valid short message: {0}")]
+    ShortMessage(
+        #[from]
+        #[source]
+        OctetStringError,
+    ),
+}
+
+impl DeliveryReceiptError {
+    #[cfg(any(test, feature = "alloc"))]
+    pub(crate) const fn missing_field(field: &'static str) -> Self {
+        Self::MissingField { field }
+    }
+
+    pub(crate) const fn invalid_field(field: &'static str) -> Self {
+        Self::InvalidField { field }
+    }
+}
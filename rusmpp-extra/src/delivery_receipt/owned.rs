@@ -0,0 +1,409 @@
+//! Owned delivery receipt support.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use rusmpp_core::{
+    pdus::owned::{DeliverSm, builders::DeliverSmBuilder},
+    tlvs::owned::{MessageDeliveryRequestTlvValue, TlvValue},
+    types::owned::{COctetString, OctetString},
+    values::{EsmClass, MessageState, MessageType},
+};
+
+use crate::delivery_receipt::{
+    DeliveryReceiptDate, errors::DeliveryReceiptError, format_stat, parse_stat,
+};
+
+/// A typed delivery receipt.
+///
+/// # Parsing
+///
+/// ```rust
+/// use rusmpp_extra::delivery_receipt::{DeliveryReceiptDate, owned::DeliveryReceipt};
+/// use rusmpp_core::values::MessageState;
+///
+/// let receipt = DeliveryReceipt::parse(
+///     b"id:0123456789 sub:001 dlvrd:001 submit date:2510181200 done date:2510181201 stat:DELIVRD err:000 text:Hello",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(receipt.id, "0123456789");
+/// assert_eq!(receipt.state, MessageState::Delivered);
+/// assert_eq!(receipt.done_date, Some(DeliveryReceiptDate::new(25, 10, 18, 12, 1)));
+/// assert_eq!(receipt.text, b"Hello");
+/// ```
+///
+/// # Rendering
+///
+/// ```rust
+/// use rusmpp_extra::delivery_receipt::owned::DeliveryReceipt;
+/// use rusmpp_core::values::MessageState;
+///
+/// let receipt = DeliveryReceipt::builder()
+///     .id("0123456789")
+///     .submitted(1)
+///     .delivered(1)
+///     .state(MessageState::Delivered)
+///     .error("000")
+///     .build();
+///
+/// assert_eq!(
+///     receipt.to_vec(),
+///     b"id:0123456789 sub:001 dlvrd:001 stat:DELIVRD err:000 text:"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DeliveryReceipt {
+    /// The message id allocated to the message by the MC.
+    pub id: String,
+    /// Number of short messages originally submitted (`sub`).
+    pub submitted: Option<u32>,
+    /// Number of short messages delivered (`dlvrd`).
+    pub delivered: Option<u32>,
+    /// The time and date at which the short message was submitted.
+    pub submit_date: Option<DeliveryReceiptDate>,
+    /// The time and date at which the short message reached its final state.
+    pub done_date: Option<DeliveryReceiptDate>,
+    /// The final status of the message (`stat`).
+    pub state: MessageState,
+    /// Network specific error code or an MC error code (`err`).
+    pub error: Option<String>,
+    /// The first characters of the original short message.
+    pub text: Vec<u8>,
+}
+
+impl DeliveryReceipt {
+    pub fn builder() -> DeliveryReceiptBuilder {
+        DeliveryReceiptBuilder::new()
+    }
+
+    /// Parses a delivery receipt from a short message body.
+    ///
+    /// Keys are matched case-insensitively. Only `id` and `stat` are required.
+    pub fn parse(body: &[u8]) -> Result<Self, DeliveryReceiptError> {
+        Fields::parse(body).into_receipt(None, None)
+    }
+
+    /// Parses a delivery receipt from a [`DeliverSm`].
+    ///
+    /// The body is taken from the `short_message`, or from the `message_payload` TLV if the `short_message` is empty.
+    /// The `receipted_message_id` and `message_state` TLVs, when present, take precedence over the `id` and `stat` fields of the body.
+    pub fn from_deliver_sm(deliver_sm: &DeliverSm) -> Result<Self, DeliveryReceiptError> {
+        let mut payload = None;
+        let mut id = None;
+        let mut stat = None;
+
+        for tlv in deliver_sm.tlvs() {
+            match tlv.value() {
+                Some(TlvValue::MessagePayload(value)) => payload = Some(value.value.as_ref()),
+                Some(TlvValue::ReceiptedMessageId(value)) => id = Some(value.as_str()),
+                Some(TlvValue::MessageState(value)) => stat = Some(*value),
+                _ => {}
+            }
+        }
+
+        let short_message: &[u8] = deliver_sm.short_message();
+
+        let body = match payload {
+            Some(payload) if short_message.is_empty() => payload,
+            _ => short_message,
+        };
+
+        Fields::parse(body).into_receipt(id, stat)
+    }
+
+    /// Renders the receipt as a short message body.
+    ///
+    /// Absent optional fields are omitted.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut body = self.header().into_bytes();
+
+        body.extend_from_slice(&self.text);
+
+        body
+    }
+
+    /// Renders the receipt as a `short_message`.
+    pub fn to_short_message(&self) -> Result<OctetString<0, 255>, DeliveryReceiptError> {
+        Ok(OctetString::from_vec(self.to_vec())?)
+    }
+
+    /// Returns the `receipted_message_id` and `message_state` TLVs of the receipt.
+    pub fn tlvs(&self) -> Result<Vec<MessageDeliveryRequestTlvValue>, DeliveryReceiptError> {
+        let id = COctetString::from_str(&self.id)
+            .map_err(|_| DeliveryReceiptError::invalid_field("id"))?;
+
+        Ok(alloc::vec![
+            MessageDeliveryRequestTlvValue::ReceiptedMessageId(id),
+            MessageDeliveryRequestTlvValue::MessageState(self.state),
+        ])
+    }
+
+    /// Completes a [`DeliverSmBuilder`] with the receipt.
+    ///
+    /// Sets the `esm_class` to [`MessageType::ShortMessageContainsMCDeliveryReceipt`], the `short_message` to the rendered receipt
+    /// and pushes the [`tlvs`](Self::tlvs) of the receipt.
+    pub fn to_deliver_sm(
+        &self,
+        builder: DeliverSmBuilder,
+    ) -> Result<DeliverSm, DeliveryReceiptError> {
+        let builder = builder
+            .esm_class(EsmClass::from(u8::from(
+                MessageType::ShortMessageContainsMCDeliveryReceipt,
+            )))
+            .short_message(self.to_short_message()?);
+
+        Ok(self
+            .tlvs()?
+            .into_iter()
+            .fold(builder, |builder, tlv| builder.push_tlv(tlv))
+            .build())
+    }
+
+    fn header(&self) -> String {
+        use fmt::Write;
+
+        let mut header = String::new();
+
+        // Writing to a `String` never fails
+        let _ = write!(header, "id:{}", self.id);
+
+        if let Some(submitted) = self.submitted {
+            let _ = write!(header, " sub:{submitted:03}");
+        }
+
+        if let Some(delivered) = self.delivered {
+            let _ = write!(header, " dlvrd:{delivered:03}");
+        }
+
+        if let Some(submit_date) = self.submit_date {
+            let _ = write!(header, " submit date:{submit_date}");
+        }
+
+        if let Some(done_date) = self.done_date {
+            let _ = write!(header, " done date:{done_date}");
+        }
+
+        let _ = write!(header, " stat:{}", format_stat(self.state));
+
+        if let Some(error) = &self.error {
+            let _ = write!(header, " err:{error}");
+        }
+
+        header.push_str(" text:");
+
+        header
+    }
+}
+
+impl fmt::Display for DeliveryReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}",
+            self.header(),
+            String::from_utf8_lossy(&self.text)
+        )
+    }
+}
+
+impl FromStr for DeliveryReceipt {
+    type Err = DeliveryReceiptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s.as_bytes())
+    }
+}
+
+impl TryFrom<&DeliverSm> for DeliveryReceipt {
+    type Error = DeliveryReceiptError;
+
+    fn try_from(deliver_sm: &DeliverSm) -> Result<Self, Self::Error> {
+        Self::from_deliver_sm(deliver_sm)
+    }
+}
+
+/// Builder for [`DeliveryReceipt`].
+#[derive(Debug, Default)]
+pub struct DeliveryReceiptBuilder {
+    inner: DeliveryReceipt,
+}
+
+impl DeliveryReceiptBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.inner.id = id.into();
+        self
+    }
+
+    pub fn submitted(mut self, submitted: u32) -> Self {
+        self.inner.submitted = Some(submitted);
+        self
+    }
+
+    pub fn delivered(mut self, delivered: u32) -> Self {
+        self.inner.delivered = Some(delivered);
+        self
+    }
+
+    pub fn submit_date(mut self, submit_date: DeliveryReceiptDate) -> Self {
+        self.inner.submit_date = Some(submit_date);
+        self
+    }
+
+    pub fn done_date(mut self, done_date: DeliveryReceiptDate) -> Self {
+        self.inner.done_date = Some(done_date);
+        self
+    }
+
+    pub fn state(mut self, state: MessageState) -> Self {
+        self.inner.state = state;
+        self
+    }
+
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.inner.error = Some(error.into());
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<Vec<u8>>) -> Self {
+        self.inner.text = text.into();
+        self
+    }
+
+    pub fn build(self) -> DeliveryReceipt {
+        self.inner
+    }
+}
+
+/// Raw fields of a receipt body.
+#[derive(Debug, Default)]
+struct Fields<'a> {
+    id: Option<&'a [u8]>,
+    sub: Option<&'a [u8]>,
+    dlvrd: Option<&'a [u8]>,
+    submit_date: Option<&'a [u8]>,
+    done_date: Option<&'a [u8]>,
+    stat: Option<&'a [u8]>,
+    err: Option<&'a [u8]>,
+    text: &'a [u8],
+}
+
+impl<'a> Fields<'a> {
+    fn parse(body: &'a [u8]) -> Self {
+        let (header, text) = match find_key(body, b"text") {
+            Some((start, end)) => (&body[..start], &body[end..]),
+            None => (body, &[][..]),
+        };
+
+        Self {
+            id: field(header, b"id"),
+            sub: field(header, b"sub"),
+            dlvrd: field(header, b"dlvrd"),
+            submit_date: field(header, b"submit date"),
+            done_date: field(header, b"done date"),
+            stat: field(header, b"stat"),
+            err: field(header, b"err"),
+            text,
+        }
+    }
+
+    fn into_receipt(
+        self,
+        id: Option<&str>,
+        stat: Option<MessageState>,
+    ) -> Result<DeliveryReceipt, DeliveryReceiptError> {
+        let id = match id {
+            Some(id) => id.to_string(),
+            None => str_field(self.id, "id")?
+                .ok_or(DeliveryReceiptError::missing_field("id"))?
+                .to_string(),
+        };
+
+        let stat = match stat {
+            Some(stat) => stat,
+            None => {
+                let stat = str_field(self.stat, "stat")?
+                    .ok_or(DeliveryReceiptError::missing_field("stat"))?;
+
+                parse_stat(stat).ok_or(DeliveryReceiptError::invalid_field("stat"))?
+            }
+        };
+
+        let count = |value: Option<&[u8]>, name| {
+            str_field(value, name)?
+                .map(|value| {
+                    value
+                        .parse::<u32>()
+                        .map_err(|_| DeliveryReceiptError::invalid_field(name))
+                })
+                .transpose()
+        };
+
+        let date = |value: Option<&[u8]>, name| {
+            value
+                .map(|value| {
+                    DeliveryReceiptDate::parse(value)
+                        .ok_or(DeliveryReceiptError::invalid_field(name))
+                })
+                .transpose()
+        };
+
+        Ok(DeliveryReceipt {
+            id,
+            submitted: count(self.sub, "sub")?,
+            delivered: count(self.dlvrd, "dlvrd")?,
+            submit_date: date(self.submit_date, "submit date")?,
+            done_date: date(self.done_date, "done date")?,
+            state: stat,
+            error: str_field(self.err, "err")?.map(ToString::to_string),
+            text: self.text.to_vec(),
+        })
+    }
+}
+
+/// Finds `key:` at the start of the body or after a whitespace.
+///
+/// Returns the start of the key and the end of the colon.
+fn find_key(body: &[u8], key: &[u8]) -> Option<(usize, usize)> {
+    let len = key.len() + 1;
+
+    (0..body.len().checked_sub(len)? + 1)
+        .filter(|&start| start == 0 || body[start - 1].is_ascii_whitespace())
+        .find(|&start| {
+            body[start..start + key.len()].eq_ignore_ascii_case(key)
+                && body[start + key.len()] == b':'
+        })
+        .map(|start| (start, start + len))
+}
+
+/// Returns the value of `key:`, up to the next whitespace.
+fn field<'a>(header: &'a [u8], key: &[u8]) -> Option<&'a [u8]> {
+    let (_, end) = find_key(header, key)?;
+
+    let value = &header[end..];
+
+    let len = value
+        .iter()
+        .position(u8::is_ascii_whitespace)
+        .unwrap_or(value.len());
+
+    Some(&value[..len])
+}
+
+fn str_field<'a>(
+    value: Option<&'a [u8]>,
+    name: &'static str,
+) -> Result<Option<&'a str>, DeliveryReceiptError> {
+    value
+        .map(|value| {
+            core::str::from_utf8(value).map_err(|_| DeliveryReceiptError::invalid_field(name))
+        })
+        .transpose()
+}
@@ -0,0 +1,273 @@
+use core::str::FromStr;
+
+use rusmpp_core::{
+    pdus::owned::DeliverSm,
+    tlvs::owned::MessageDeliveryRequestTlvValue,
+    types::owned::{AnyOctetString, COctetString, OctetString},
+    values::{MessageState, MessageType, owned::MessagePayload},
+};
+
+use crate::delivery_receipt::{
+    DeliveryReceiptDate, errors::DeliveryReceiptError, owned::DeliveryReceipt,
+};
+
+const BODY: &[u8] = b"id:0123456789 sub:001 dlvrd:001 submit date:2510181200 done date:251018120130 stat:DELIVRD err:000 text:Hello world";
+
+fn expected() -> DeliveryReceipt {
+    DeliveryReceipt::builder()
+        .id("0123456789")
+        .submitted(1)
+        .delivered(1)
+        .submit_date(DeliveryReceiptDate::new(25, 10, 18, 12, 0))
+        .done_date(DeliveryReceiptDate::new(25, 10, 18, 12, 1).with_second(30))
+        .state(MessageState::Delivered)
+        .error("000")
+        .text(&b"Hello world"[..])
+        .build()
+}
+
+mod parse {
+    use super::*;
+
+    #[test]
+    fn full() {
+        assert_eq!(DeliveryReceipt::parse(BODY).unwrap(), expected());
+    }
+
+    #[test]
+    fn case_insensitive_keys_and_long_stat() {
+        let receipt =
+            DeliveryReceipt::parse(b"ID:abc Sub:2 DLVRD:0 Stat:undeliverable Err:0x1 Text:")
+                .unwrap();
+
+        assert_eq!(receipt.id, "abc");
+        assert_eq!(receipt.submitted, Some(2));
+        assert_eq!(receipt.delivered, Some(0));
+        assert_eq!(receipt.state, MessageState::Undeliverable);
+        assert_eq!(receipt.error.as_deref(), Some("0x1"));
+        assert!(receipt.text.is_empty());
+    }
+
+    #[test]
+    fn text_may_contain_keys() {
+        let receipt = DeliveryReceipt::parse(b"id:1 stat:EXPIRED text:id:2 stat:DELIVRD").unwrap();
+
+        assert_eq!(receipt.id, "1");
+        assert_eq!(receipt.state, MessageState::Expired);
+        assert_eq!(receipt.text, b"id:2 stat:DELIVRD");
+    }
+
+    #[test]
+    fn optional_fields() {
+        let receipt = DeliveryReceipt::parse(b"id:1 stat:REJECTD").unwrap();
+
+        assert_eq!(
+            receipt,
+            DeliveryReceipt::builder()
+                .id("1")
+                .state(MessageState::Rejected)
+                .build()
+        );
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn missing_id() {
+            let err = DeliveryReceipt::parse(b"sub:001 stat:DELIVRD").unwrap_err();
+
+            assert!(matches!(
+                err,
+                DeliveryReceiptError::MissingField { field: "id" }
+            ));
+        }
+
+        #[test]
+        fn missing_stat() {
+            let err = DeliveryReceipt::parse(b"id:1 sub:001").unwrap_err();
+
+            assert!(matches!(
+                err,
+                DeliveryReceiptError::MissingField { field: "stat" }
+            ));
+        }
+
+        #[test]
+        fn unknown_stat() {
+            let err = DeliveryReceipt::parse(b"id:1 stat:FOO").unwrap_err();
+
+            assert!(matches!(
+                err,
+                DeliveryReceiptError::InvalidField { field: "stat" }
+            ));
+        }
+
+        #[test]
+        fn invalid_date() {
+            let err =
+                DeliveryReceipt::parse(b"id:1 done date:2513181200 stat:DELIVRD").unwrap_err();
+
+            assert!(matches!(
+                err,
+                DeliveryReceiptError::InvalidField { field: "done date" }
+            ));
+        }
+
+        #[test]
+        fn invalid_count() {
+            let err = DeliveryReceipt::parse(b"id:1 sub:abc stat:DELIVRD").unwrap_err();
+
+            assert!(matches!(
+                err,
+                DeliveryReceiptError::InvalidField { field: "sub" }
+            ));
+        }
+    }
+}
+
+mod deliver_sm {
+    use super::*;
+
+    #[test]
+    fn short_message() {
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::from_static_slice(BODY).unwrap())
+            .build();
+
+        assert_eq!(DeliveryReceipt::try_from(&deliver_sm).unwrap(), expected());
+    }
+
+    #[test]
+    fn message_payload() {
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::from_static_slice(BODY)),
+            ))
+            .build();
+
+        assert_eq!(
+            DeliveryReceipt::from_deliver_sm(&deliver_sm).unwrap(),
+            expected()
+        );
+    }
+
+    #[test]
+    fn tlvs_take_precedence() {
+        let deliver_sm = DeliverSm::builder()
+            .short_message(OctetString::from_static_slice(BODY).unwrap())
+            .push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+                COctetString::from_str("tlv-id").unwrap(),
+            ))
+            .push_tlv(MessageDeliveryRequestTlvValue::MessageState(
+                MessageState::Expired,
+            ))
+            .build();
+
+        let receipt = DeliveryReceipt::from_deliver_sm(&deliver_sm).unwrap();
+
+        assert_eq!(receipt.id, "tlv-id");
+        assert_eq!(receipt.state, MessageState::Expired);
+        assert_eq!(receipt.submitted, Some(1));
+    }
+
+    #[test]
+    fn tlvs_only() {
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::ReceiptedMessageId(
+                COctetString::from_str("tlv-id").unwrap(),
+            ))
+            .push_tlv(MessageDeliveryRequestTlvValue::MessageState(
+                MessageState::Delivered,
+            ))
+            .build();
+
+        let receipt = DeliveryReceipt::from_deliver_sm(&deliver_sm).unwrap();
+
+        assert_eq!(
+            receipt,
+            DeliveryReceipt::builder()
+                .id("tlv-id")
+                .state(MessageState::Delivered)
+                .build()
+        );
+    }
+}
+
+mod render {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let receipt = expected();
+
+        assert_eq!(receipt.to_vec(), BODY);
+        assert_eq!(DeliveryReceipt::parse(&receipt.to_vec()).unwrap(), receipt);
+        assert_eq!(alloc::format!("{receipt}").as_bytes(), BODY,);
+    }
+
+    #[test]
+    fn to_deliver_sm() {
+        let receipt = expected();
+
+        let deliver_sm = receipt.to_deliver_sm(DeliverSm::builder()).unwrap();
+
+        assert_eq!(
+            deliver_sm.esm_class.message_type,
+            MessageType::ShortMessageContainsMCDeliveryReceipt
+        );
+        assert_eq!(deliver_sm.short_message().as_ref(), BODY);
+        assert_eq!(deliver_sm.tlvs().len(), 2);
+        assert_eq!(
+            DeliveryReceipt::from_deliver_sm(&deliver_sm).unwrap(),
+            receipt
+        );
+    }
+
+    #[test]
+    fn to_deliver_sm_text_too_long() {
+        let receipt = DeliveryReceipt::builder()
+            .id("1")
+            .state(MessageState::Delivered)
+            .text(alloc::vec![b'a'; 255])
+            .build();
+
+        let err = receipt.to_deliver_sm(DeliverSm::builder()).unwrap_err();
+
+        assert!(matches!(err, DeliveryReceiptError::ShortMessage(_)));
+    }
+}
+
+mod date {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        let date = DeliveryReceiptDate::from_str("2502281659").unwrap();
+
+        assert_eq!(date, DeliveryReceiptDate::new(25, 2, 28, 16, 59));
+        assert_eq!(alloc::format!("{date}"), "2502281659");
+
+        let date = DeliveryReceiptDate::from_str("250228165907").unwrap();
+
+        assert_eq!(date.second, Some(7));
+        assert_eq!(alloc::format!("{date}"), "250228165907");
+    }
+
+    #[test]
+    fn invalid() {
+        for value in [
+            "",
+            "25022816",
+            "2502281660",
+            "2500281659",
+            "25022816590a",
+            "25022816596",
+        ] {
+            assert!(
+                DeliveryReceiptDate::parse(value.as_bytes()).is_none(),
+                "{value}"
+            );
+        }
+    }
+}
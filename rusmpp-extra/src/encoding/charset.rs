@@ -0,0 +1,336 @@
+//! Encoding/decoding support for the remaining SMPP character sets.
+//!
+//! | Codec           | [`DataCoding`]                  | Character set                    |
+//! |-----------------|---------------------------------|----------------------------------|
+//! | [`Ia5`]         | [`DataCoding::Ia5`]             | IA5/ASCII                        |
+//! | [`Cyrillic`]    | [`DataCoding::Cyrillic`]        | ISO-8859-5                       |
+//! | [`LatinHebrew`] | [`DataCoding::LatinHebrew`]     | ISO-8859-8                       |
+//! | [`Jis`]         | [`DataCoding::Jis`]             | JIS X 0208, encoded as Shift-JIS |
+//! | [`Ksc5601`]     | [`DataCoding::Ksc5601`]         | KS C 5601, encoded as EUC-KR     |
+//!
+//! Multi-byte characters are never split across message parts.
+
+use rusmpp_core::values::DataCoding;
+
+mod errors;
+pub use errors::{CharsetConcatenateError, CharsetDecodeError, CharsetEncodeError};
+
+macro_rules! declare_charset {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        #[non_exhaustive]
+        pub struct $name {}
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl $name {
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] codec.")]
+            pub const fn new() -> Self {
+                Self {}
+            }
+
+            /// Returns the associated [`DataCoding`].
+            pub const fn data_coding(&self) -> DataCoding {
+                DataCoding::$name
+            }
+        }
+    };
+}
+
+declare_charset!(
+    /// IA5 (CCITT T.50)/ASCII codec.
+    ///
+    /// Every character is encoded into one octet.
+    Ia5
+);
+
+declare_charset!(
+    /// Cyrillic (ISO-8859-5) codec.
+    Cyrillic
+);
+
+declare_charset!(
+    /// Latin/Hebrew (ISO-8859-8) codec.
+    ///
+    /// Characters are encoded in logical order.
+    LatinHebrew
+);
+
+declare_charset!(
+    /// JIS (X 0208-1990) codec.
+    ///
+    /// Characters are encoded as Shift-JIS, using one octet for ASCII and half-width katakana and two octets otherwise.
+    Jis
+);
+
+declare_charset!(
+    /// KS C 5601 codec.
+    ///
+    /// Characters are encoded as EUC-KR, using one octet for ASCII and two octets otherwise.
+    /// Characters outside of KS C 5601, e.g. the Hangul syllables only defined by the Unified Hangul Code, are rejected.
+    Ksc5601
+);
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod impl_owned {
+    use alloc::{string::String, vec::Vec};
+
+    use encoding_rs::{DecoderResult, EncoderResult, Encoding};
+
+    use crate::{
+        concatenation::{
+            MAX_PARTS,
+            owned::{Concatenation, Concatenator},
+        },
+        encoding::owned::{Decoder, Encoder},
+    };
+
+    use super::*;
+
+    /// The character sets supported by this module.
+    #[derive(Debug, Clone, Copy)]
+    enum Charset {
+        Ia5,
+        Cyrillic,
+        LatinHebrew,
+        Jis,
+        Ksc5601,
+    }
+
+    impl Charset {
+        /// Returns the length of the encoded character starting with the given `byte`.
+        const fn char_length(self, byte: u8) -> usize {
+            match self {
+                Self::Ia5 | Self::Cyrillic | Self::LatinHebrew => 1,
+                Self::Jis => match byte {
+                    0x81..=0x9F | 0xE0..=0xFC => 2,
+                    _ => 1,
+                },
+                Self::Ksc5601 => match byte {
+                    0x81..=0xFE => 2,
+                    _ => 1,
+                },
+            }
+        }
+
+        const fn encoding(self) -> Option<&'static Encoding> {
+            match self {
+                Self::Ia5 => None,
+                Self::Cyrillic => Some(encoding_rs::ISO_8859_5),
+                Self::LatinHebrew => Some(encoding_rs::ISO_8859_8),
+                Self::Jis => Some(encoding_rs::SHIFT_JIS),
+                Self::Ksc5601 => Some(encoding_rs::EUC_KR),
+            }
+        }
+
+        /// Splits the encoded bytes into characters.
+        fn chars(self, encoded: &[u8]) -> impl Iterator<Item = &[u8]> {
+            let mut rest = encoded;
+
+            core::iter::from_fn(move || {
+                let (&first, _) = rest.split_first()?;
+                let (character, tail) = rest.split_at(self.char_length(first).min(rest.len()));
+
+                rest = tail;
+
+                Some(character)
+            })
+        }
+
+        fn encode(self, input: &str) -> Result<Vec<u8>, CharsetEncodeError> {
+            let Some(encoding) = self.encoding() else {
+                return input
+                    .chars()
+                    .map(|character| match character.is_ascii() {
+                        true => Ok(character as u8),
+                        false => Err(CharsetEncodeError::UnencodableCharacter(character)),
+                    })
+                    .collect();
+            };
+
+            let mut encoder = encoding.new_encoder();
+
+            let length = encoder
+                .max_buffer_length_from_utf8_without_replacement(input.len())
+                .expect("Message length overflow");
+
+            let mut buffer = alloc::vec![0u8; length];
+
+            let (result, _, written) =
+                encoder.encode_from_utf8_without_replacement(input, &mut buffer, true);
+
+            match result {
+                EncoderResult::InputEmpty => {}
+                EncoderResult::Unmappable(character) => {
+                    return Err(CharsetEncodeError::UnencodableCharacter(character));
+                }
+                // The buffer has the maximum length of the encoded input.
+                EncoderResult::OutputFull => unreachable!("Encoder output buffer too small"),
+            }
+
+            buffer.truncate(written);
+
+            if let Self::Ksc5601 = self {
+                // EUC-KR encodes the Unified Hangul Code extensions outside of the 0xA1..=0xFE range.
+                let is_ksc5601 = |encoded: &[u8]| {
+                    encoded.len() == 1 || encoded.iter().all(|byte| (0xA1..=0xFE).contains(byte))
+                };
+
+                if let Some((character, _)) = input
+                    .chars()
+                    .zip(self.chars(&buffer))
+                    .find(|(_, encoded)| !is_ksc5601(encoded))
+                {
+                    return Err(CharsetEncodeError::UnencodableCharacter(character));
+                }
+            }
+
+            Ok(buffer)
+        }
+
+        fn decode(self, input: &[u8]) -> Result<String, CharsetDecodeError> {
+            let Some(encoding) = self.encoding() else {
+                return match input.iter().position(|byte| !byte.is_ascii()) {
+                    Some(index) => Err(CharsetDecodeError::Malformed(index)),
+                    None => Ok(input.iter().map(|&byte| char::from(byte)).collect()),
+                };
+            };
+
+            let mut decoder = encoding.new_decoder_without_bom_handling();
+
+            let length = decoder
+                .max_utf8_buffer_length_without_replacement(input.len())
+                .expect("Message length overflow");
+
+            let mut output = "\0".repeat(length);
+
+            let (result, read, written) =
+                decoder.decode_to_str_without_replacement(input, &mut output, true);
+
+            match result {
+                DecoderResult::InputEmpty => {}
+                DecoderResult::Malformed(length, consumed) => {
+                    return Err(CharsetDecodeError::Malformed(
+                        read - length as usize - consumed as usize,
+                    ));
+                }
+                // The buffer has the maximum length of the decoded input.
+                DecoderResult::OutputFull => unreachable!("Decoder output buffer too small"),
+            }
+
+            output.truncate(written);
+
+            Ok(output)
+        }
+
+        fn concatenate(
+            self,
+            message: &str,
+            max_message_size: usize,
+            part_header_size: usize,
+        ) -> Result<Concatenation, CharsetConcatenateError> {
+            let encoded = self.encode(message)?;
+
+            if encoded.len() <= max_message_size {
+                return Ok(Concatenation::single(encoded));
+            }
+
+            let part_payload_size = max_message_size.saturating_sub(part_header_size);
+
+            let mut parts: Vec<Vec<u8>> = Vec::new();
+            let mut part = Vec::with_capacity(part_payload_size);
+
+            for character in self.chars(&encoded) {
+                if character.len() > part_payload_size {
+                    return Err(CharsetConcatenateError::PartCapacityExceeded);
+                }
+
+                if part.len() + character.len() > part_payload_size {
+                    parts.push(core::mem::replace(
+                        &mut part,
+                        Vec::with_capacity(part_payload_size),
+                    ));
+                }
+
+                part.extend_from_slice(character);
+            }
+
+            if !part.is_empty() {
+                parts.push(part);
+            }
+
+            if parts.len() > MAX_PARTS {
+                return Err(CharsetConcatenateError::parts_count_exceeded(parts.len()));
+            }
+
+            Ok(Concatenation::concatenated(parts))
+        }
+    }
+
+    macro_rules! impl_charset {
+        ($name:ident) => {
+            impl $name {
+                const fn charset(&self) -> Charset {
+                    Charset::$name
+                }
+
+                /// Encodes the given message into a vector of bytes.
+                pub fn encode_to_vec(&self, input: &str) -> Result<Vec<u8>, CharsetEncodeError> {
+                    self.charset().encode(input)
+                }
+
+                /// Decodes the given bytes into a string.
+                pub fn decode_to_string(&self, input: &[u8]) -> Result<String, CharsetDecodeError> {
+                    self.charset().decode(input)
+                }
+            }
+
+            impl Encoder for $name {
+                type Error = CharsetEncodeError;
+
+                fn encode(&self, message: &str) -> Result<(Vec<u8>, DataCoding), Self::Error> {
+                    self.encode_to_vec(message)
+                        .map(|vec| (vec, self.data_coding()))
+                }
+            }
+
+            impl Decoder for $name {
+                type Error = CharsetDecodeError;
+
+                fn decode(&self, bytes: &[u8]) -> Result<String, Self::Error> {
+                    self.decode_to_string(bytes)
+                }
+            }
+
+            impl Concatenator for $name {
+                type Error = CharsetConcatenateError;
+
+                fn concatenate(
+                    &self,
+                    message: &str,
+                    max_message_size: usize,
+                    part_header_size: usize,
+                ) -> Result<(Concatenation, DataCoding), Self::Error> {
+                    self.charset()
+                        .concatenate(message, max_message_size, part_header_size)
+                        .map(|concatenation| (concatenation, self.data_coding()))
+                }
+            }
+        };
+    }
+
+    impl_charset!(Ia5);
+    impl_charset!(Cyrillic);
+    impl_charset!(LatinHebrew);
+    impl_charset!(Jis);
+    impl_charset!(Ksc5601);
+}
+
+#[cfg(test)]
+mod tests;
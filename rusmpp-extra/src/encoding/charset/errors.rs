@@ -0,0 +1,53 @@
+use crate::concatenation::MAX_PARTS;
+
+/// Errors that can occur during character set encoding.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CharsetEncodeError {
+    /// Input contains un-encodable character.
+    #[error("Input contains un-encodable character: '{0}'")]
+    UnencodableCharacter(char),
+}
+
+/// Errors that can occur during character set decoding.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CharsetDecodeError {
+    /// Input contains a byte sequence that is not valid in the character set.
+    #[error("Input contains a malformed byte sequence at index {0}")]
+    Malformed(usize),
+}
+
+/// Errors that can occur during character set concatenation.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CharsetConcatenateError {
+    /// Encoding error.
+    #[error("Encoding error: {0}")]
+    Encode(
+        #[from]
+        #[source]
+        CharsetEncodeError,
+    ),
+    /// Part cannot fit even a single character.
+    ///
+    /// This error is returned when `max_message_size - part_header_size` is smaller than the size of an encoded character.
+    #[error(
+        "Cannot fit even a single character into a part with the given header and size constraints"
+    )]
+    PartCapacityExceeded,
+    #[error("The number of parts exceeds the maximum allowed. actual: {actual}, max: {max}")]
+    /// The number of parts exceeds the maximum allowed.
+    PartsCountExceeded {
+        /// The maximum allowed number of parts.
+        max: usize,
+        /// The actual number of parts.
+        actual: usize,
+    },
+}
+
+impl CharsetConcatenateError {
+    pub(crate) const fn parts_count_exceeded(actual: usize) -> Self {
+        Self::PartsCountExceeded {
+            max: MAX_PARTS,
+            actual,
+        }
+    }
+}
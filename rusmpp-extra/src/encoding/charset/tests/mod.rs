@@ -0,0 +1,2 @@
+#[cfg(feature = "alloc")]
+mod owned;
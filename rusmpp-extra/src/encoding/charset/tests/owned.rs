@@ -0,0 +1,163 @@
+use crate::{
+    concatenation::{MAX_PARTS, owned::Concatenator},
+    encoding::{
+        charset::{
+            CharsetConcatenateError, CharsetDecodeError, CharsetEncodeError, Cyrillic, Ia5, Jis,
+            Ksc5601, LatinHebrew,
+        },
+        owned::{Decoder, Encoder},
+    },
+};
+
+mod encode {
+    use super::*;
+
+    #[test]
+    fn cases() {
+        assert_eq!(Ia5::new().encode_to_vec("Hi!").unwrap(), b"Hi!");
+        // cspell: disable
+        assert_eq!(
+            Cyrillic::new().encode_to_vec("Привет").unwrap(),
+            [0xBF, 0xE0, 0xD8, 0xD2, 0xD5, 0xE2]
+        );
+        assert_eq!(
+            LatinHebrew::new().encode_to_vec("שלום").unwrap(),
+            [0xF9, 0xEC, 0xE5, 0xED]
+        );
+        assert_eq!(
+            Jis::new().encode_to_vec("Aこんにちは").unwrap(),
+            [
+                0x41, 0x82, 0xB1, 0x82, 0xF1, 0x82, 0xC9, 0x82, 0xBF, 0x82, 0xCD
+            ]
+        );
+        assert_eq!(
+            Ksc5601::new().encode_to_vec("A안녕").unwrap(),
+            [0x41, 0xBE, 0xC8, 0xB3, 0xE7]
+        );
+        // cspell: enable
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn unencodable_character() {
+            assert_eq!(
+                Ia5::new().encode("Héllo").unwrap_err(),
+                CharsetEncodeError::UnencodableCharacter('é')
+            );
+            assert_eq!(
+                Cyrillic::new().encode("Hi 😀").unwrap_err(),
+                CharsetEncodeError::UnencodableCharacter('😀')
+            );
+            assert_eq!(
+                Jis::new().encode("안녕").unwrap_err(),
+                CharsetEncodeError::UnencodableCharacter('안')
+            );
+        }
+
+        // Encoded by EUC-KR using the Unified Hangul Code extension.
+        #[test]
+        fn ksc5601_unified_hangul_code() {
+            assert_eq!(
+                Ksc5601::new().encode("A똠").unwrap_err(),
+                CharsetEncodeError::UnencodableCharacter('똠')
+            );
+        }
+    }
+}
+
+mod decode {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        fn round_trip<C>(codec: C, input: &str)
+        where
+            C: Encoder<Error = CharsetEncodeError> + Decoder<Error = CharsetDecodeError>,
+        {
+            let (encoded, _) = codec.encode(input).unwrap();
+
+            assert_eq!(codec.decode(&encoded).unwrap(), input);
+        }
+
+        // cspell: disable
+        round_trip(Ia5::new(), "Hello, World!");
+        round_trip(Cyrillic::new(), "Съешь же ещё этих булок");
+        round_trip(LatinHebrew::new(), "שלום עולם");
+        round_trip(Jis::new(), "日本語のテキスト ｶﾀｶﾅ");
+        round_trip(Ksc5601::new(), "안녕하세요 세계");
+        // cspell: enable
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            Ia5::new().decode(&[0x41, 0x80]).unwrap_err(),
+            CharsetDecodeError::Malformed(1)
+        );
+        assert_eq!(
+            Jis::new().decode(&[0x41, 0x42, 0x82]).unwrap_err(),
+            CharsetDecodeError::Malformed(2)
+        );
+    }
+}
+
+mod concatenate {
+    use super::*;
+
+    #[test]
+    fn does_not_split_characters() {
+        // cspell: disable-next-line
+        let message = "Aこんにちは";
+
+        let (concatenation, data_coding) = Jis::new().concatenate(message, 6, 2).unwrap();
+
+        assert_eq!(data_coding, Jis::new().data_coding());
+
+        let parts = concatenation.collect();
+
+        assert_eq!(
+            parts,
+            [
+                &[0x41, 0x82, 0xB1][..],
+                &[0x82, 0xF1, 0x82, 0xC9],
+                &[0x82, 0xBF, 0x82, 0xCD]
+            ]
+        );
+    }
+
+    #[test]
+    fn single() {
+        let (concatenation, _) = Cyrillic::new().concatenate("Да", 2, 1).unwrap();
+
+        assert_eq!(concatenation.collect(), [[0xB4, 0xD0]]);
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn part_capacity_exceeded() {
+            // cspell: disable-next-line
+            let err = Ksc5601::new().concatenate("안녕하세요", 5, 4).unwrap_err();
+
+            assert_eq!(err, CharsetConcatenateError::PartCapacityExceeded);
+        }
+
+        #[test]
+        fn parts_count_exceeded() {
+            let message = "123456".repeat(MAX_PARTS + 1);
+
+            let err = Ia5::new().concatenate(&message, 6, 0).unwrap_err();
+
+            assert_eq!(
+                err,
+                CharsetConcatenateError::PartsCountExceeded {
+                    max: MAX_PARTS,
+                    actual: 256
+                }
+            );
+        }
+    }
+}
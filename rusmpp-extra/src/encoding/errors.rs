@@ -1,6 +1,10 @@
 //! Errors related to encoded message creation.
 
-use rusmpp_core::types::OctetStringError;
+use rusmpp_core::{types::OctetStringError, values::DataCoding};
+
+use crate::encoding::{
+    charset::CharsetDecodeError, gsm7bit::Gsm7BitDecodeError, ucs2::Ucs2DecodeError,
+};
 
 /// Errors that can occur during encoded message creation.
 #[derive(Debug, thiserror::Error)]
@@ -21,3 +25,31 @@ impl<E> EncodeError<E> {
         Self::Encode(error)
     }
 }
+
+/// Errors that can occur during message decoding.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    #[error("Unsupported data coding: {0:?}")]
+    UnsupportedDataCoding(DataCoding),
+    #[error("Invalid user data header")]
+    InvalidUdh,
+    #[error("GSM 7-bit decode error: {0}")]
+    Gsm7Bit(
+        #[from]
+        #[source]
+        Gsm7BitDecodeError,
+    ),
+    #[error("UCS2 decode error: {0}")]
+    Ucs2(
+        #[from]
+        #[source]
+        Ucs2DecodeError,
+    ),
+    #[error("Character set decode error: {0}")]
+    Charset(
+        #[from]
+        #[source]
+        CharsetDecodeError,
+    ),
+}
@@ -2,10 +2,14 @@
 
 mod alphabet;
 mod errors;
+mod packed;
 mod unpacked;
 
-pub use alphabet::{Gsm7BitAlphabet, Gsm7BitDefaultAlphabet};
-pub use errors::{Gsm7BitConcatenateError, Gsm7BitEncodeError};
+pub use alphabet::{
+    Gsm7BitAlphabet, Gsm7BitDefaultAlphabet, Gsm7BitNationalAlphabet, NationalLanguage,
+};
+pub use errors::{Gsm7BitConcatenateError, Gsm7BitDecodeError, Gsm7BitEncodeError};
+pub use packed::Gsm7BitPacked;
 pub use unpacked::Gsm7BitUnpacked;
 
 #[cfg(test)]
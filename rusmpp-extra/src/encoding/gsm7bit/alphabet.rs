@@ -1,6 +1,10 @@
+use rusmpp_core::udhs::UdhId;
+
 mod default;
+mod national;
 
 pub use default::Gsm7BitDefaultAlphabet;
+pub use national::{Gsm7BitNationalAlphabet, NationalLanguage};
 
 /// Gsm 7-bit escape character.
 pub const ESCAPE_CHARACTER: u8 = 0x1B;
@@ -11,6 +15,8 @@ pub const ESCAPE_CHARACTER: u8 = 0x1B;
 pub enum Gsm7BitAlphabet {
     /// Default GSM 7-bit alphabet.
     Default(Gsm7BitDefaultAlphabet),
+    /// GSM 7-bit alphabet using national language shift tables.
+    National(Gsm7BitNationalAlphabet),
 }
 
 impl Default for Gsm7BitAlphabet {
@@ -24,6 +30,53 @@ impl Gsm7BitAlphabet {
         Self::Default(Gsm7BitDefaultAlphabet::new())
     }
 
+    /// Creates a [`Gsm7BitAlphabet::National`] using the locking and single shift tables of `language`.
+    pub const fn national(language: NationalLanguage) -> Self {
+        Self::National(Gsm7BitNationalAlphabet::new(language))
+    }
+
+    /// Creates a [`Gsm7BitAlphabet::National`] using the Turkish locking and single shift tables.
+    pub const fn turkish() -> Self {
+        Self::national(NationalLanguage::Turkish)
+    }
+
+    /// Creates a [`Gsm7BitAlphabet::National`] using the default alphabet and the Spanish single shift table.
+    pub const fn spanish() -> Self {
+        Self::national(NationalLanguage::Spanish)
+    }
+
+    /// Creates a [`Gsm7BitAlphabet::National`] using the Portuguese locking and single shift tables.
+    pub const fn portuguese() -> Self {
+        Self::national(NationalLanguage::Portuguese)
+    }
+
+    /// Returns the national language shift user data header information elements required to decode messages encoded with this alphabet.
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub(crate) fn information_elements(&self) -> alloc::vec::Vec<u8> {
+        let mut information_elements = alloc::vec::Vec::with_capacity(6);
+
+        if let Self::National(alphabet) = self {
+            if let Some(language) = alphabet.effective_locking_shift() {
+                information_elements.extend_from_slice(&[
+                    UdhId::NationalLanguageLockingShift.into(),
+                    1,
+                    language.id(),
+                ]);
+            }
+
+            if let Some(language) = alphabet.single_shift() {
+                information_elements.extend_from_slice(&[
+                    UdhId::NationalLanguageSingleShift.into(),
+                    1,
+                    language.id(),
+                ]);
+            }
+        }
+
+        information_elements
+    }
+
     /// # Returns
     ///
     /// - `Some(Encoded)` if the character is found in the GSM 7-bit tables.
@@ -31,6 +84,7 @@ impl Gsm7BitAlphabet {
     const fn encode(&self, ch: char) -> Option<Encoded> {
         match self {
             Self::Default(alphabet) => alphabet.encode(ch),
+            Self::National(alphabet) => alphabet.encode(ch),
         }
     }
 
@@ -68,6 +122,60 @@ impl Gsm7BitAlphabet {
 
         Ok(encoded)
     }
+
+    /// Decodes a standard septet.
+    const fn decode_standard(&self, byte: u8) -> Option<char> {
+        match self {
+            Self::Default(alphabet) => alphabet.decode_standard(byte),
+            Self::National(alphabet) => alphabet.decode_standard(byte),
+        }
+    }
+
+    /// Decodes the septet following an escape character.
+    const fn decode_extended(&self, byte: u8) -> Option<char> {
+        match self {
+            Self::Default(alphabet) => alphabet.decode_extended(byte),
+            Self::National(alphabet) => alphabet.decode_extended(byte),
+        }
+    }
+
+    /// Decodes the given GSM 7-bit septets, one per byte, into a string.
+    ///
+    /// As recommended by 3GPP TS 23.038:
+    ///
+    /// - An escape character followed by a septet that is not in the extension table is decoded as the standard character of that septet.
+    /// - Two consecutive escape characters are decoded as a space.
+    ///
+    /// A trailing escape character is ignored.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `Err(u8)` if a byte is not a valid septet.
+    #[cfg(any(test, feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub(crate) fn decode_to_string(&self, septets: &[u8]) -> Result<alloc::string::String, u8> {
+        let mut decoded = alloc::string::String::with_capacity(septets.len());
+
+        let mut septets = septets.iter().copied();
+
+        while let Some(byte) = septets.next() {
+            let ch = match byte {
+                ESCAPE_CHARACTER => match septets.next() {
+                    None => break,
+                    Some(ESCAPE_CHARACTER) => ' ',
+                    Some(byte) => self
+                        .decode_extended(byte)
+                        .or_else(|| self.decode_standard(byte))
+                        .ok_or(byte)?,
+                },
+                byte => self.decode_standard(byte).ok_or(byte)?,
+            };
+
+            decoded.push(ch);
+        }
+
+        Ok(decoded)
+    }
 }
 
 enum Encoded {
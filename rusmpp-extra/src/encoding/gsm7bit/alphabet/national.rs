@@ -0,0 +1,319 @@
+use super::{ESCAPE_CHARACTER, Encoded};
+
+/// National language identifier of the GSM 7-bit national language shift tables.
+///
+/// See 3GPP TS 23.038, section 6.2.1.2.4.
+#[repr(u8)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NationalLanguage {
+    /// Turkish.
+    Turkish = 0x01,
+    /// Spanish.
+    ///
+    /// Spanish only defines a single shift table. Its locking shift table is the default alphabet.
+    Spanish = 0x02,
+    /// Portuguese.
+    Portuguese = 0x03,
+}
+
+impl NationalLanguage {
+    /// Returns the national language identifier used in the shift information elements.
+    pub const fn id(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the national language with the given identifier, or `None` if the language is not supported.
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0x01 => Some(Self::Turkish),
+            0x02 => Some(Self::Spanish),
+            0x03 => Some(Self::Portuguese),
+            _ => None,
+        }
+    }
+
+    /// Returns the locking shift table, or `None` if the language uses the default alphabet.
+    const fn locking_shift_table(self) -> Option<&'static [char; 128]> {
+        match self {
+            Self::Turkish => Some(&TURKISH_LOCKING_SHIFT),
+            Self::Spanish => None,
+            Self::Portuguese => Some(&PORTUGUESE_LOCKING_SHIFT),
+        }
+    }
+
+    /// Returns the single shift table.
+    const fn single_shift_table(self) -> &'static [(u8, char)] {
+        match self {
+            Self::Turkish => TURKISH_SINGLE_SHIFT,
+            Self::Spanish => SPANISH_SINGLE_SHIFT,
+            Self::Portuguese => PORTUGUESE_SINGLE_SHIFT,
+        }
+    }
+}
+
+/// GSM 7-bit alphabet using the national language shift tables of 3GPP TS 23.038.
+///
+/// The locking shift table replaces the default alphabet, the single shift table replaces the default alphabet extension table.
+/// Without a locking or single shift language, the respective default table is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gsm7BitNationalAlphabet {
+    locking_shift: Option<NationalLanguage>,
+    single_shift: Option<NationalLanguage>,
+}
+
+impl Gsm7BitNationalAlphabet {
+    /// Creates a new [`Gsm7BitNationalAlphabet`] using the locking and single shift tables of `language`.
+    pub const fn new(language: NationalLanguage) -> Self {
+        Self {
+            locking_shift: Some(language),
+            single_shift: Some(language),
+        }
+    }
+
+    /// Sets the locking shift language.
+    pub const fn with_locking_shift(mut self, language: Option<NationalLanguage>) -> Self {
+        self.locking_shift = language;
+        self
+    }
+
+    /// Sets the single shift language.
+    pub const fn with_single_shift(mut self, language: Option<NationalLanguage>) -> Self {
+        self.single_shift = language;
+        self
+    }
+
+    /// Returns the locking shift language.
+    pub const fn locking_shift(&self) -> Option<NationalLanguage> {
+        self.locking_shift
+    }
+
+    /// Returns the single shift language.
+    pub const fn single_shift(&self) -> Option<NationalLanguage> {
+        self.single_shift
+    }
+
+    /// Returns the locking shift language whose table differs from the default alphabet.
+    pub(super) const fn effective_locking_shift(&self) -> Option<NationalLanguage> {
+        match self.locking_shift {
+            Some(language) if language.locking_shift_table().is_some() => Some(language),
+            _ => None,
+        }
+    }
+
+    /// # Returns
+    ///
+    /// - `Some(Self)` if the character is found in the shift tables.
+    /// - `None` if the character is not found.
+    pub(super) const fn encode(&self, ch: char) -> Option<Encoded> {
+        let standard = match self.locking_table() {
+            Some(table) => {
+                let mut index = 0;
+
+                loop {
+                    if index == table.len() {
+                        break None;
+                    }
+
+                    if index != ESCAPE_CHARACTER as usize && table[index] == ch {
+                        break Some(index as u8);
+                    }
+
+                    index += 1;
+                }
+            }
+            None => super::default::Gsm7BitDefaultAlphabet::new().encode_standard(ch),
+        };
+
+        if let Some(byte) = standard {
+            return Some(Encoded::Standard(byte));
+        }
+
+        let extended = match self.single_shift {
+            Some(language) => {
+                let table = language.single_shift_table();
+                let mut index = 0;
+
+                loop {
+                    if index == table.len() {
+                        break None;
+                    }
+
+                    if table[index].1 == ch {
+                        break Some(table[index].0);
+                    }
+
+                    index += 1;
+                }
+            }
+            None => super::default::Gsm7BitDefaultAlphabet::new().encode_extended(ch),
+        };
+
+        match extended {
+            Some(byte) => Some(Encoded::Extended(byte)),
+            None => None,
+        }
+    }
+
+    /// Decodes a standard septet.
+    ///
+    /// Returns `None` if the septet is the escape character or out of range.
+    pub(super) const fn decode_standard(&self, byte: u8) -> Option<char> {
+        match self.locking_table() {
+            Some(_) if byte == ESCAPE_CHARACTER || byte > 0x7F => None,
+            Some(table) => Some(table[byte as usize]),
+            None => super::default::Gsm7BitDefaultAlphabet::new().decode_standard(byte),
+        }
+    }
+
+    /// Decodes the septet following an escape character.
+    ///
+    /// Returns `None` if the septet is not in the single shift table.
+    pub(super) const fn decode_extended(&self, byte: u8) -> Option<char> {
+        match self.single_shift {
+            Some(language) => {
+                let table = language.single_shift_table();
+                let mut index = 0;
+
+                while index < table.len() {
+                    if table[index].0 == byte {
+                        return Some(table[index].1);
+                    }
+
+                    index += 1;
+                }
+
+                None
+            }
+            None => super::default::Gsm7BitDefaultAlphabet::new().decode_extended(byte),
+        }
+    }
+
+    const fn locking_table(&self) -> Option<&'static [char; 128]> {
+        match self.locking_shift {
+            Some(language) => language.locking_shift_table(),
+            None => None,
+        }
+    }
+}
+
+/// Placeholder for the escape character position in the locking shift tables.
+const ESC: char = '\u{1B}';
+
+/// Turkish national language locking shift table.
+///
+/// See 3GPP TS 23.038, section A.3.1.
+#[rustfmt::skip]
+const TURKISH_LOCKING_SHIFT: [char; 128] = [
+    '@', '£', '$', '¥', '€', 'é', 'ù', 'ı', 'ò', 'Ç', '\n', 'Ğ', 'ğ', '\r', 'Å', 'å',
+    'Δ', '_', 'Φ', 'Γ', 'Λ', 'Ω', 'Π', 'Ψ', 'Σ', 'Θ', 'Ξ', ESC, 'Ş', 'ş', 'ß', 'É',
+    ' ', '!', '"', '#', '¤', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    'İ', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ñ', 'Ü', '§',
+    'ç', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ä', 'ö', 'ñ', 'ü', 'à',
+];
+
+/// Portuguese national language locking shift table.
+///
+/// See 3GPP TS 23.038, section A.3.3.
+#[rustfmt::skip]
+const PORTUGUESE_LOCKING_SHIFT: [char; 128] = [
+    '@', '£', '$', '¥', 'ê', 'é', 'ú', 'í', 'ó', 'ç', '\n', 'Ô', 'ô', '\r', 'Á', 'á',
+    'Δ', '_', 'ª', 'Ç', 'À', '∞', '^', '\\', '€', 'Ó', '|', ESC, 'Â', 'â', 'Ê', 'É',
+    ' ', '!', '"', '#', 'º', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    'Í', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ã', 'Õ', 'Ú', 'Ü', '§',
+    '~', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ã', 'õ', '`', 'ü', 'à',
+];
+
+/// Turkish national language single shift table.
+///
+/// See 3GPP TS 23.038, section A.2.1.
+const TURKISH_SINGLE_SHIFT: &[(u8, char)] = &[
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x47, 'Ğ'),
+    (0x49, 'İ'),
+    (0x53, 'Ş'),
+    (0x63, 'ç'),
+    (0x65, '€'),
+    (0x67, 'ğ'),
+    (0x69, 'ı'),
+    (0x73, 'ş'),
+];
+
+/// Spanish national language single shift table.
+///
+/// See 3GPP TS 23.038, section A.2.2.
+const SPANISH_SINGLE_SHIFT: &[(u8, char)] = &[
+    (0x09, 'ç'),
+    (0x14, '^'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'Á'),
+    (0x49, 'Í'),
+    (0x4F, 'Ó'),
+    (0x55, 'Ú'),
+    (0x61, 'á'),
+    (0x65, '€'),
+    (0x69, 'í'),
+    (0x6F, 'ó'),
+    (0x75, 'ú'),
+];
+
+/// Portuguese national language single shift table.
+///
+/// See 3GPP TS 23.038, section A.2.3.
+const PORTUGUESE_SINGLE_SHIFT: &[(u8, char)] = &[
+    (0x05, 'ê'),
+    (0x09, 'ç'),
+    (0x0B, 'Ô'),
+    (0x0C, 'ô'),
+    (0x0E, 'Á'),
+    (0x0F, 'á'),
+    (0x12, 'Φ'),
+    (0x13, 'Γ'),
+    (0x14, '^'),
+    (0x15, 'Ω'),
+    (0x16, 'Π'),
+    (0x17, 'Ψ'),
+    (0x18, 'Σ'),
+    (0x19, 'Θ'),
+    (0x1F, 'Ê'),
+    (0x28, '{'),
+    (0x29, '}'),
+    (0x2F, '\\'),
+    (0x3C, '['),
+    (0x3D, '~'),
+    (0x3E, ']'),
+    (0x40, '|'),
+    (0x41, 'À'),
+    (0x49, 'Í'),
+    (0x4F, 'Ó'),
+    (0x55, 'Ú'),
+    (0x5B, 'Ã'),
+    (0x5C, 'Õ'),
+    (0x61, 'Â'),
+    (0x65, '€'),
+    (0x69, 'í'),
+    (0x6F, 'ó'),
+    (0x75, 'ú'),
+    (0x7B, 'ã'),
+    (0x7C, 'õ'),
+    (0x7F, 'â'),
+];
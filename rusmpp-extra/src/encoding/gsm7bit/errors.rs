@@ -8,6 +8,14 @@ pub enum Gsm7BitEncodeError {
     UnencodableCharacter(char),
 }
 
+/// Errors that can occur during GSM 7-bit decoding.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Gsm7BitDecodeError {
+    /// Input contains a byte that is not a valid septet.
+    #[error("Input contains invalid septet: {0:#04x}")]
+    InvalidSeptet(u8),
+}
+
 /// Errors that can occur during GSM 7-bit concatenation.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum Gsm7BitConcatenateError {
@@ -0,0 +1,343 @@
+use rusmpp_core::values::{
+    Alphabet, DataCoding, DataCodingScheme, MessageClass, MessageWaitingGroup, TypeOfMessage,
+};
+
+use crate::encoding::gsm7bit::alphabet::Gsm7BitAlphabet;
+
+/// The `<CR>` septet used to fill 7 spare bits at the end of packed user data.
+const CARRIAGE_RETURN: u8 = 0x0D;
+
+/// GSM 7-bit packed codec.
+///
+/// Septets are packed into octets as described in 3GPP TS 23.038, allowing 160 characters in 140 octets.
+///
+/// When a user data header precedes the packed septets, fill bits are inserted after the header so that
+/// the first septet starts on a septet boundary. See [`Gsm7BitPacked::padding_bits`].
+///
+/// If the packed septets leave 7 spare bits in the last octet, the spare bits are filled with `<CR>`,
+/// which is removed again when decoding.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct Gsm7BitPacked {
+    /// The GSM 7-bit alphabet to use for encoding.
+    alphabet: Gsm7BitAlphabet,
+    /// Whether to allow splitting extended characters across message parts.
+    allow_split_extended_character: bool,
+    /// The data coding of the encoded messages.
+    data_coding: DataCoding,
+}
+
+impl Default for Gsm7BitPacked {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gsm7BitPacked {
+    /// Creates a new [`Gsm7BitPacked`] with [`Gsm7BitAlphabet::Default`].
+    ///
+    /// # Defaults
+    ///
+    /// - `alphabet`: [`Gsm7BitAlphabet::Default`]
+    /// - `allow_split_extended_character`: `false`
+    /// - `data_coding`: [`DataCoding::McSpecific`]
+    pub const fn new() -> Self {
+        Self {
+            alphabet: Gsm7BitAlphabet::default(),
+            allow_split_extended_character: false,
+            data_coding: DataCoding::McSpecific,
+        }
+    }
+
+    /// Sets the alphabet for the codec.
+    pub const fn with_alphabet(mut self, alphabet: Gsm7BitAlphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Returns whether splitting extended characters is allowed.
+    pub const fn allow_split_extended_character(&self) -> bool {
+        self.allow_split_extended_character
+    }
+
+    /// Sets whether to allow splitting extended characters across message parts.
+    pub const fn with_allow_split_extended_character(mut self, allow: bool) -> Self {
+        self.allow_split_extended_character = allow;
+        self
+    }
+
+    /// Returns the associated [`Gsm7BitAlphabet`].
+    pub const fn alphabet(&self) -> &Gsm7BitAlphabet {
+        &self.alphabet
+    }
+
+    /// Returns the associated [`DataCoding`].
+    pub const fn data_coding(&self) -> DataCoding {
+        self.data_coding
+    }
+
+    /// Sets the message class of the encoded messages, e.g. [`MessageClass::Class0`] for flash messages.
+    ///
+    /// The [`DataCoding`] becomes a general data coding indication carrying the message class,
+    /// see [`DataCodingScheme::General`].
+    pub fn with_message_class(mut self, message_class: MessageClass) -> Self {
+        self.data_coding = DataCoding::from(DataCodingScheme::General {
+            compressed: false,
+            auto_deletion: false,
+            message_class: Some(message_class),
+            alphabet: Alphabet::Gsm7Bit,
+        });
+        self
+    }
+
+    /// Sets the message waiting indication of the encoded messages, the messages are stored by the mobile station.
+    ///
+    /// The [`DataCoding`] becomes [`MessageWaitingGroup::StoreGsm7Bit`], see [`DataCodingScheme::MessageWaiting`].
+    pub fn with_message_waiting(mut self, indication: TypeOfMessage, active: bool) -> Self {
+        self.data_coding = DataCoding::from(DataCodingScheme::MessageWaiting {
+            group: MessageWaitingGroup::StoreGsm7Bit,
+            active,
+            indication,
+        });
+        self
+    }
+
+    /// Returns the number of fill bits required after a user data header of `udh_length` octets
+    /// (including the user data header length octet) to align the packed septets on a septet boundary.
+    pub const fn padding_bits(udh_length: usize) -> usize {
+        (7 - (udh_length * 8) % 7) % 7
+    }
+
+    /// Returns the number of septets that fit into `octets` octets preceded by `padding_bits` fill bits.
+    pub const fn septet_capacity(octets: usize, padding_bits: usize) -> usize {
+        (octets * 8).saturating_sub(padding_bits) / 7
+    }
+}
+
+#[cfg(any(test, feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+mod impl_owned {
+    use alloc::{string::String, vec::Vec};
+
+    use crate::{
+        concatenation::owned::{Concatenation, Concatenator},
+        encoding::{
+            gsm7bit::{
+                errors::{Gsm7BitConcatenateError, Gsm7BitDecodeError, Gsm7BitEncodeError},
+                unpacked::split_septets,
+            },
+            owned::{Decoder, Encoder},
+        },
+    };
+
+    use super::*;
+
+    impl Gsm7BitPacked {
+        /// Encodes the given message into a vector of packed septets.
+        pub fn encode_to_vec(&self, input: &str) -> Result<Vec<u8>, Gsm7BitEncodeError> {
+            self.encode_to_vec_with_padding(input, 0)
+        }
+
+        /// Encodes the given message into a vector of packed septets preceded by `padding_bits` fill bits.
+        pub fn encode_to_vec_with_padding(
+            &self,
+            input: &str,
+            padding_bits: usize,
+        ) -> Result<Vec<u8>, Gsm7BitEncodeError> {
+            self.alphabet
+                .encode_to_vec(input)
+                .map(|septets| Self::pack(&septets, padding_bits))
+                .map_err(Gsm7BitEncodeError::UnencodableCharacter)
+        }
+
+        /// Decodes the given packed septets into a string.
+        pub fn decode_to_string(&self, input: &[u8]) -> Result<String, Gsm7BitDecodeError> {
+            self.decode_to_string_with_padding(input, 0)
+        }
+
+        /// Decodes the given packed septets preceded by `padding_bits` fill bits into a string.
+        pub fn decode_to_string_with_padding(
+            &self,
+            input: &[u8],
+            padding_bits: usize,
+        ) -> Result<String, Gsm7BitDecodeError> {
+            self.alphabet
+                .decode_to_string(&Self::unpack(input, padding_bits))
+                .map_err(Gsm7BitDecodeError::InvalidSeptet)
+        }
+
+        /// Packs unpacked septets into octets, starting after `padding_bits` fill bits.
+        pub fn pack(septets: &[u8], padding_bits: usize) -> Vec<u8> {
+            if septets.is_empty() {
+                return Vec::new();
+            }
+
+            let mut septets = septets.to_vec();
+
+            if (padding_bits + septets.len() * 7) % 8 == 1 {
+                septets.push(CARRIAGE_RETURN);
+            }
+
+            let mut packed = alloc::vec![0; (padding_bits + septets.len() * 7).div_ceil(8)];
+
+            for (index, septet) in septets.into_iter().enumerate() {
+                let bit = padding_bits + index * 7;
+                let value = ((septet & 0x7F) as u16) << (bit % 8);
+
+                packed[bit / 8] |= value as u8;
+
+                if bit % 8 > 1 {
+                    packed[bit / 8 + 1] |= (value >> 8) as u8;
+                }
+            }
+
+            packed
+        }
+
+        /// Unpacks octets preceded by `padding_bits` fill bits into septets.
+        pub fn unpack(octets: &[u8], padding_bits: usize) -> Vec<u8> {
+            let count = Self::septet_capacity(octets.len(), padding_bits);
+
+            let mut septets: Vec<u8> = (0..count)
+                .map(|index| {
+                    let bit = padding_bits + index * 7;
+                    let mut value = (octets[bit / 8] as u16) >> (bit % 8);
+
+                    if bit % 8 > 1 {
+                        value |= (octets[bit / 8 + 1] as u16) << (8 - bit % 8);
+                    }
+
+                    (value & 0x7F) as u8
+                })
+                .collect();
+
+            if (padding_bits + count * 7) % 8 == 0 && septets.last() == Some(&CARRIAGE_RETURN) {
+                septets.pop();
+            }
+
+            septets
+        }
+    }
+
+    impl Encoder for Gsm7BitPacked {
+        type Error = Gsm7BitEncodeError;
+
+        fn encode(&self, message: &str) -> Result<(Vec<u8>, DataCoding), Self::Error> {
+            self.encode_to_vec(message)
+                .map(|vec| (vec, self.data_coding()))
+        }
+
+        fn encode_with_information_elements(
+            &self,
+            message: &str,
+        ) -> Result<(Vec<u8>, DataCoding, Vec<u8>), Self::Error> {
+            let information_elements = self.alphabet.information_elements();
+
+            // The septets must start on a septet boundary after the user data header.
+            let padding_bits = match information_elements.len() {
+                0 => 0,
+                length => Self::padding_bits(1 + length),
+            };
+
+            self.encode_to_vec_with_padding(message, padding_bits)
+                .map(|vec| (vec, self.data_coding(), information_elements))
+        }
+    }
+
+    impl Decoder for Gsm7BitPacked {
+        type Error = Gsm7BitDecodeError;
+
+        fn decode(&self, bytes: &[u8]) -> Result<String, Self::Error> {
+            self.decode_to_string(bytes)
+        }
+    }
+
+    impl Concatenator for Gsm7BitPacked {
+        type Error = Gsm7BitConcatenateError;
+
+        fn concatenate(
+            &self,
+            message: &str,
+            max_message_size: usize,
+            part_header_size: usize,
+        ) -> Result<(Concatenation, DataCoding), Self::Error> {
+            let septets = self
+                .alphabet
+                .encode_to_vec(message)
+                .map_err(Gsm7BitEncodeError::UnencodableCharacter)?;
+
+            self.concatenate_septets(&septets, max_message_size, 0, part_header_size)
+        }
+
+        fn concatenate_with_information_elements(
+            &self,
+            message: &str,
+            max_message_size: usize,
+            part_header_size: usize,
+        ) -> Result<(Concatenation, DataCoding, Vec<u8>), Self::Error> {
+            let information_elements = self.alphabet.information_elements();
+
+            let septets = self
+                .alphabet
+                .encode_to_vec(message)
+                .map_err(Gsm7BitEncodeError::UnencodableCharacter)?;
+
+            let (single_header_size, part_header_size) = match information_elements.len() {
+                0 => (0, part_header_size),
+                // The user data header length octet and the information elements.
+                length => (1 + length, part_header_size + length),
+            };
+
+            self.concatenate_septets(
+                &septets,
+                max_message_size,
+                single_header_size,
+                part_header_size,
+            )
+            .map(|(concatenation, data_coding)| (concatenation, data_coding, information_elements))
+        }
+    }
+
+    impl Gsm7BitPacked {
+        fn concatenate_septets(
+            &self,
+            septets: &[u8],
+            max_message_size: usize,
+            single_header_size: usize,
+            part_header_size: usize,
+        ) -> Result<(Concatenation, DataCoding), Gsm7BitConcatenateError> {
+            // The septets must start on a septet boundary after the header.
+            let padding_bits = Self::padding_bits(single_header_size);
+
+            let single_payload_size = Self::septet_capacity(
+                max_message_size.saturating_sub(single_header_size),
+                padding_bits,
+            );
+
+            if septets.len() <= single_payload_size {
+                return Ok((
+                    Concatenation::single(Self::pack(septets, padding_bits)),
+                    self.data_coding(),
+                ));
+            }
+
+            let padding_bits = Self::padding_bits(part_header_size);
+
+            let part_payload_size = Self::septet_capacity(
+                max_message_size.saturating_sub(part_header_size),
+                padding_bits,
+            );
+
+            let parts = split_septets(
+                septets,
+                part_payload_size,
+                self.allow_split_extended_character,
+            )?
+            .into_iter()
+            .map(|part| Self::pack(part, padding_bits))
+            .collect();
+
+            Ok((Concatenation::concatenated(parts), self.data_coding()))
+        }
+    }
+}
@@ -1 +1,3 @@
+mod national;
+mod packed;
 mod unpacked;
@@ -0,0 +1,218 @@
+use crate::{
+    concatenation::owned::{Concatenation, Concatenator},
+    encoding::{
+        gsm7bit::{
+            Gsm7BitAlphabet, Gsm7BitNationalAlphabet, NationalLanguage, errors::Gsm7BitEncodeError,
+            packed::Gsm7BitPacked, unpacked::Gsm7BitUnpacked,
+        },
+        owned::{Decoder, Encoder},
+    },
+};
+
+fn unpacked(alphabet: Gsm7BitAlphabet) -> Gsm7BitUnpacked {
+    Gsm7BitUnpacked::new().with_alphabet(alphabet)
+}
+
+mod encode {
+    use super::*;
+
+    #[test]
+    fn turkish() {
+        let encoded = unpacked(Gsm7BitAlphabet::turkish())
+            .encode_to_vec("ğüşıöçĞÜŞİÖÇ€")
+            .unwrap();
+
+        assert_eq!(
+            encoded,
+            [
+                0x0C, 0x7E, 0x1D, 0x07, 0x7C, 0x60, 0x0B, 0x5E, 0x1C, 0x40, 0x5C, 0x09, 0x04
+            ]
+        );
+    }
+
+    #[test]
+    fn turkish_single_shift_only() {
+        let alphabet =
+            Gsm7BitNationalAlphabet::new(NationalLanguage::Turkish).with_locking_shift(None);
+
+        let encoded = unpacked(Gsm7BitAlphabet::National(alphabet))
+            .encode_to_vec("ğé{")
+            .unwrap();
+
+        assert_eq!(encoded, [0x1B, 0x67, 0x05, 0x1B, 0x28]);
+    }
+
+    #[test]
+    fn spanish() {
+        let encoded = unpacked(Gsm7BitAlphabet::spanish())
+            .encode_to_vec("áñÚ€")
+            .unwrap();
+
+        assert_eq!(encoded, [0x1B, 0x61, 0x7D, 0x1B, 0x55, 0x1B, 0x65]);
+    }
+
+    #[test]
+    fn portuguese() {
+        let encoded = unpacked(Gsm7BitAlphabet::portuguese())
+            .encode_to_vec("ãâÃçêª€")
+            .unwrap();
+
+        assert_eq!(encoded, [0x7B, 0x1D, 0x5B, 0x09, 0x04, 0x12, 0x18]);
+    }
+
+    #[test]
+    fn information_elements() {
+        let (_, _, information_elements) = unpacked(Gsm7BitAlphabet::turkish())
+            .encode_with_information_elements("ğ")
+            .unwrap();
+
+        assert_eq!(information_elements, [0x25, 0x01, 0x01, 0x24, 0x01, 0x01]);
+
+        // Spanish has no locking shift table.
+        let (_, _, information_elements) = unpacked(Gsm7BitAlphabet::spanish())
+            .encode_with_information_elements("á")
+            .unwrap();
+
+        assert_eq!(information_elements, [0x24, 0x01, 0x02]);
+
+        let (_, _, information_elements) = unpacked(Gsm7BitAlphabet::default())
+            .encode_with_information_elements("a")
+            .unwrap();
+
+        assert!(information_elements.is_empty());
+    }
+
+    #[test]
+    fn packed_information_elements_padding() {
+        let (encoded, _, information_elements) = Gsm7BitPacked::new()
+            .with_alphabet(Gsm7BitAlphabet::spanish())
+            .encode_with_information_elements("hello")
+            .unwrap();
+
+        assert_eq!(information_elements.len(), 3);
+
+        // A 4 octet user data header requires 3 fill bits.
+        assert_eq!(
+            Gsm7BitPacked::new()
+                .with_alphabet(Gsm7BitAlphabet::spanish())
+                .decode_to_string_with_padding(&encoded, 3)
+                .unwrap(),
+            "hello"
+        );
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn unencodable_character() {
+            let err = unpacked(Gsm7BitAlphabet::default())
+                .encode_to_vec("ğ")
+                .unwrap_err();
+
+            assert_eq!(err, Gsm7BitEncodeError::UnencodableCharacter('ğ'));
+
+            let err = unpacked(Gsm7BitAlphabet::turkish())
+                .encode_to_vec("á")
+                .unwrap_err();
+
+            assert_eq!(err, Gsm7BitEncodeError::UnencodableCharacter('á'));
+        }
+    }
+}
+
+mod decode {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for (alphabet, message) in [
+            (
+                Gsm7BitAlphabet::turkish(),
+                "Türkçe karakterler: ğüşıöç ĞÜŞİÖÇ {€}",
+            ),
+            (
+                Gsm7BitAlphabet::spanish(),
+                "Canción de Ávila, ¿qué tal? ñÑ [€]",
+            ),
+            (
+                Gsm7BitAlphabet::portuguese(),
+                "Informação, avô e irmã: ÃÕ ª º ∞ {€}",
+            ),
+        ] {
+            let codec = unpacked(alphabet);
+
+            let encoded = codec.encode_to_vec(message).unwrap();
+
+            assert_eq!(codec.decode(&encoded).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn escape_character_is_not_a_standard_character() {
+        // A trailing escape character is ignored.
+        assert_eq!(
+            unpacked(Gsm7BitAlphabet::turkish())
+                .decode(&[b'A', 0x1B])
+                .unwrap(),
+            "A"
+        );
+    }
+}
+
+mod concatenate {
+    use super::*;
+
+    #[test]
+    fn single_accounts_for_information_elements() {
+        // 140 - (1 + 6) = 133 septets
+        let message = "ş".repeat(133);
+
+        let (concatenation, _, information_elements) = unpacked(Gsm7BitAlphabet::turkish())
+            .concatenate_with_information_elements(&message, 140, 6)
+            .unwrap();
+
+        assert_eq!(information_elements.len(), 6);
+        assert!(matches!(concatenation, Concatenation::Single(_)));
+
+        let message = "ş".repeat(134);
+
+        let (concatenation, _, _) = unpacked(Gsm7BitAlphabet::turkish())
+            .concatenate_with_information_elements(&message, 140, 6)
+            .unwrap();
+
+        let parts = concatenation.collect();
+
+        // 140 - (6 + 6) = 128 septets
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 128);
+    }
+
+    #[test]
+    fn packed_parts_account_for_information_elements() {
+        let message = "ş".repeat(300);
+
+        let (concatenation, _, _) = Gsm7BitPacked::new()
+            .with_alphabet(Gsm7BitAlphabet::turkish())
+            .concatenate_with_information_elements(&message, 140, 6)
+            .unwrap();
+
+        let parts = concatenation.collect();
+
+        // 12 octets of header require 2 fill bits: (128 * 8 - 2) / 7 = 146 septets
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].len(), 128);
+    }
+
+    #[test]
+    fn default_alphabet_has_no_information_elements() {
+        let message = "a".repeat(160);
+
+        let (concatenation, _, information_elements) = unpacked(Gsm7BitAlphabet::default())
+            .concatenate_with_information_elements(&message, 140, 6)
+            .unwrap();
+
+        assert!(information_elements.is_empty());
+        assert_eq!(concatenation.collect().len(), 2);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    concatenation::owned::{Concatenation, Concatenator},
+    encoding::{
+        gsm7bit::{
+            errors::{Gsm7BitConcatenateError, Gsm7BitEncodeError},
+            packed::Gsm7BitPacked,
+        },
+        owned::{Decoder, Encoder},
+    },
+};
+
+mod encode {
+    use super::*;
+
+    #[test]
+    fn encode() {
+        let (encoded, _) = Gsm7BitPacked::new()
+            .encode("hellohello")
+            .expect("Encoding failed");
+
+        assert_eq!(
+            encoded,
+            [0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37]
+        );
+    }
+
+    #[test]
+    fn extended_characters() {
+        let encoded = Gsm7BitPacked::new()
+            .encode_to_vec("€")
+            .expect("Encoding failed");
+
+        // 0x1B 0x65
+        assert_eq!(encoded, [0x9B, 0x32]);
+    }
+
+    #[test]
+    fn seven_spare_bits_are_filled_with_carriage_return() {
+        let encoded = Gsm7BitPacked::new()
+            .encode_to_vec("1234567")
+            .expect("Encoding failed");
+
+        assert_eq!(encoded.len(), 7);
+        assert_eq!(encoded[6] >> 1, 0x0D);
+    }
+
+    #[test]
+    fn hundred_sixty_characters_fit_in_hundred_forty_octets() {
+        let encoded = Gsm7BitPacked::new()
+            .encode_to_vec(&"a".repeat(160))
+            .expect("Encoding failed");
+
+        assert_eq!(encoded.len(), 140);
+    }
+
+    #[test]
+    fn padding() {
+        let encoded = Gsm7BitPacked::new()
+            .encode_to_vec_with_padding("hello", 1)
+            .expect("Encoding failed");
+
+        // Same septets as without padding, shifted by one bit.
+        assert_eq!(encoded, [0xD0, 0x65, 0x36, 0xFB, 0x0D]);
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn unencodable_character() {
+            let err = Gsm7BitPacked::new().encode("Hello 😀").unwrap_err();
+
+            assert_eq!(err, Gsm7BitEncodeError::UnencodableCharacter('😀'));
+        }
+    }
+}
+
+mod decode {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let decoded = Gsm7BitPacked::new()
+            .decode(&[0xE8, 0x32, 0x9B, 0xFD, 0x46, 0x97, 0xD9, 0xEC, 0x37])
+            .unwrap();
+
+        assert_eq!(decoded, "hellohello");
+    }
+
+    #[test]
+    fn round_trip() {
+        let codec = Gsm7BitPacked::new();
+
+        for padding_bits in 0..7 {
+            for len in 0..=20 {
+                let message = "Hello world! {€}"
+                    .chars()
+                    .cycle()
+                    .take(len)
+                    .collect::<alloc::string::String>();
+
+                let encoded = codec
+                    .encode_to_vec_with_padding(&message, padding_bits)
+                    .unwrap();
+
+                let decoded = codec
+                    .decode_to_string_with_padding(&encoded, padding_bits)
+                    .unwrap();
+
+                assert_eq!(decoded, message, "padding_bits: {padding_bits}, len: {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn carriage_return_at_octet_boundary_is_removed() {
+        let codec = Gsm7BitPacked::new();
+
+        let encoded = codec.encode_to_vec("1234567").unwrap();
+
+        assert_eq!(codec.decode_to_string(&encoded).unwrap(), "1234567");
+    }
+
+    #[test]
+    fn pack_unpack() {
+        let septets = [0x00, 0x7F, 0x1B, 0x65, 0x41];
+
+        for padding_bits in 0..7 {
+            let packed = Gsm7BitPacked::pack(&septets, padding_bits);
+
+            assert_eq!(Gsm7BitPacked::unpack(&packed, padding_bits), septets);
+        }
+    }
+
+    #[test]
+    fn trailing_escape_is_ignored() {
+        let packed = Gsm7BitPacked::pack(&[b'A', 0x1B], 0);
+
+        assert_eq!(Gsm7BitPacked::new().decode(&packed).unwrap(), "A");
+    }
+}
+
+mod padding_bits {
+    use super::*;
+
+    #[test]
+    fn padding_bits() {
+        assert_eq!(Gsm7BitPacked::padding_bits(0), 0);
+        // 8-bit reference concatenation UDH
+        assert_eq!(Gsm7BitPacked::padding_bits(6), 1);
+        // 16-bit reference concatenation UDH
+        assert_eq!(Gsm7BitPacked::padding_bits(7), 0);
+        assert_eq!(Gsm7BitPacked::padding_bits(1), 6);
+    }
+
+    #[test]
+    fn septet_capacity() {
+        assert_eq!(Gsm7BitPacked::septet_capacity(140, 0), 160);
+        assert_eq!(Gsm7BitPacked::septet_capacity(134, 1), 153);
+        assert_eq!(Gsm7BitPacked::septet_capacity(133, 0), 152);
+    }
+}
+
+mod concatenate {
+    use super::*;
+
+    #[test]
+    fn single() {
+        let message = "a".repeat(160);
+
+        let (concatenation, _) = Gsm7BitPacked::new().concatenate(&message, 140, 6).unwrap();
+
+        let Concatenation::Single(encoded) = concatenation else {
+            panic!("Expected a single part");
+        };
+
+        assert_eq!(encoded.len(), 140);
+    }
+
+    #[test]
+    fn parts_are_aligned_after_header() {
+        let message = "a".repeat(161);
+        let codec = Gsm7BitPacked::new();
+
+        let parts = codec.concatenate(&message, 140, 6).unwrap().0.collect();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 134);
+
+        let decoded = parts
+            .iter()
+            .map(|part| codec.decode_to_string_with_padding(part, 1).unwrap())
+            .collect::<alloc::vec::Vec<_>>();
+
+        assert_eq!(decoded[0].len(), 153);
+        assert_eq!(decoded[1].len(), 8);
+    }
+
+    #[test]
+    fn sixteen_bit_reference_header() {
+        let message = "a".repeat(161);
+
+        let parts = Gsm7BitPacked::new()
+            .concatenate(&message, 140, 7)
+            .unwrap()
+            .0
+            .collect();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 133);
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn zero_part_size() {
+            let err = Gsm7BitPacked::new().concatenate("Hello", 1, 1).unwrap_err();
+
+            assert_eq!(err, Gsm7BitConcatenateError::PartCapacityExceeded);
+        }
+
+        #[test]
+        fn extended_character_no_split() {
+            // Each part fits a single septet.
+            let err = Gsm7BitPacked::new().concatenate("€€", 3, 1).unwrap_err();
+
+            assert_eq!(err, Gsm7BitConcatenateError::InvalidBoundary);
+        }
+    }
+
+    #[test]
+    fn extended_character_split() {
+        let parts = Gsm7BitPacked::new()
+            .with_allow_split_extended_character(true)
+            .concatenate("€€", 3, 1)
+            .unwrap()
+            .0
+            .collect();
+
+        assert_eq!(parts.len(), 4);
+    }
+}
@@ -5,10 +5,10 @@ use crate::{
     },
     encoding::{
         gsm7bit::{
-            errors::{Gsm7BitConcatenateError, Gsm7BitEncodeError},
+            errors::{Gsm7BitConcatenateError, Gsm7BitDecodeError, Gsm7BitEncodeError},
             unpacked::Gsm7BitUnpacked,
         },
-        owned::Encoder,
+        owned::{Decoder, Encoder},
     },
 };
 
@@ -129,6 +129,56 @@ mod encode {
     }
 }
 
+mod decode {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        // c-spell: disable
+        let input = r##"Hello world!
+
+@£$¥èéùìòÇØøÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !"#¤%&'()*+,-./0123456789:;<=>?¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà
+
+^{}\[~]|€"##;
+        // c-spell: enable
+
+        let codec = Gsm7BitUnpacked::new();
+
+        let (encoded, _) = codec.encode(input).expect("Encoding failed");
+
+        let decoded = codec.decode(&encoded).expect("Decoding failed");
+
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn unknown_extended_character_decodes_as_standard() {
+        let decoded = Gsm7BitUnpacked::new()
+            .decode(&[0x1b, b'A', 0x1b, 0x1b, b'B'])
+            .unwrap();
+
+        assert_eq!(decoded, "A B");
+    }
+
+    #[test]
+    fn trailing_escape_is_ignored() {
+        let decoded = Gsm7BitUnpacked::new().decode(&[b'A', 0x1b]).unwrap();
+
+        assert_eq!(decoded, "A");
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn invalid_septet() {
+            let err = Gsm7BitUnpacked::new().decode(&[b'A', 0x80]).unwrap_err();
+
+            assert_eq!(err, Gsm7BitDecodeError::InvalidSeptet(0x80));
+        }
+    }
+}
+
 mod concatenate {
 
     use super::*;
@@ -1,4 +1,6 @@
-use rusmpp_core::values::DataCoding;
+use rusmpp_core::values::{
+    Alphabet, DataCoding, DataCodingScheme, MessageClass, MessageWaitingGroup, TypeOfMessage,
+};
 
 use crate::encoding::gsm7bit::alphabet::Gsm7BitAlphabet;
 
@@ -10,6 +12,8 @@ pub struct Gsm7BitUnpacked {
     alphabet: Gsm7BitAlphabet,
     /// Whether to allow splitting extended characters across message parts.
     allow_split_extended_character: bool,
+    /// The data coding of the encoded messages.
+    data_coding: DataCoding,
 }
 
 impl Default for Gsm7BitUnpacked {
@@ -25,10 +29,12 @@ impl Gsm7BitUnpacked {
     ///
     /// - `alphabet`: [`Gsm7BitAlphabet::Default`]
     /// - `allow_split_extended_character`: `false`
+    /// - `data_coding`: [`DataCoding::McSpecific`]
     pub const fn new() -> Self {
         Self {
             alphabet: Gsm7BitAlphabet::default(),
             allow_split_extended_character: false,
+            data_coding: DataCoding::McSpecific,
         }
     }
 
@@ -56,14 +62,40 @@ impl Gsm7BitUnpacked {
 
     /// Returns the associated [`DataCoding`].
     pub const fn data_coding(&self) -> DataCoding {
-        DataCoding::McSpecific
+        self.data_coding
+    }
+
+    /// Sets the message class of the encoded messages, e.g. [`MessageClass::Class0`] for flash messages.
+    ///
+    /// The [`DataCoding`] becomes a general data coding indication carrying the message class,
+    /// see [`DataCodingScheme::General`].
+    pub fn with_message_class(mut self, message_class: MessageClass) -> Self {
+        self.data_coding = DataCoding::from(DataCodingScheme::General {
+            compressed: false,
+            auto_deletion: false,
+            message_class: Some(message_class),
+            alphabet: Alphabet::Gsm7Bit,
+        });
+        self
+    }
+
+    /// Sets the message waiting indication of the encoded messages, the messages are stored by the mobile station.
+    ///
+    /// The [`DataCoding`] becomes [`MessageWaitingGroup::StoreGsm7Bit`], see [`DataCodingScheme::MessageWaiting`].
+    pub fn with_message_waiting(mut self, indication: TypeOfMessage, active: bool) -> Self {
+        self.data_coding = DataCoding::from(DataCodingScheme::MessageWaiting {
+            group: MessageWaitingGroup::StoreGsm7Bit,
+            active,
+            indication,
+        });
+        self
     }
 }
 
 #[cfg(any(test, feature = "alloc"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod impl_owned {
-    use alloc::vec::Vec;
+    use alloc::{string::String, vec::Vec};
 
     use crate::{
         concatenation::{
@@ -73,9 +105,9 @@ mod impl_owned {
         encoding::{
             gsm7bit::{
                 alphabet::ESCAPE_CHARACTER,
-                errors::{Gsm7BitConcatenateError, Gsm7BitEncodeError},
+                errors::{Gsm7BitConcatenateError, Gsm7BitDecodeError, Gsm7BitEncodeError},
             },
-            owned::Encoder,
+            owned::{Decoder, Encoder},
         },
     };
 
@@ -88,6 +120,13 @@ mod impl_owned {
                 .encode_to_vec(input)
                 .map_err(Gsm7BitEncodeError::UnencodableCharacter)
         }
+
+        /// Decodes the given unpacked septets into a string.
+        pub fn decode_to_string(&self, input: &[u8]) -> Result<String, Gsm7BitDecodeError> {
+            self.alphabet
+                .decode_to_string(input)
+                .map_err(Gsm7BitDecodeError::InvalidSeptet)
+        }
     }
 
     impl Encoder for Gsm7BitUnpacked {
@@ -97,6 +136,27 @@ mod impl_owned {
             self.encode_to_vec(message)
                 .map(|vec| (vec, self.data_coding()))
         }
+
+        fn encode_with_information_elements(
+            &self,
+            message: &str,
+        ) -> Result<(Vec<u8>, DataCoding, Vec<u8>), Self::Error> {
+            self.encode_to_vec(message).map(|vec| {
+                (
+                    vec,
+                    self.data_coding(),
+                    self.alphabet.information_elements(),
+                )
+            })
+        }
+    }
+
+    impl Decoder for Gsm7BitUnpacked {
+        type Error = Gsm7BitDecodeError;
+
+        fn decode(&self, bytes: &[u8]) -> Result<String, Self::Error> {
+            self.decode_to_string(bytes)
+        }
     }
 
     impl Concatenator for Gsm7BitUnpacked {
@@ -110,48 +170,108 @@ mod impl_owned {
         ) -> Result<(Concatenation, DataCoding), Self::Error> {
             let encoded = self.encode_to_vec(message)?;
 
-            let total = encoded.len();
+            self.concatenate_septets(
+                encoded,
+                max_message_size,
+                max_message_size.saturating_sub(part_header_size),
+            )
+        }
+
+        fn concatenate_with_information_elements(
+            &self,
+            message: &str,
+            max_message_size: usize,
+            part_header_size: usize,
+        ) -> Result<(Concatenation, DataCoding, Vec<u8>), Self::Error> {
+            let information_elements = self.alphabet.information_elements();
+
+            let encoded = self.encode_to_vec(message)?;
+
+            let (single_header_size, part_header_size) = match information_elements.len() {
+                0 => (0, part_header_size),
+                // The user data header length octet and the information elements.
+                length => (1 + length, part_header_size + length),
+            };
 
-            if total <= max_message_size {
+            self.concatenate_septets(
+                encoded,
+                max_message_size.saturating_sub(single_header_size),
+                max_message_size.saturating_sub(part_header_size),
+            )
+            .map(|(concatenation, data_coding)| (concatenation, data_coding, information_elements))
+        }
+    }
+
+    impl Gsm7BitUnpacked {
+        fn concatenate_septets(
+            &self,
+            encoded: Vec<u8>,
+            single_payload_size: usize,
+            part_payload_size: usize,
+        ) -> Result<(Concatenation, DataCoding), Gsm7BitConcatenateError> {
+            if encoded.len() <= single_payload_size {
                 return Ok((Concatenation::single(encoded), self.data_coding()));
             }
 
-            let part_payload_size = max_message_size.saturating_sub(part_header_size);
+            let parts = split_septets(
+                &encoded,
+                part_payload_size,
+                self.allow_split_extended_character,
+            )?
+            .into_iter()
+            .map(<[u8]>::to_vec)
+            .collect();
 
-            if part_payload_size == 0 {
-                return Err(Gsm7BitConcatenateError::PartCapacityExceeded);
-            }
+            Ok((Concatenation::concatenated(parts), self.data_coding()))
+        }
+    }
 
-            let mut parts: Vec<Vec<u8>> = Vec::new();
-            let mut i = 0;
+    /// Splits the septets into parts of at most `part_size` septets.
+    ///
+    /// Extended characters are not split across parts unless `allow_split_extended_character` is `true`.
+    pub(in crate::encoding::gsm7bit) fn split_septets(
+        septets: &[u8],
+        part_size: usize,
+        allow_split_extended_character: bool,
+    ) -> Result<Vec<&[u8]>, Gsm7BitConcatenateError> {
+        if part_size == 0 {
+            return Err(Gsm7BitConcatenateError::PartCapacityExceeded);
+        }
 
-            while i < total {
-                let mut end = (i + part_payload_size).min(total);
+        let total = septets.len();
 
-                // avoid splitting extended characters unless allow_split_extended_character == true
-                if !self.allow_split_extended_character {
-                    // If not last part AND the last byte of this part is 0x1B,
-                    // we must shrink the part to avoid splitting ESC + next byte.
-                    if end < total && encoded[end - 1] == ESCAPE_CHARACTER {
-                        end -= 1;
+        let mut parts: Vec<&[u8]> = Vec::new();
+        let mut i = 0;
 
-                        // If shrinking removed the entire part -> impossible
-                        if end == i {
-                            return Err(Gsm7BitConcatenateError::InvalidBoundary);
-                        }
-                    }
-                }
+        while i < total {
+            let mut end = (i + part_size).min(total);
 
-                parts.push(encoded[i..end].to_vec());
+            // avoid splitting extended characters unless allow_split_extended_character == true
+            if !allow_split_extended_character {
+                // If not last part AND the last byte of this part is 0x1B,
+                // we must shrink the part to avoid splitting ESC + next byte.
+                if end < total && septets[end - 1] == ESCAPE_CHARACTER {
+                    end -= 1;
 
-                i = end;
+                    // If shrinking removed the entire part -> impossible
+                    if end == i {
+                        return Err(Gsm7BitConcatenateError::InvalidBoundary);
+                    }
+                }
             }
 
-            if parts.len() > MAX_PARTS {
-                return Err(Gsm7BitConcatenateError::parts_count_exceeded(parts.len()));
-            }
+            parts.push(&septets[i..end]);
 
-            Ok((Concatenation::concatenated(parts), self.data_coding()))
+            i = end;
+        }
+
+        if parts.len() > MAX_PARTS {
+            return Err(Gsm7BitConcatenateError::parts_count_exceeded(parts.len()));
         }
+
+        Ok(parts)
     }
 }
+
+#[cfg(any(test, feature = "alloc"))]
+pub(super) use impl_owned::split_septets;
@@ -31,14 +31,15 @@ impl Latin1 {
 #[cfg(any(test, feature = "alloc"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod impl_owned {
-    use alloc::vec::Vec;
+    use alloc::{string::String, vec::Vec};
+    use core::convert::Infallible;
 
     use crate::{
         concatenation::{
             MAX_PARTS,
             owned::{Concatenation, Concatenator},
         },
-        encoding::owned::Encoder,
+        encoding::owned::{Decoder, Encoder},
     };
 
     use super::*;
@@ -64,6 +65,13 @@ mod impl_owned {
 
             Ok(buffer)
         }
+
+        /// Decodes the given Latin1 bytes into a string.
+        ///
+        /// Every byte maps to a character, so decoding never fails.
+        pub fn decode_to_string(&self, input: &[u8]) -> String {
+            input.iter().map(|&byte| char::from(byte)).collect()
+        }
     }
 
     impl Encoder for Latin1 {
@@ -75,6 +83,14 @@ mod impl_owned {
         }
     }
 
+    impl Decoder for Latin1 {
+        type Error = Infallible;
+
+        fn decode(&self, bytes: &[u8]) -> Result<String, Self::Error> {
+            Ok(self.decode_to_string(bytes))
+        }
+    }
+
     impl Concatenator for Latin1 {
         type Error = Latin1ConcatenateError;
 
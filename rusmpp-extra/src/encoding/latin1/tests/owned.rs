@@ -2,7 +2,7 @@ use crate::{
     concatenation::{MAX_PARTS, owned::Concatenator},
     encoding::{
         latin1::{Latin1, Latin1ConcatenateError, Latin1EncodeError},
-        owned::Encoder,
+        owned::{Decoder, Encoder},
     },
 };
 
@@ -25,6 +25,21 @@ mod encode {
     }
 }
 
+mod decode {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "Héllo wörld ÿ";
+
+        let codec = Latin1::new();
+
+        let (encoded, _) = codec.encode(input).unwrap();
+
+        assert_eq!(codec.decode(&encoded).unwrap(), input);
+    }
+}
+
 mod concatenate {
     use super::*;
 
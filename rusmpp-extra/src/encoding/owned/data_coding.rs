@@ -0,0 +1,191 @@
+use alloc::{string::String, vec::Vec};
+
+use rusmpp_core::values::{CharacterSet, DataCoding};
+
+use crate::encoding::{
+    charset::{Cyrillic, Ia5, Jis, Ksc5601, LatinHebrew},
+    errors::DecodeError,
+    gsm7bit::{
+        Gsm7BitAlphabet, Gsm7BitDecodeError, Gsm7BitNationalAlphabet, Gsm7BitPacked,
+        Gsm7BitUnpacked,
+    },
+    latin1::Latin1,
+    ucs2::Ucs2,
+};
+
+/// Decodes messages using the codec associated with the [`CharacterSet`] of their [`DataCoding`].
+///
+/// | [`CharacterSet`]              | Codec                                    |
+/// |-------------------------------|------------------------------------------|
+/// | [`CharacterSet::Gsm7Bit`]     | [`Gsm7BitUnpacked`] or [`Gsm7BitPacked`] |
+/// | [`CharacterSet::Latin1`]      | [`Latin1`]                               |
+/// | [`CharacterSet::Ucs2`]        | [`Ucs2`]                                 |
+/// | [`CharacterSet::Ia5`]         | [`Ia5`]                                  |
+/// | [`CharacterSet::Cyrillic`]    | [`Cyrillic`]                             |
+/// | [`CharacterSet::LatinHebrew`] | [`LatinHebrew`]                          |
+/// | [`CharacterSet::Jis`]         | [`Jis`]                                  |
+/// | [`CharacterSet::Ksc5601`]     | [`Ksc5601`]                              |
+///
+/// The character set is resolved through the [`DataCodingScheme`](rusmpp_core::values::DataCodingScheme),
+/// so that messages carrying a message class or a message waiting indication are decoded as well.
+///
+/// [`CharacterSet::Gsm7Bit`] is decoded with [`Gsm7BitUnpacked`] unless [`DataCodingDecoder::with_gsm7bit_packed`] is used.
+/// When decoding PDUs, see [`DecodeExt`](crate::encoding::owned::DecodeExt), the national language shift tables announced
+/// in the user data header take precedence over the alphabet of the codec.
+///
+/// Other character sets and compressed messages are rejected with [`DecodeError::UnsupportedDataCoding`].
+#[derive(Debug, Default)]
+pub struct DataCodingDecoder {
+    gsm7bit_unpacked: Gsm7BitUnpacked,
+    gsm7bit_packed: Option<Gsm7BitPacked>,
+    latin1: Latin1,
+    ucs2: Ucs2,
+    ia5: Ia5,
+    cyrillic: Cyrillic,
+    latin_hebrew: LatinHebrew,
+    jis: Jis,
+    ksc5601: Ksc5601,
+}
+
+impl DataCodingDecoder {
+    /// Creates a new [`DataCodingDecoder`] with the default codecs.
+    pub const fn new() -> Self {
+        Self {
+            gsm7bit_unpacked: Gsm7BitUnpacked::new(),
+            gsm7bit_packed: None,
+            latin1: Latin1::new(),
+            ucs2: Ucs2::new(),
+            ia5: Ia5::new(),
+            cyrillic: Cyrillic::new(),
+            latin_hebrew: LatinHebrew::new(),
+            jis: Jis::new(),
+            ksc5601: Ksc5601::new(),
+        }
+    }
+
+    /// Sets the [`Gsm7BitUnpacked`] codec used for [`CharacterSet::Gsm7Bit`].
+    pub fn with_gsm7bit_unpacked(mut self, gsm7bit_unpacked: Gsm7BitUnpacked) -> Self {
+        self.gsm7bit_unpacked = gsm7bit_unpacked;
+        self.gsm7bit_packed = None;
+        self
+    }
+
+    /// Sets the [`Gsm7BitPacked`] codec used for [`CharacterSet::Gsm7Bit`].
+    pub fn with_gsm7bit_packed(mut self, gsm7bit_packed: Gsm7BitPacked) -> Self {
+        self.gsm7bit_packed = Some(gsm7bit_packed);
+        self
+    }
+
+    /// Sets the [`Ucs2`] codec used for [`CharacterSet::Ucs2`].
+    ///
+    /// Use [`Ucs2::with_utf16`] to decode messages containing surrogate pairs.
+    pub fn with_ucs2(mut self, ucs2: Ucs2) -> Self {
+        self.ucs2 = ucs2;
+        self
+    }
+
+    /// Decodes the given bytes using the codec associated with `data_coding`.
+    pub fn decode(&self, data_coding: DataCoding, bytes: &[u8]) -> Result<String, DecodeError> {
+        self.decode_user_data(data_coding, 0, bytes)
+    }
+
+    /// Decodes user data that followed a user data header of `udh_length` octets
+    /// (including the user data header length octet) using the codec associated with `data_coding`.
+    ///
+    /// The user data header length is required to skip the fill bits of [`Gsm7BitPacked`] user data.
+    pub fn decode_user_data(
+        &self,
+        data_coding: DataCoding,
+        udh_length: usize,
+        bytes: &[u8],
+    ) -> Result<String, DecodeError> {
+        self.decode_user_data_with_national_alphabet(data_coding, udh_length, None, bytes)
+    }
+
+    /// Decodes user data like [`DataCodingDecoder::decode_user_data`],
+    /// using the national language shift tables announced in the user data header, if any, for [`CharacterSet::Gsm7Bit`].
+    pub(crate) fn decode_user_data_with_national_alphabet(
+        &self,
+        data_coding: DataCoding,
+        udh_length: usize,
+        national_alphabet: Option<Gsm7BitNationalAlphabet>,
+        bytes: &[u8],
+    ) -> Result<String, DecodeError> {
+        match (character_set(data_coding), &self.gsm7bit_packed) {
+            (Some(CharacterSet::Gsm7Bit), Some(_)) => self.decode_septets(
+                national_alphabet,
+                &Gsm7BitPacked::unpack(bytes, Gsm7BitPacked::padding_bits(udh_length)),
+            ),
+            (Some(CharacterSet::Gsm7Bit), None) => self.decode_septets(national_alphabet, bytes),
+            (Some(CharacterSet::Latin1), _) => Ok(self.latin1.decode_to_string(bytes)),
+            (Some(CharacterSet::Ucs2), _) => Ok(self.ucs2.decode_to_string(bytes)?),
+            (Some(CharacterSet::Ia5), _) => Ok(self.ia5.decode_to_string(bytes)?),
+            (Some(CharacterSet::Cyrillic), _) => Ok(self.cyrillic.decode_to_string(bytes)?),
+            (Some(CharacterSet::LatinHebrew), _) => {
+                Ok(self.latin_hebrew.decode_to_string(bytes)?)
+            }
+            (Some(CharacterSet::Jis), _) => Ok(self.jis.decode_to_string(bytes)?),
+            (Some(CharacterSet::Ksc5601), _) => Ok(self.ksc5601.decode_to_string(bytes)?),
+            _ => Err(DecodeError::UnsupportedDataCoding(data_coding)),
+        }
+    }
+
+    /// Unpacks [`Gsm7BitPacked`] user data into septets, so that user data of several parts can be concatenated.
+    ///
+    /// Other user data is returned as is.
+    pub(crate) fn unpack(
+        &self,
+        data_coding: DataCoding,
+        udh_length: usize,
+        bytes: &[u8],
+    ) -> Vec<u8> {
+        match (character_set(data_coding), &self.gsm7bit_packed) {
+            (Some(CharacterSet::Gsm7Bit), Some(_)) => {
+                Gsm7BitPacked::unpack(bytes, Gsm7BitPacked::padding_bits(udh_length))
+            }
+            _ => bytes.to_vec(),
+        }
+    }
+
+    /// Decodes user data returned by [`DataCodingDecoder::unpack`].
+    pub(crate) fn decode_unpacked(
+        &self,
+        data_coding: DataCoding,
+        national_alphabet: Option<Gsm7BitNationalAlphabet>,
+        bytes: &[u8],
+    ) -> Result<String, DecodeError> {
+        match character_set(data_coding) {
+            Some(CharacterSet::Gsm7Bit) => self.decode_septets(national_alphabet, bytes),
+            _ => self.decode(data_coding, bytes),
+        }
+    }
+
+    /// Decodes GSM 7-bit septets, one per byte, with the national alphabet or the alphabet of the configured codec.
+    fn decode_septets(
+        &self,
+        national_alphabet: Option<Gsm7BitNationalAlphabet>,
+        septets: &[u8],
+    ) -> Result<String, DecodeError> {
+        let national_alphabet = national_alphabet.map(Gsm7BitAlphabet::National);
+
+        let alphabet = match (&national_alphabet, &self.gsm7bit_packed) {
+            (Some(alphabet), _) => alphabet,
+            (None, Some(packed)) => packed.alphabet(),
+            (None, None) => self.gsm7bit_unpacked.alphabet(),
+        };
+
+        Ok(alphabet
+            .decode_to_string(septets)
+            .map_err(Gsm7BitDecodeError::InvalidSeptet)?)
+    }
+}
+
+/// Returns the character set of uncompressed messages with the given `data_coding`.
+fn character_set(data_coding: DataCoding) -> Option<CharacterSet> {
+    let scheme = data_coding.scheme();
+
+    match scheme.is_compressed() {
+        true => None,
+        false => scheme.character_set(),
+    }
+}
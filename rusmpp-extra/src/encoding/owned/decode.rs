@@ -0,0 +1,175 @@
+use alloc::string::String;
+
+use rusmpp_core::{
+    pdus::owned::{DataSm, DeliverSm, SubmitSm},
+    tlvs::owned::{Tlv, TlvValue},
+    udhs::UdhId,
+    values::{EsmClass, GsmFeatures},
+};
+
+use crate::encoding::{
+    errors::DecodeError,
+    gsm7bit::{Gsm7BitNationalAlphabet, NationalLanguage},
+    owned::DataCodingDecoder,
+};
+
+/// Extension trait for PDUs carrying user data to decode their messages.
+///
+/// The user data is the `short_message`, or the `message_payload` TLV if the `short_message` is empty.
+/// If the UDHI bit of the `esm_class` is set, the user data header is stripped before decoding.
+/// GSM 7-bit messages carrying national language shift information elements are decoded with the matching shift tables.
+pub trait DecodeExt {
+    /// Returns the user data without the user data header.
+    fn user_data(&self) -> Result<&[u8], DecodeError>;
+
+    /// Decodes the message using the default [`DataCodingDecoder`].
+    fn decode(&self) -> Result<String, DecodeError> {
+        self.decode_with(&DataCodingDecoder::new())
+    }
+
+    /// Decodes the message using the given [`DataCodingDecoder`].
+    fn decode_with(&self, decoder: &DataCodingDecoder) -> Result<String, DecodeError>;
+}
+
+impl DecodeExt for DeliverSm {
+    fn user_data(&self) -> Result<&[u8], DecodeError> {
+        split_user_data(self.esm_class, self.short_message(), self.tlvs())
+            .map(|(_, _, user_data)| user_data)
+    }
+
+    fn decode_with(&self, decoder: &DataCodingDecoder) -> Result<String, DecodeError> {
+        let (information_elements, udh_length, user_data) =
+            split_user_data(self.esm_class, self.short_message(), self.tlvs())?;
+
+        decoder.decode_user_data_with_national_alphabet(
+            self.data_coding,
+            udh_length,
+            national_alphabet(information_elements),
+            user_data,
+        )
+    }
+}
+
+impl DecodeExt for SubmitSm {
+    fn user_data(&self) -> Result<&[u8], DecodeError> {
+        split_user_data(self.esm_class, self.short_message(), self.tlvs())
+            .map(|(_, _, user_data)| user_data)
+    }
+
+    fn decode_with(&self, decoder: &DataCodingDecoder) -> Result<String, DecodeError> {
+        let (information_elements, udh_length, user_data) =
+            split_user_data(self.esm_class, self.short_message(), self.tlvs())?;
+
+        decoder.decode_user_data_with_national_alphabet(
+            self.data_coding,
+            udh_length,
+            national_alphabet(information_elements),
+            user_data,
+        )
+    }
+}
+
+impl DecodeExt for DataSm {
+    fn user_data(&self) -> Result<&[u8], DecodeError> {
+        split_user_data(self.esm_class, &[], self.tlvs()).map(|(_, _, user_data)| user_data)
+    }
+
+    fn decode_with(&self, decoder: &DataCodingDecoder) -> Result<String, DecodeError> {
+        let (information_elements, udh_length, user_data) =
+            split_user_data(self.esm_class, &[], self.tlvs())?;
+
+        decoder.decode_user_data_with_national_alphabet(
+            self.data_coding,
+            udh_length,
+            national_alphabet(information_elements),
+            user_data,
+        )
+    }
+}
+
+/// Returns the information elements of the user data header, the length of the user data header,
+/// including the user data header length octet, and the user data without it.
+fn split_user_data<'a>(
+    esm_class: EsmClass,
+    short_message: &'a [u8],
+    tlvs: &'a [Tlv],
+) -> Result<(&'a [u8], usize, &'a [u8]), DecodeError> {
+    let user_data = payload(short_message, tlvs);
+
+    if !has_udhi(esm_class) {
+        return Ok((&[], 0, user_data));
+    }
+
+    let (information_elements, rest) = split_udh(user_data)?;
+
+    Ok((information_elements, user_data.len() - rest.len(), rest))
+}
+
+/// Returns the `short_message`, or the `message_payload` TLV if the `short_message` is empty.
+pub(crate) fn payload<'a>(short_message: &'a [u8], tlvs: &'a [Tlv]) -> &'a [u8] {
+    if !short_message.is_empty() {
+        return short_message;
+    }
+
+    tlvs.iter()
+        .find_map(|tlv| match tlv.value() {
+            Some(TlvValue::MessagePayload(payload)) => Some(payload.value.as_ref()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Splits the user data into the information elements of its user data header and the remaining user data.
+pub(crate) fn split_udh(user_data: &[u8]) -> Result<(&[u8], &[u8]), DecodeError> {
+    let (&udh_length, rest) = user_data.split_first().ok_or(DecodeError::InvalidUdh)?;
+
+    if rest.len() < udh_length as usize {
+        return Err(DecodeError::InvalidUdh);
+    }
+
+    Ok(rest.split_at(udh_length as usize))
+}
+
+/// Returns the national language shift tables announced by the information elements of a user data header.
+///
+/// Returns `None` if the user data header has no shift information elements for a supported language.
+/// Malformed information elements are ignored.
+pub(crate) fn national_alphabet(
+    mut information_elements: &[u8],
+) -> Option<Gsm7BitNationalAlphabet> {
+    let mut locking_shift = None;
+    let mut single_shift = None;
+
+    while let [id, length, rest @ ..] = information_elements {
+        let Some((value, next)) = rest.split_at_checked(*length as usize) else {
+            break;
+        };
+
+        match (UdhId::from(*id), value) {
+            (UdhId::NationalLanguageLockingShift, &[language]) => {
+                locking_shift = NationalLanguage::from_id(language);
+            }
+            (UdhId::NationalLanguageSingleShift, &[language]) => {
+                single_shift = NationalLanguage::from_id(language);
+            }
+            _ => {}
+        }
+
+        information_elements = next;
+    }
+
+    let language = locking_shift.or(single_shift)?;
+
+    Some(
+        Gsm7BitNationalAlphabet::new(language)
+            .with_locking_shift(locking_shift)
+            .with_single_shift(single_shift),
+    )
+}
+
+pub(crate) const fn has_udhi(esm_class: EsmClass) -> bool {
+    matches!(
+        esm_class.gsm_features,
+        GsmFeatures::UdhiIndicator | GsmFeatures::SetUdhiAndReplyPath
+    )
+}
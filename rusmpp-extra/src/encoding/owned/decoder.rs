@@ -0,0 +1,10 @@
+use alloc::string::String;
+
+/// A trait for decoding byte slices into messages.
+pub trait Decoder {
+    /// The type of errors that can occur during decoding.
+    type Error;
+
+    /// Decodes the given bytes into a message.
+    fn decode(&self, bytes: &[u8]) -> Result<String, Self::Error>;
+}
@@ -7,4 +7,19 @@ pub trait Encoder {
 
     /// Encodes the given message into a vector of bytes and its associated [`DataCoding`].
     fn encode(&self, message: &str) -> Result<(alloc::vec::Vec<u8>, DataCoding), Self::Error>;
+
+    /// Encodes the given message into a vector of bytes, its associated [`DataCoding`]
+    /// and the user data header information elements required to decode it.
+    ///
+    /// The information elements are encoded as `IEI`, `IEDL`, `IED` without the user data header length.
+    /// If they are not empty, the encoded bytes are meant to follow a user data header containing them.
+    ///
+    /// The default implementation calls [`Encoder::encode`] and returns no information elements.
+    fn encode_with_information_elements(
+        &self,
+        message: &str,
+    ) -> Result<(alloc::vec::Vec<u8>, DataCoding, alloc::vec::Vec<u8>), Self::Error> {
+        self.encode(message)
+            .map(|(encoded, data_coding)| (encoded, data_coding, alloc::vec::Vec::new()))
+    }
 }
@@ -1,7 +1,7 @@
 use rusmpp_core::values::DataCoding;
 
 use crate::{
-    encoding::owned::Encoder,
+    encoding::owned::{Decoder, Encoder},
     fallback::{Fallback, FallbackError},
 };
 
@@ -21,4 +21,35 @@ where
             },
         }
     }
+
+    fn encode_with_information_elements(
+        &self,
+        message: &str,
+    ) -> Result<(alloc::vec::Vec<u8>, DataCoding, alloc::vec::Vec<u8>), Self::Error> {
+        match self.first.encode_with_information_elements(message) {
+            Ok(result) => Ok(result),
+            Err(first_err) => match self.second.encode_with_information_elements(message) {
+                Ok(result) => Ok(result),
+                Err(second_err) => Err(FallbackError::new(first_err, second_err)),
+            },
+        }
+    }
+}
+
+impl<T, U> Decoder for Fallback<T, U>
+where
+    T: Decoder,
+    U: Decoder,
+{
+    type Error = FallbackError<T::Error, U::Error>;
+
+    fn decode(&self, bytes: &[u8]) -> Result<alloc::string::String, Self::Error> {
+        match self.first.decode(bytes) {
+            Ok(result) => Ok(result),
+            Err(first_err) => match self.second.decode(bytes) {
+                Ok(result) => Ok(result),
+                Err(second_err) => Err(FallbackError::new(first_err, second_err)),
+            },
+        }
+    }
 }
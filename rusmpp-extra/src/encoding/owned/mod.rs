@@ -3,7 +3,20 @@
 mod encoder;
 pub use encoder::Encoder;
 
+mod decoder;
+pub use decoder::Decoder;
+
+mod data_coding;
+pub use data_coding::DataCodingDecoder;
+
+mod decode;
+pub use decode::DecodeExt;
+pub(crate) use decode::{has_udhi, national_alphabet, payload, split_udh};
+
 mod submit_sm;
 pub use submit_sm::{EncodedSubmitSmBuilder, EncodedSubmitSmExt};
 
 mod fallback;
+
+#[cfg(test)]
+mod tests;
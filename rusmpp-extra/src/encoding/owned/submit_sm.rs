@@ -1,8 +1,13 @@
+use alloc::vec::Vec;
 use rusmpp_core::{pdus::owned::SubmitSm, types::owned::OctetString};
 
 use crate::{
     encoding::{
-        errors::EncodeError, gsm7bit::Gsm7BitUnpacked, latin1::Latin1, owned::Encoder, ucs2::Ucs2,
+        errors::EncodeError,
+        gsm7bit::{Gsm7BitPacked, Gsm7BitUnpacked},
+        latin1::Latin1,
+        owned::Encoder,
+        ucs2::Ucs2,
     },
     fallback::Fallback,
 };
@@ -47,6 +52,11 @@ impl<'a, E> EncodedSubmitSmBuilder<'a, E> {
         self.encoder(Gsm7BitUnpacked::new())
     }
 
+    /// Sets the [`Gsm7BitPacked`] encoder.
+    pub fn gsm7bit_packed(self) -> EncodedSubmitSmBuilder<'a, Gsm7BitPacked> {
+        self.encoder(Gsm7BitPacked::new())
+    }
+
     /// Sets the [`Ucs2`] encoder.
     pub fn ucs2(self) -> EncodedSubmitSmBuilder<'a, Ucs2> {
         self.encoder(Ucs2::new())
@@ -73,15 +83,33 @@ where
 {
     /// Builds the encoded [`SubmitSm`] message.
     pub fn build(self) -> Result<SubmitSm, EncodeError<E::Error>> {
-        let (encoded, data_coding) = self
+        let (encoded, data_coding, information_elements) = self
             .encoder
-            .encode(self.short_message)
+            .encode_with_information_elements(self.short_message)
             .map_err(EncodeError::encode)?;
 
-        let short_message = OctetString::from_vec(encoded)?;
+        if information_elements.is_empty() {
+            let short_message = OctetString::from_vec(encoded)?;
+
+            let sm = self
+                .sm
+                .with_short_message(short_message)
+                .with_data_coding(data_coding);
+
+            return Ok(sm);
+        }
+
+        let mut payload = Vec::with_capacity(1 + information_elements.len() + encoded.len());
+
+        payload.push(information_elements.len() as u8);
+        payload.extend_from_slice(&information_elements);
+        payload.extend_from_slice(&encoded);
+
+        let short_message = OctetString::from_vec(payload)?;
 
         let sm = self
             .sm
+            .with_udhi_indicator()
             .with_short_message(short_message)
             .with_data_coding(data_coding);
 
@@ -97,6 +125,8 @@ pub trait EncodedSubmitSmExt {
     ///
     /// - [`SubmitSm::data_coding`] will be overridden by the multipart builder to match the encoder.
     /// - [`SubmitSm::short_message`] will be overridden by `short_message` of the multipart builder.
+    /// - [`SubmitSm::esm_class`] will be updated with UDHI indicator if the encoder requires user data header information elements
+    ///   (e.g. national language shift tables).
     fn encode<'a>(self, short_message: &'a str) -> EncodedSubmitSmBuilder<'a, Gsm7BitUnpacked>;
 }
 
@@ -0,0 +1,244 @@
+use core::str::FromStr;
+
+use rusmpp_core::{
+    pdus::owned::{DataSm, DeliverSm, SubmitSm},
+    tlvs::owned::{MessageDeliveryRequestTlvValue, MessageSubmissionRequestTlvValue},
+    types::owned::{AnyOctetString, OctetString},
+    values::{
+        DataCoding, EsmClass, GsmFeatures, MessageClass, TypeOfMessage, owned::MessagePayload,
+    },
+};
+
+use crate::encoding::{
+    errors::DecodeError,
+    gsm7bit::{Gsm7BitAlphabet, Gsm7BitDecodeError, Gsm7BitUnpacked},
+    owned::{DataCodingDecoder, DecodeExt, EncodedSubmitSmExt, Encoder},
+    ucs2::Ucs2,
+};
+
+mod data_coding {
+    use super::*;
+
+    #[test]
+    fn dispatch() {
+        let decoder = DataCodingDecoder::new();
+
+        assert_eq!(
+            decoder
+                .decode(DataCoding::McSpecific, &[0x00, b'A'])
+                .unwrap(),
+            "@A"
+        );
+        assert_eq!(
+            decoder.decode(DataCoding::Latin1, &[0x40, 0xE9]).unwrap(),
+            "@é"
+        );
+        assert_eq!(
+            decoder
+                .decode(DataCoding::Ucs2, &[0x04, 0x1F, 0x00, b'A'])
+                .unwrap(),
+            "ПA"
+        );
+        assert_eq!(
+            decoder.decode(DataCoding::Cyrillic, &[0xBF, b'A']).unwrap(),
+            "ПA"
+        );
+        assert_eq!(
+            decoder
+                .decode(DataCoding::Ksc5601, &[0xBE, 0xC8, b'A'])
+                .unwrap(),
+            "안A"
+        );
+    }
+
+    #[test]
+    fn utf16() {
+        let bytes = [0xD8, 0x3D, 0xDE, 0x00];
+
+        assert!(matches!(
+            DataCodingDecoder::new().decode(DataCoding::Ucs2, &bytes),
+            Err(DecodeError::Ucs2(_))
+        ));
+
+        let decoder = DataCodingDecoder::new().with_ucs2(Ucs2::new().with_utf16(true));
+
+        assert_eq!(decoder.decode(DataCoding::Ucs2, &bytes).unwrap(), "😀");
+    }
+
+    #[test]
+    fn unsupported() {
+        let err = DataCodingDecoder::new()
+            .decode(DataCoding::OctetUnspecified, &[0x00])
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            DecodeError::UnsupportedDataCoding(DataCoding::OctetUnspecified)
+        );
+    }
+
+    #[test]
+    fn codec_error() {
+        let err = DataCodingDecoder::new()
+            .decode(DataCoding::McSpecific, &[0xFF])
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            DecodeError::Gsm7Bit(Gsm7BitDecodeError::InvalidSeptet(0xFF))
+        );
+    }
+
+    #[test]
+    fn message_class_and_message_waiting() {
+        let decoder = DataCodingDecoder::new();
+
+        let flash = Gsm7BitUnpacked::new().with_message_class(MessageClass::Class0);
+        let (encoded, data_coding) = flash.encode("Flash").unwrap();
+
+        assert_eq!(u8::from(data_coding), 0b00010000);
+        assert_eq!(decoder.decode(data_coding, &encoded).unwrap(), "Flash");
+
+        let voicemail =
+            Ucs2::new().with_message_waiting(TypeOfMessage::VoicemailMessageWaiting, true);
+        let (encoded, data_coding) = voicemail.encode("Voicemail").unwrap();
+
+        assert_eq!(u8::from(data_coding), 0b11101000);
+        assert_eq!(decoder.decode(data_coding, &encoded).unwrap(), "Voicemail");
+
+        assert_eq!(
+            decoder.decode(DataCoding::from(0b11110001), b"A").unwrap(),
+            "A"
+        );
+    }
+
+    #[test]
+    fn compressed_unsupported() {
+        let data_coding = DataCoding::from(0b00100000);
+
+        let err = DataCodingDecoder::new()
+            .decode(data_coding, &[0x00])
+            .unwrap_err();
+
+        assert_eq!(err, DecodeError::UnsupportedDataCoding(data_coding));
+    }
+}
+
+mod decode_ext {
+    use super::*;
+
+    const UDH: &[u8] = &[0x05, 0x00, 0x03, 0x01, 0x02, 0x01];
+
+    #[test]
+    fn deliver_sm_short_message() {
+        let deliver_sm = DeliverSm::builder()
+            .data_coding(DataCoding::Latin1)
+            .short_message(OctetString::from_str("Hello").unwrap())
+            .build();
+
+        assert_eq!(deliver_sm.decode().unwrap(), "Hello");
+    }
+
+    #[test]
+    fn deliver_sm_strips_udh() {
+        let mut short_message = UDH.to_vec();
+        short_message.extend_from_slice(&[0x00, 0x4F, 0x00, 0x4B]);
+
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .data_coding(DataCoding::Ucs2)
+            .short_message(OctetString::from_vec(short_message).unwrap())
+            .build();
+
+        assert_eq!(deliver_sm.user_data().unwrap(), &[0x00, 0x4F, 0x00, 0x4B]);
+        assert_eq!(deliver_sm.decode().unwrap(), "OK");
+    }
+
+    #[test]
+    fn deliver_sm_message_payload() {
+        let deliver_sm = DeliverSm::builder()
+            .push_tlv(MessageDeliveryRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::from_static_slice(b"Payload")),
+            ))
+            .build();
+
+        assert_eq!(deliver_sm.decode().unwrap(), "Payload");
+    }
+
+    #[test]
+    fn data_sm_message_payload_strips_udh() {
+        let mut payload = UDH.to_vec();
+        payload.extend_from_slice(b"Hi");
+
+        let data_sm = DataSm::builder()
+            .esm_class(EsmClass {
+                gsm_features: GsmFeatures::SetUdhiAndReplyPath,
+                ..Default::default()
+            })
+            .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                MessagePayload::new(AnyOctetString::from_vec(payload)),
+            ))
+            .build();
+
+        assert_eq!(data_sm.decode().unwrap(), "Hi");
+    }
+
+    #[test]
+    fn invalid_udh() {
+        let deliver_sm = DeliverSm::builder()
+            .esm_class(EsmClass::default().with_udhi_indicator())
+            .short_message(OctetString::from_static_slice(&[0x05, 0x00, 0x03]).unwrap())
+            .build();
+
+        assert_eq!(deliver_sm.decode().unwrap_err(), DecodeError::InvalidUdh);
+    }
+}
+
+mod encoded_submit_sm {
+    use super::*;
+
+    #[test]
+    fn national_language_information_elements() {
+        let submit_sm = SubmitSm::builder()
+            .build()
+            .encode("ğüşıöç")
+            .encoder(Gsm7BitUnpacked::new().with_alphabet(Gsm7BitAlphabet::turkish()))
+            .build()
+            .unwrap();
+
+        assert_eq!(submit_sm.esm_class.gsm_features, GsmFeatures::UdhiIndicator);
+        assert_eq!(
+            submit_sm.short_message().as_ref(),
+            [
+                0x06, 0x25, 0x01, 0x01, 0x24, 0x01, 0x01, 0x0C, 0x7E, 0x1D, 0x07, 0x7C, 0x60
+            ]
+        );
+    }
+
+    #[test]
+    fn national_language_round_trip() {
+        for (alphabet, text) in [
+            (Gsm7BitAlphabet::turkish(), "ğüşıöç Ğ€"),
+            (Gsm7BitAlphabet::spanish(), "Canción ç"),
+            (Gsm7BitAlphabet::portuguese(), "Olá, você está ê"),
+        ] {
+            let submit_sm = SubmitSm::builder()
+                .build()
+                .encode(text)
+                .encoder(Gsm7BitUnpacked::new().with_alphabet(alphabet))
+                .build()
+                .unwrap();
+
+            // The shift tables are taken from the user data header, not from the default decoder.
+            assert_eq!(submit_sm.decode().unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn default_alphabet_has_no_udh() {
+        let submit_sm = SubmitSm::builder().build().encode("Hello").build().unwrap();
+
+        assert_eq!(submit_sm.esm_class.gsm_features, GsmFeatures::NotSelected);
+        assert_eq!(submit_sm.short_message().as_ref(), b"Hello");
+    }
+}
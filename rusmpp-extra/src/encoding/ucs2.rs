@@ -1,14 +1,30 @@
 //! Ucs2 encoding/decoding support.
 
 mod errors;
-pub use errors::{Ucs2ConcatenateError, Ucs2EncodeError};
-use rusmpp_core::values::DataCoding;
+pub use errors::{Ucs2ConcatenateError, Ucs2DecodeError, Ucs2EncodeError};
+
+#[cfg(any(test, feature = "alloc"))]
+mod grapheme;
+
+use rusmpp_core::values::{
+    Alphabet, DataCoding, DataCodingScheme, MessageClass, MessageWaitingGroup, TypeOfMessage,
+};
 
 /// UCS2 codec.
+///
+/// Plain UCS2 can only represent the characters of the Basic Multilingual Plane.
+/// Most SMSCs interpret [`DataCoding::Ucs2`] as UTF-16 though, see [`Ucs2::with_utf16`] to send emoji
+/// and other supplementary characters as surrogate pairs.
 #[derive(Debug)]
 pub struct Ucs2 {
     /// Whether to allow splitting characters across message parts.
     allow_split_character: bool,
+    /// Whether to encode and decode supplementary characters as UTF-16 surrogate pairs.
+    utf16: bool,
+    /// Whether to avoid splitting grapheme clusters across message parts.
+    keep_grapheme_clusters: bool,
+    /// The data coding of the encoded messages.
+    data_coding: DataCoding,
 }
 
 impl Default for Ucs2 {
@@ -23,9 +39,15 @@ impl Ucs2 {
     /// # Defaults
     ///
     /// - `allow_split_character`: `false`
+    /// - `utf16`: `false`
+    /// - `keep_grapheme_clusters`: `false`
+    /// - `data_coding`: [`DataCoding::Ucs2`]
     pub const fn new() -> Self {
         Self {
             allow_split_character: false,
+            utf16: false,
+            keep_grapheme_clusters: false,
+            data_coding: DataCoding::Ucs2,
         }
     }
 
@@ -40,30 +62,89 @@ impl Ucs2 {
         self
     }
 
+    /// Returns whether the UTF-16 mode is enabled.
+    pub const fn utf16(&self) -> bool {
+        self.utf16
+    }
+
+    /// Sets whether to encode characters outside of the Basic Multilingual Plane, e.g. emoji, as UTF-16 surrogate pairs
+    /// and to decode surrogate pairs back into these characters.
+    ///
+    /// Surrogate pairs are never split across message parts unless splitting characters is allowed.
+    pub const fn with_utf16(mut self, utf16: bool) -> Self {
+        self.utf16 = utf16;
+        self
+    }
+
+    /// Returns whether splitting grapheme clusters is avoided.
+    pub const fn keep_grapheme_clusters(&self) -> bool {
+        self.keep_grapheme_clusters
+    }
+
+    /// Sets whether to avoid splitting grapheme clusters across message parts,
+    /// e.g. characters followed by combining marks, emoji modifier and zero width joiner sequences, or flags.
+    ///
+    /// Grapheme clusters that do not fit into a single part are split between characters.
+    /// Has no effect if splitting characters is allowed.
+    pub const fn with_keep_grapheme_clusters(mut self, keep: bool) -> Self {
+        self.keep_grapheme_clusters = keep;
+        self
+    }
+
     /// Returns the associated [`DataCoding`].
     pub const fn data_coding(&self) -> DataCoding {
-        DataCoding::Ucs2
+        self.data_coding
+    }
+
+    /// Sets the message class of the encoded messages, e.g. [`MessageClass::Class0`] for flash messages.
+    ///
+    /// The [`DataCoding`] becomes a general data coding indication carrying the message class,
+    /// see [`DataCodingScheme::General`].
+    pub fn with_message_class(mut self, message_class: MessageClass) -> Self {
+        self.data_coding = DataCoding::from(DataCodingScheme::General {
+            compressed: false,
+            auto_deletion: false,
+            message_class: Some(message_class),
+            alphabet: Alphabet::Ucs2,
+        });
+        self
+    }
+
+    /// Sets the message waiting indication of the encoded messages, the messages are stored by the mobile station.
+    ///
+    /// The [`DataCoding`] becomes [`MessageWaitingGroup::StoreUcs2`], see [`DataCodingScheme::MessageWaiting`].
+    pub fn with_message_waiting(mut self, indication: TypeOfMessage, active: bool) -> Self {
+        self.data_coding = DataCoding::from(DataCodingScheme::MessageWaiting {
+            group: MessageWaitingGroup::StoreUcs2,
+            active,
+            indication,
+        });
+        self
     }
 }
 
 #[cfg(any(test, feature = "alloc"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 mod impl_owned {
-    use alloc::vec::Vec;
+    use alloc::{string::String, vec::Vec};
 
     use crate::{
         concatenation::{
             MAX_PARTS,
             owned::{Concatenation, Concatenator},
         },
-        encoding::owned::Encoder,
+        encoding::owned::{Decoder, Encoder},
     };
 
-    use super::*;
+    use super::{grapheme, *};
 
     impl Ucs2 {
         /// Encodes the given message into a vector of bytes.
         pub fn encode_to_vec(&self, input: &str) -> Result<Vec<u8>, Ucs2EncodeError> {
+            if self.utf16 {
+                return Ok(input.encode_utf16().flat_map(u16::to_be_bytes).collect());
+            }
+
             // Maximum possible UCS-2 units = number of chars
             let char_count = input.chars().count();
             let mut buffer = alloc::vec![0u16; char_count];
@@ -87,6 +168,36 @@ mod impl_owned {
                 },
             }
         }
+
+        /// Decodes the given big-endian UCS2 bytes into a string.
+        ///
+        /// Surrogate pairs are only decoded in UTF-16 mode.
+        pub fn decode_to_string(&self, input: &[u8]) -> Result<String, Ucs2DecodeError> {
+            if input.len() % 2 != 0 {
+                return Err(Ucs2DecodeError::OddLength);
+            }
+
+            let code_units = input
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+
+            if self.utf16 {
+                return char::decode_utf16(code_units)
+                    .map(|result| {
+                        result.map_err(|err| {
+                            Ucs2DecodeError::UndecodableCharacter(err.unpaired_surrogate())
+                        })
+                    })
+                    .collect();
+            }
+
+            code_units
+                .map(|code_unit| {
+                    char::from_u32(code_unit as u32)
+                        .ok_or(Ucs2DecodeError::UndecodableCharacter(code_unit))
+                })
+                .collect()
+        }
     }
 
     impl Encoder for Ucs2 {
@@ -98,6 +209,14 @@ mod impl_owned {
         }
     }
 
+    impl Decoder for Ucs2 {
+        type Error = Ucs2DecodeError;
+
+        fn decode(&self, bytes: &[u8]) -> Result<String, Self::Error> {
+            self.decode_to_string(bytes)
+        }
+    }
+
     impl Concatenator for Ucs2 {
         type Error = Ucs2ConcatenateError;
 
@@ -121,26 +240,16 @@ mod impl_owned {
                 return Err(Ucs2ConcatenateError::PartCapacityExceeded);
             }
 
+            if !self.allow_split_character {
+                return self.concatenate_code_units(message, encoded, part_payload_size);
+            }
+
             let mut parts: Vec<Vec<u8>> = Vec::new();
             let mut i = 0;
 
             while i < total {
-                let mut end = (i + part_payload_size).min(total);
-
-                if !self.allow_split_character {
-                    // If not at the end and our cut is *not* on a 2-byte boundary,
-                    // shrink the part by 1 byte to align to even boundary.
-                    if end < total && (end % 2 != 0) {
-                        end -= 1;
-
-                        // If shrinking removed the entire part -> impossible
-                        if end == i {
-                            return Err(Ucs2ConcatenateError::InvalidBoundary);
-                        }
-                    }
-                }
-
-                // If allow_split_character == true, we accept uneven boundaries as-is.
+                // Splitting characters is allowed, we accept uneven boundaries as-is.
+                let end = (i + part_payload_size).min(total);
 
                 parts.push(encoded[i..end].to_vec());
                 i = end;
@@ -153,6 +262,91 @@ mod impl_owned {
             Ok((Concatenation::concatenated(parts), self.data_coding()))
         }
     }
+
+    impl Ucs2 {
+        /// Splits the encoded message into parts of at most `part_payload_size` bytes,
+        /// without splitting code units, surrogate pairs and, if enabled, grapheme clusters.
+        fn concatenate_code_units(
+            &self,
+            message: &str,
+            encoded: Vec<u8>,
+            part_payload_size: usize,
+        ) -> Result<(Concatenation, DataCoding), Ucs2ConcatenateError> {
+            let boundaries = Boundaries::new(message, self.keep_grapheme_clusters);
+
+            let total = encoded.len() / 2;
+            let part_size = part_payload_size / 2;
+
+            let mut parts: Vec<Vec<u8>> = Vec::new();
+            let mut start = 0;
+
+            while start < total {
+                let mut end = (start + part_size).min(total);
+
+                if end < total {
+                    end = boundaries
+                        .last_before(start, end)
+                        .ok_or(Ucs2ConcatenateError::InvalidBoundary)?;
+                }
+
+                parts.push(encoded[start * 2..end * 2].to_vec());
+                start = end;
+            }
+
+            if parts.len() > MAX_PARTS {
+                return Err(Ucs2ConcatenateError::parts_count_exceeded(parts.len()));
+            }
+
+            Ok((Concatenation::concatenated(parts), self.data_coding()))
+        }
+    }
+
+    /// The code unit offsets where the encoded message may be split.
+    struct Boundaries {
+        /// Offsets between characters, surrogate pairs are never split.
+        characters: Vec<usize>,
+        /// Offsets between grapheme clusters, empty if grapheme clusters may be split.
+        grapheme_clusters: Vec<usize>,
+    }
+
+    impl Boundaries {
+        fn new(message: &str, keep_grapheme_clusters: bool) -> Self {
+            let mut characters = Vec::new();
+            let mut grapheme_clusters = Vec::new();
+            let mut offset = 0;
+            let mut segmenter = grapheme::Segmenter::new();
+
+            for character in message.chars() {
+                characters.push(offset);
+
+                if keep_grapheme_clusters && segmenter.is_boundary(character) {
+                    grapheme_clusters.push(offset);
+                }
+
+                offset += character.len_utf16();
+            }
+
+            Self {
+                characters,
+                grapheme_clusters,
+            }
+        }
+
+        /// Returns the last offset in `start + 1..=end`,
+        /// preferring grapheme cluster boundaries over character boundaries.
+        fn last_before(&self, start: usize, end: usize) -> Option<usize> {
+            let last = |offsets: &[usize]| {
+                let index = offsets.partition_point(|&offset| offset <= end);
+
+                offsets[..index]
+                    .last()
+                    .copied()
+                    .filter(|&offset| offset > start)
+            };
+
+            last(&self.grapheme_clusters).or_else(|| last(&self.characters))
+        }
+    }
 }
 
 #[cfg(test)]
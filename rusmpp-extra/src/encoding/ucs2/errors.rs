@@ -8,6 +8,17 @@ pub enum Ucs2EncodeError {
     UnencodableCharacter,
 }
 
+/// Errors that can occur during UCS2 decoding.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum Ucs2DecodeError {
+    /// Input length is not a multiple of two.
+    #[error("Input length is not a multiple of two")]
+    OddLength,
+    /// Input contains a code unit that is not a valid character.
+    #[error("Input contains undecodable code unit: {0:#06x}")]
+    UndecodableCharacter(u16),
+}
+
 /// Errors that can occur during UCS2 concatenation.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum Ucs2ConcatenateError {
@@ -0,0 +1,115 @@
+//! Approximation of the extended grapheme cluster boundaries defined by Unicode Standard Annex #29.
+//!
+//! Only the rules relevant to short messages are implemented: combining marks, variation selectors,
+//! emoji modifier, tag and zero width joiner sequences, regional indicator pairs (flags) and `CR LF`.
+
+const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Finds the grapheme cluster boundaries of a message, one character after another.
+#[derive(Debug)]
+pub(super) struct Segmenter {
+    previous: Option<char>,
+    /// The number of consecutive regional indicators ending with the previous character.
+    regional_indicators: usize,
+}
+
+impl Segmenter {
+    pub(super) const fn new() -> Self {
+        Self {
+            previous: None,
+            regional_indicators: 0,
+        }
+    }
+
+    /// Returns whether a grapheme cluster boundary precedes `character`.
+    pub(super) fn is_boundary(&mut self, character: char) -> bool {
+        let boundary = match self.previous {
+            None => true,
+            Some(previous) => !self.joins(previous, character),
+        };
+
+        self.regional_indicators = match is_regional_indicator(character) {
+            true => self.regional_indicators + 1,
+            false => 0,
+        };
+
+        self.previous = Some(character);
+
+        boundary
+    }
+
+    fn joins(&self, previous: char, current: char) -> bool {
+        (previous == '\r' && current == '\n')
+            || is_extend(current)
+            || current == ZERO_WIDTH_JOINER
+            || (previous == ZERO_WIDTH_JOINER && is_pictographic(current))
+            // The previous regional indicator starts a pair.
+            || (is_regional_indicator(current) && self.regional_indicators % 2 == 1)
+    }
+}
+
+/// Combining marks, variation selectors, emoji modifiers and tags.
+const fn is_extend(character: char) -> bool {
+    matches!(
+        character as u32,
+        0x0300..=0x036F
+            | 0x0483..=0x0489
+            | 0x0591..=0x05BD
+            | 0x05BF
+            | 0x05C1..=0x05C2
+            | 0x05C4..=0x05C5
+            | 0x05C7
+            | 0x0610..=0x061A
+            | 0x064B..=0x065F
+            | 0x0670
+            | 0x06D6..=0x06DC
+            | 0x06DF..=0x06E4
+            | 0x0900..=0x0903
+            | 0x093A..=0x093C
+            | 0x093E..=0x094F
+            | 0x0951..=0x0957
+            | 0x0962..=0x0963
+            | 0x0E31
+            | 0x0E34..=0x0E3A
+            | 0x0E47..=0x0E4E
+            | 0x1AB0..=0x1AFF
+            | 0x1DC0..=0x1DFF
+            | 0x200C
+            | 0x20D0..=0x20FF
+            | 0x3099..=0x309A
+            | 0xFE00..=0xFE0F
+            | 0xFE20..=0xFE2F
+            | 0x1F3FB..=0x1F3FF
+            | 0xE0020..=0xE007F
+            | 0xE0100..=0xE01EF
+    )
+}
+
+/// Characters that can be joined into emoji zero width joiner sequences.
+const fn is_pictographic(character: char) -> bool {
+    matches!(
+        character as u32,
+        0x00A9
+            | 0x00AE
+            | 0x203C
+            | 0x2049
+            | 0x2122
+            | 0x2139
+            | 0x2194..=0x21AA
+            | 0x231A..=0x23FF
+            | 0x24C2
+            | 0x25AA..=0x25FE
+            | 0x2600..=0x27BF
+            | 0x2934..=0x2935
+            | 0x2B05..=0x2B55
+            | 0x3030
+            | 0x303D
+            | 0x3297
+            | 0x3299
+            | 0x1F000..=0x1FAFF
+    )
+}
+
+const fn is_regional_indicator(character: char) -> bool {
+    matches!(character as u32, 0x1F1E6..=0x1F1FF)
+}
@@ -4,8 +4,8 @@ use crate::{
         owned::{Concatenation, Concatenator},
     },
     encoding::{
-        owned::Encoder,
-        ucs2::{Ucs2, Ucs2ConcatenateError, Ucs2EncodeError},
+        owned::{Decoder, Encoder},
+        ucs2::{Ucs2, Ucs2ConcatenateError, Ucs2DecodeError, Ucs2EncodeError},
     },
 };
 
@@ -26,11 +26,172 @@ mod encode {
             assert!(matches!(err, Ucs2EncodeError::UnencodableCharacter))
         }
     }
+
+    #[test]
+    fn utf16_surrogate_pairs() {
+        let encoder = Ucs2::new().with_utf16(true);
+
+        let (encoded, _) = encoder.encode("Hi 😀").unwrap();
+
+        assert_eq!(
+            encoded,
+            [0x00, 0x48, 0x00, 0x69, 0x00, 0x20, 0xD8, 0x3D, 0xDE, 0x00]
+        );
+    }
+}
+
+mod decode {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "Hello, Привет, 你好";
+
+        let codec = Ucs2::new();
+
+        let (encoded, _) = codec.encode(input).unwrap();
+
+        assert_eq!(codec.decode(&encoded).unwrap(), input);
+    }
+
+    #[test]
+    fn utf16_round_trip() {
+        let input = "Hi 😀👍🏽 🇩🇪 Привет";
+
+        let codec = Ucs2::new().with_utf16(true);
+
+        let (encoded, _) = codec.encode(input).unwrap();
+
+        assert_eq!(codec.decode(&encoded).unwrap(), input);
+    }
+
+    mod error {
+        use super::*;
+
+        #[test]
+        fn odd_length() {
+            let err = Ucs2::new().decode(&[0x00, 0x41, 0x00]).unwrap_err();
+
+            assert_eq!(err, Ucs2DecodeError::OddLength);
+        }
+
+        #[test]
+        fn surrogate() {
+            let err = Ucs2::new().decode(&[0xD8, 0x3D, 0xDE, 0x00]).unwrap_err();
+
+            assert_eq!(err, Ucs2DecodeError::UndecodableCharacter(0xD83D));
+        }
+
+        #[test]
+        fn utf16_unpaired_surrogate() {
+            let err = Ucs2::new()
+                .with_utf16(true)
+                .decode(&[0xD8, 0x3D, 0x00, 0x41])
+                .unwrap_err();
+
+            assert_eq!(err, Ucs2DecodeError::UndecodableCharacter(0xD83D));
+        }
+    }
 }
 
 mod concatenate {
     use super::*;
 
+    /// Concatenates the message and decodes every part.
+    fn decoded_parts(
+        codec: &Ucs2,
+        message: &str,
+        max_message_size: usize,
+        part_header_size: usize,
+    ) -> Result<alloc::vec::Vec<alloc::string::String>, Ucs2ConcatenateError> {
+        let (concatenation, _) = codec.concatenate(message, max_message_size, part_header_size)?;
+
+        Ok(concatenation
+            .collect()
+            .iter()
+            .map(|part| codec.decode(part).unwrap())
+            .collect())
+    }
+
+    mod utf16 {
+        use super::*;
+
+        #[test]
+        fn surrogate_pair_not_split() {
+            let codec = Ucs2::new().with_utf16(true);
+
+            assert_eq!(
+                decoded_parts(&codec, "A😀B", 6, 2).unwrap(),
+                ["A", "😀", "B"]
+            );
+        }
+
+        #[test]
+        fn surrogate_pair_split() {
+            let codec = Ucs2::new()
+                .with_utf16(true)
+                .with_allow_split_character(true);
+
+            let (concatenation, _) = codec.concatenate("A😀B", 6, 2).unwrap();
+
+            assert_eq!(
+                concatenation.collect(),
+                [&[0x00, 0x41, 0xD8, 0x3D][..], &[0xDE, 0x00, 0x00, 0x42]]
+            );
+        }
+
+        #[test]
+        fn grapheme_clusters() {
+            let codec = Ucs2::new().with_utf16(true);
+
+            assert_eq!(decoded_parts(&codec, "ab👍🏽", 8, 0).unwrap(), ["ab👍", "🏽"]);
+            assert_eq!(decoded_parts(&codec, "a🇩🇪", 8, 0).unwrap(), ["a🇩", "🇪"]);
+
+            let codec = codec.with_keep_grapheme_clusters(true);
+
+            assert_eq!(decoded_parts(&codec, "ab👍🏽", 8, 0).unwrap(), ["ab", "👍🏽"]);
+            assert_eq!(decoded_parts(&codec, "a🇩🇪", 8, 0).unwrap(), ["a", "🇩🇪"]);
+            assert_eq!(
+                decoded_parts(&codec, "🇩🇪🇫🇷🇮🇹", 8, 0).unwrap(),
+                ["🇩🇪", "🇫🇷", "🇮🇹"]
+            );
+            assert_eq!(
+                decoded_parts(&codec, "Hi 👨\u{200D}👩\u{200D}👧", 16, 0).unwrap(),
+                ["Hi ", "👨\u{200D}👩\u{200D}👧"]
+            );
+            assert_eq!(
+                decoded_parts(
+                    &Ucs2::new().with_keep_grapheme_clusters(true),
+                    "cafe\u{301}",
+                    8,
+                    0
+                )
+                .unwrap(),
+                ["caf", "e\u{301}"]
+            );
+        }
+
+        // Grapheme clusters that do not fit into a part are split between characters.
+        #[test]
+        fn grapheme_cluster_too_long() {
+            let codec = Ucs2::new()
+                .with_utf16(true)
+                .with_keep_grapheme_clusters(true);
+
+            assert_eq!(decoded_parts(&codec, "👍🏽", 6, 0).unwrap(), ["👍", "🏽"]);
+        }
+
+        #[test]
+        fn invalid_boundary() {
+            let codec = Ucs2::new().with_utf16(true);
+
+            assert_eq!(
+                decoded_parts(&codec, "😀", 3, 1).unwrap_err(),
+                Ucs2ConcatenateError::InvalidBoundary
+            );
+        }
+    }
+
     mod error {
         use super::*;
 
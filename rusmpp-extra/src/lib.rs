@@ -8,8 +8,9 @@
 //! ## Features
 //!
 //! - `alloc`:  Enables the `alloc` crate.
-//! - `concatenation`: Enables concatenation support.
+//! - `concatenation`: Enables concatenation and reassembly support.
 //! - `encoding`: Enables encoding/decoding support.
+//! - `delivery-receipt`: Enables delivery receipt support.
 
 #[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
@@ -22,4 +23,8 @@ pub mod concatenation;
 #[cfg_attr(docsrs, doc(cfg(feature = "encoding")))]
 pub mod encoding;
 
+#[cfg(feature = "delivery-receipt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "delivery-receipt")))]
+pub mod delivery_receipt;
+
 pub mod fallback;
@@ -1,9 +1,10 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{DataEnum, DeriveInput, Fields, Ident, parse};
+use quote::{format_ident, quote};
+use syn::{DataEnum, DeriveInput, Fields, Ident, Token, Type, parse, punctuated::Punctuated};
 
 use crate::{
     container_attributes::{DecodeAttributes, FromIntoAttributes, TestAttributes},
+    from_borrowed::split_generics,
     repr::{Repr, ReprType},
 };
 
@@ -93,9 +94,20 @@ pub fn derive_tlv_value_for_enum(
     let ident = &input.ident;
     let (impl_generics, ty_generics, where_clause) = &input.generics.split_for_impl();
 
+    let pdus = TlvValueAttributes::extract(input)?.pdus;
+
+    // Borrowed TLV values carry a lifetime, their PDUs store the TLVs in a fixed capacity vector.
+    let lifetime = input
+        .generics
+        .lifetimes()
+        .next()
+        .map(|param| &param.lifetime);
+
     // Collect match arms
     let mut tag_arms = Vec::new();
     let mut value_arms = Vec::new();
+    let mut supported_tags = Vec::new();
+    let mut accessors = Vec::new();
     let mut has_other_variant = false;
 
     for variant in &data_enum.variants {
@@ -107,9 +119,13 @@ pub fn derive_tlv_value_for_enum(
                     #ident::#v_ident(_) => TlvTag::#v_ident,
                 });
 
+                supported_tags.push(quote! { TlvTag::#v_ident });
+
                 value_arms.push(quote! {
                     #ident::#v_ident(value) => TlvValue::#v_ident(value),
                 });
+
+                accessors.push(quote_accessors(v_ident, &fields.unnamed[0].ty, lifetime));
             }
             Fields::Named(fields) => {
                 if v_ident == "Other" {
@@ -151,6 +167,8 @@ pub fn derive_tlv_value_for_enum(
                         #ident::Other { tag, .. } => *tag,
                     });
 
+                    supported_tags.push(quote! { TlvTag::Other(_) });
+
                     value_arms.push(quote! {
                         #ident::Other { tag, value } => TlvValue::Other { tag, value },
                     });
@@ -165,13 +183,38 @@ pub fn derive_tlv_value_for_enum(
         }
     }
 
+    let pdu_impls = pdus
+        .iter()
+        .map(|pdu| {
+            let (_, pdu_impl_generics) = split_generics(pdu)?;
+
+            Ok(quote! {
+                impl #pdu_impl_generics #pdu {
+                    #(#accessors)*
+
+                    /// Removes every TLV with the given `tag`.
+                    pub fn remove_tlv(&mut self, tag: TlvTag) {
+                        self.tlvs.retain(|tlv| tlv.tag() != tag);
+                    }
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
     Ok(quote! {
+        #(#pdu_impls)*
+
         impl #impl_generics #ident #ty_generics #where_clause {
             pub const fn tag(&self) -> TlvTag {
                 match self {
                     #(#tag_arms)*
                 }
             }
+
+            /// Returns `true` if a TLV with the given `tag` can be represented by this type.
+            pub const fn supports_tag(tag: TlvTag) -> bool {
+                matches!(tag, #(#supported_tags)|*)
+            }
         }
 
         impl #impl_generics From<#ident #ty_generics> for TlvValue #ty_generics #where_clause {
@@ -189,3 +232,97 @@ pub fn derive_tlv_value_for_enum(
         }
     })
 }
+
+/// Creates the typed getter and setter of the TLV `variant` holding a value of type `ty`.
+fn quote_accessors(variant: &Ident, ty: &Type, lifetime: Option<&syn::Lifetime>) -> TokenStream {
+    let name = snake_case(&variant.to_string());
+    let getter = format_ident!("{name}");
+    let setter = format_ident!("set_{name}");
+
+    let getter_doc = format!("Returns the value of the `{name}` TLV, if present.");
+    let setter_doc = format!("Sets the `{name}` TLV, replacing the existing one.");
+
+    let setter = match lifetime {
+        None => quote! {
+            #[doc = #setter_doc]
+            pub fn #setter(&mut self, value: #ty) {
+                Tlv::replace_or_push(&mut self.tlvs, Tlv::new(TlvValue::#variant(value)));
+            }
+        },
+        Some(lifetime) => quote! {
+            #[doc = #setter_doc]
+            ///
+            /// Fails if the TLV is not present and the TLVs are full.
+            pub fn #setter(&mut self, value: #ty) -> Result<(), Tlv<#lifetime>> {
+                Tlv::replace_or_push(&mut self.tlvs, Tlv::new(TlvValue::#variant(value)))
+            }
+        },
+    };
+
+    quote! {
+        #[doc = #getter_doc]
+        pub fn #getter(&self) -> Option<&#ty> {
+            // The TLV values live in child modules of `tlv`, so the private field is visible.
+            // Unlike `Tlv::value`, it keeps the lifetime of borrowed values.
+            self.tlvs.iter().find_map(|tlv| match &tlv.value {
+                Some(TlvValue::#variant(value)) => Some(value),
+                _ => None,
+            })
+        }
+
+        #setter
+    }
+}
+
+/// Converts a `PascalCase` variant name into `snake_case`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (index, char) in name.char_indices() {
+        if char.is_ascii_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+
+            snake.push(char.to_ascii_lowercase());
+        } else {
+            snake.push(char);
+        }
+    }
+
+    snake
+}
+
+/// `#[tlv_value(pdus(path::to::Pdu, path::to::Borrowed<'a, N>))]`
+#[derive(Default)]
+struct TlvValueAttributes {
+    /// The PDUs carrying these TLVs, they get a typed getter and setter for every TLV.
+    pdus: Vec<Type>,
+}
+
+impl TlvValueAttributes {
+    fn extract(input: &DeriveInput) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+
+        for attr in &input.attrs {
+            if attr.path().is_ident("tlv_value") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("pdus") {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+
+                        attributes
+                            .pdus
+                            .extend(Punctuated::<Type, Token![,]>::parse_terminated(&content)?);
+
+                        return Ok(());
+                    }
+
+                    Err(meta.error("unsupported tlv_value attribute, expected `pdus(...)`"))
+                })?;
+            }
+        }
+
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,431 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, Ident, Path, PathArguments, Token, Type,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+pub fn derive_from_borrowed(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let borrowed = BorrowedType::extract(input)?;
+
+    let borrowed_ty = &borrowed.ty;
+    let borrowed_path = &borrowed.path;
+    let impl_generics = &borrowed.impl_generics;
+
+    let (body, to_borrowed_body) = match &input.data {
+        Data::Struct(data_struct) => {
+            let Fields::Named(fields_named) = &data_struct.fields else {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "FromBorrowed can only be derived for structs with named fields",
+                ));
+            };
+
+            let fields = fields_named.named.iter().map(|field| {
+                let ident = field.ident.as_ref().expect("Named fields must have idents");
+                let value = quote_into_owned(&field.ty, quote! { parts.#ident });
+
+                quote! { #ident: #value }
+            });
+
+            let borrowed_fields = fields_named.named.iter().map(|field| {
+                let ident = field.ident.as_ref().expect("Named fields must have idents");
+                let value = quote_to_borrowed(&field.ty, quote! { &self.#ident });
+
+                quote! { #ident: #value }
+            });
+
+            let parts_path = &borrowed.parts;
+
+            let body = quote! {
+                let parts = value.into_parts();
+
+                Self {
+                    #(#fields),*
+                }
+            };
+
+            let to_borrowed_body = quote! {
+                ::core::option::Option::Some(#borrowed_path::from_parts(#parts_path {
+                    #(#borrowed_fields),*
+                }))
+            };
+
+            (body, to_borrowed_body)
+        }
+        Data::Enum(data_enum) => {
+            let borrowed_arms = data_enum.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+
+                match &variant.fields {
+                    Fields::Unit => quote! {
+                        Self::#variant_ident => #borrowed_path::#variant_ident
+                    },
+                    Fields::Unnamed(fields_unnamed) => {
+                        let bindings = (0..fields_unnamed.unnamed.len())
+                            .map(|index| Ident::new(&format!("field_{index}"), variant.span()))
+                            .collect::<Vec<_>>();
+
+                        let values = fields_unnamed
+                            .unnamed
+                            .iter()
+                            .zip(bindings.iter())
+                            .map(|(field, binding)| quote_to_borrowed(&field.ty, quote! { #binding }));
+
+                        quote! {
+                            Self::#variant_ident(#(#bindings),*) => #borrowed_path::#variant_ident(#(#values),*)
+                        }
+                    }
+                    Fields::Named(fields_named) => {
+                        let bindings = fields_named
+                            .named
+                            .iter()
+                            .map(|field| field.ident.as_ref().expect("Named fields must have idents"))
+                            .collect::<Vec<_>>();
+
+                        let values = fields_named.named.iter().map(|field| {
+                            let ident = field.ident.as_ref().expect("Named fields must have idents");
+                            let value = quote_to_borrowed(&field.ty, quote! { #ident });
+
+                            quote! { #ident: #value }
+                        });
+
+                        quote! {
+                            Self::#variant_ident { #(#bindings),* } => #borrowed_path::#variant_ident { #(#values),* }
+                        }
+                    }
+                }
+            });
+
+            let arms = data_enum.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+
+                match &variant.fields {
+                    Fields::Unit => quote! {
+                        #borrowed_path::#variant_ident => Self::#variant_ident
+                    },
+                    Fields::Unnamed(fields_unnamed) => {
+                        let bindings = (0..fields_unnamed.unnamed.len())
+                            .map(|index| Ident::new(&format!("field_{index}"), variant.span()))
+                            .collect::<Vec<_>>();
+
+                        let values = fields_unnamed
+                            .unnamed
+                            .iter()
+                            .zip(bindings.iter())
+                            .map(|(field, binding)| quote_into_owned(&field.ty, quote! { #binding }));
+
+                        quote! {
+                            #borrowed_path::#variant_ident(#(#bindings),*) => Self::#variant_ident(#(#values),*)
+                        }
+                    }
+                    Fields::Named(fields_named) => {
+                        let bindings = fields_named
+                            .named
+                            .iter()
+                            .map(|field| field.ident.as_ref().expect("Named fields must have idents"))
+                            .collect::<Vec<_>>();
+
+                        let values = fields_named.named.iter().map(|field| {
+                            let ident = field.ident.as_ref().expect("Named fields must have idents");
+                            let value = quote_into_owned(&field.ty, quote! { #ident });
+
+                            quote! { #ident: #value }
+                        });
+
+                        quote! {
+                            #borrowed_path::#variant_ident { #(#bindings),* } => Self::#variant_ident { #(#values),* }
+                        }
+                    }
+                }
+            });
+
+            let body = quote! {
+                match value {
+                    #(#arms),*
+                }
+            };
+
+            let to_borrowed_body = quote! {
+                ::core::option::Option::Some(match self {
+                    #(#borrowed_arms),*
+                })
+            };
+
+            (body, to_borrowed_body)
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "FromBorrowed can only be derived for structs or enums",
+            ));
+        }
+    };
+
+    let doc = format!("Converts [`Self`] into an owned [`{name}`], copying all borrowed data.");
+
+    // Only borrowed types with a capacity (const generics) can fail to hold an owned value
+    let as_borrowed = if borrowed.capacity {
+        let doc = format!(
+            "Returns a borrowed view of the [`{name}`] without copying.\n\nReturns `None` if a list holds more than `N` elements."
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn as_borrowed #impl_generics (&'a self) -> ::core::option::Option<#borrowed_ty> {
+                crate::borrow::ToBorrowed::to_borrowed(self)
+            }
+        }
+    } else {
+        let doc = format!("Returns a borrowed view of the [`{name}`] without copying.");
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn as_borrowed #impl_generics (&'a self) -> #borrowed_ty {
+                crate::borrow::ToBorrowed::to_borrowed(self)
+                    .expect("Borrowed types without a capacity hold any owned value")
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<#borrowed_ty> for #name {
+            fn from(value: #borrowed_ty) -> Self {
+                #body
+            }
+        }
+
+        impl #impl_generics #borrowed_ty {
+            #[doc = #doc]
+            #[inline]
+            pub fn into_owned(self) -> #name {
+                ::core::convert::From::from(self)
+            }
+        }
+
+        impl #impl_generics crate::borrow::ToBorrowed<'a, #borrowed_ty> for #name {
+            fn to_borrowed(&'a self) -> ::core::option::Option<#borrowed_ty> {
+                #to_borrowed_body
+            }
+        }
+
+        impl #name {
+            #as_borrowed
+        }
+    })
+}
+
+/// Borrows the owned field `value` as its borrowed field type, see `crate::borrow::ToBorrowed`.
+///
+/// `Option` fields are converted element-wise, any other field is converted using `ToBorrowed`.
+/// Evaluates to `None` if a list does not fit into the borrowed type.
+fn quote_to_borrowed(ty: &Type, value: TokenStream) -> TokenStream {
+    match outer_ident(ty).map(|ident| ident.to_string()).as_deref() {
+        Some("Option") => quote! {
+            match #value {
+                ::core::option::Option::Some(value) => ::core::option::Option::Some(
+                    crate::borrow::ToBorrowed::to_borrowed(value)?,
+                ),
+                ::core::option::Option::None => ::core::option::Option::None,
+            }
+        },
+        _ => quote! { crate::borrow::ToBorrowed::to_borrowed(#value)? },
+    }
+}
+
+/// Converts a borrowed value into the owned field type `ty`.
+///
+/// `Vec` and `Option` fields are converted element-wise, any other field is converted using `From`.
+fn quote_into_owned(ty: &Type, value: TokenStream) -> TokenStream {
+    match outer_ident(ty).map(|ident| ident.to_string()).as_deref() {
+        Some("Vec") => quote! {
+            ::core::iter::IntoIterator::into_iter(#value)
+                .map(::core::convert::From::from)
+                .collect()
+        },
+        Some("Option") => quote! {
+            ::core::option::Option::map(#value, ::core::convert::From::from)
+        },
+        _ => quote! { ::core::convert::From::from(#value) },
+    }
+}
+
+fn outer_ident(ty: &Type) -> Option<&Ident> {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.last().map(|segment| &segment.ident),
+        _ => None,
+    }
+}
+
+/// `#[from_borrowed(path::to::Borrowed<'a, N>)]` or `#[from_borrowed(path::to::Borrowed<'a, N>, parts = path::to::BorrowedParts)]`
+struct BorrowedType {
+    /// The borrowed type with its generics, e.g. `crate::pdus::borrowed::SubmitSm<'a, N>`.
+    ty: Type,
+    /// The borrowed type without generics, e.g. `crate::pdus::borrowed::SubmitSm`.
+    path: Path,
+    /// The parts struct of the borrowed type, e.g. `crate::pdus::borrowed::parts::SubmitSmParts`.
+    ///
+    /// Defaults to `<Borrowed>Parts` in the `parts` module next to the borrowed type.
+    parts: Path,
+    /// The generics of the borrowed type, e.g. `<'a, const N: usize>`.
+    impl_generics: TokenStream,
+    /// Whether the borrowed type has const generics, i.e. a capacity.
+    capacity: bool,
+}
+
+/// The arguments of the `#[from_borrowed(...)]` attribute.
+struct BorrowedArgs {
+    ty: Type,
+    parts: Option<Path>,
+}
+
+impl Parse for BorrowedArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+
+        if input.is_empty() {
+            return Ok(Self { ty, parts: None });
+        }
+
+        input.parse::<Token![,]>()?;
+
+        let key: Ident = input.parse()?;
+
+        if key != "parts" {
+            return Err(syn::Error::new_spanned(
+                key,
+                "unknown from_borrowed argument, expected parts",
+            ));
+        }
+
+        input.parse::<Token![=]>()?;
+
+        let parts = input.parse()?;
+
+        Ok(Self {
+            ty,
+            parts: Some(parts),
+        })
+    }
+}
+
+impl BorrowedType {
+    fn extract(input: &DeriveInput) -> syn::Result<Self> {
+        let attr = input
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("from_borrowed"))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &input.ident,
+                    "missing #[from_borrowed(path::to::Borrowed<'a>)] attribute",
+                )
+            })?;
+
+        let BorrowedArgs { ty, parts } = attr.parse_args()?;
+        let (path, impl_generics) = split_generics(&ty)?;
+
+        let capacity = has_const_generics(&ty);
+
+        let parts = match parts {
+            Some(parts) => parts,
+            None => {
+                let mut parts = path.clone();
+
+                let last = parts
+                    .segments
+                    .pop()
+                    .expect("split_generics checked that the path is not empty")
+                    .into_value();
+
+                parts
+                    .segments
+                    .push(Ident::new("parts", last.ident.span()).into());
+                parts
+                    .segments
+                    .push(Ident::new(&format!("{}Parts", last.ident), last.ident.span()).into());
+
+                parts
+            }
+        };
+
+        Ok(Self {
+            ty,
+            path,
+            parts,
+            impl_generics,
+            capacity,
+        })
+    }
+}
+
+/// Returns `true` if a type like `path::to::Borrowed<'a, N>` has type like, i.e. const generic, arguments.
+fn has_const_generics(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+
+    type_path.path.segments.last().is_some_and(|segment| {
+        matches!(
+            &segment.arguments,
+            PathArguments::AngleBracketed(arguments)
+                if arguments
+                    .args
+                    .iter()
+                    .any(|argument| matches!(argument, GenericArgument::Type(_)))
+        )
+    })
+}
+
+/// Splits a type like `path::to::Borrowed<'a, N>` into its path without generics and its impl generics, e.g. `<'a, const N: usize>`.
+///
+/// Type like arguments are assumed to be `usize` const generics.
+pub fn split_generics(ty: &Type) -> syn::Result<(Path, TokenStream)> {
+    let Type::Path(type_path) = ty else {
+        return Err(syn::Error::new_spanned(ty, "expected a path to a type"));
+    };
+
+    let mut path = type_path.path.clone();
+
+    let last = path
+        .segments
+        .last_mut()
+        .ok_or_else(|| syn::Error::new_spanned(ty, "expected a path to a type"))?;
+
+    let arguments = core::mem::replace(&mut last.arguments, PathArguments::None);
+
+    let params = match arguments {
+        PathArguments::None => Vec::new(),
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .into_iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Lifetime(lifetime) => Some(quote! { #lifetime }),
+                // Type like arguments of borrowed types are const generics (e.g. `N`)
+                GenericArgument::Type(Type::Path(type_path)) => type_path
+                    .path
+                    .get_ident()
+                    .map(|ident| quote! { const #ident: usize }),
+                _ => None,
+            })
+            .collect(),
+        PathArguments::Parenthesized(_) => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "unexpected parenthesized arguments",
+            ));
+        }
+    };
+
+    let impl_generics = if params.is_empty() {
+        quote! {}
+    } else {
+        quote! { <#(#params),*> }
+    };
+
+    Ok((path, impl_generics))
+}
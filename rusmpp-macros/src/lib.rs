@@ -8,6 +8,7 @@ use syn::{DeriveInput, parse_macro_input};
 mod container_attributes;
 mod derive;
 mod enums;
+mod from_borrowed;
 mod parts;
 mod repr;
 mod structs;
@@ -55,7 +56,12 @@ pub fn rusmpp(input: TokenStream) -> TokenStream {
 }
 
 /// Creates a `TlvValue`-like and implements `Into<TlvValue>` and `Into<Tlv>`.
-#[proc_macro_derive(TlvValue)]
+///
+/// # Container attributes
+///
+/// - `#[tlv_value(pdus(path::to::Pdu, path::to::Borrowed<'a, N>))]`: The PDUs carrying these TLVs in a `tlvs` field.
+///   Every PDU gets a typed getter and a replacing setter for each TLV (e.g. `receipted_message_id` and `set_receipted_message_id`) and a `remove_tlv` method.
+#[proc_macro_derive(TlvValue, attributes(tlv_value))]
 pub fn tlv_value(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -63,3 +69,24 @@ pub fn tlv_value(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+/// Implements `From<Borrowed>` for an owned struct or enum and adds an `into_owned` method to the borrowed type.
+///
+/// Also implements `crate::borrow::ToBorrowed` and adds an `as_borrowed` method to the owned type.
+/// `as_borrowed` returns an `Option` if the borrowed type has const generics, since its lists may not hold all owned elements.
+///
+/// # Container attributes
+///
+/// - `#[from_borrowed(path::to::Borrowed<'a, N>)]`: The borrowed counterpart. Lifetimes and const generics (assumed to be `usize`) of the borrowed type become the generics of the implementations.
+/// - `#[from_borrowed(path::to::Borrowed<'a, N>, parts = path::to::BorrowedParts)]`: The parts struct of a borrowed struct, if it is not `path::to::parts::BorrowedParts`.
+///
+/// Borrowed structs are converted using their `into_parts` method. `Vec` and `Option` fields are converted element-wise, any other field or variant value is converted using `From`.
+/// Owned structs are borrowed using the borrowed `from_parts` method. `Option` fields are borrowed element-wise, any other field or variant value is borrowed using `ToBorrowed`.
+#[proc_macro_derive(FromBorrowed, attributes(from_borrowed))]
+pub fn from_borrowed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    from_borrowed::derive_from_borrowed(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
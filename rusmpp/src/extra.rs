@@ -37,13 +37,27 @@ pub mod encoding {
 }
 
 pub mod concatenation {
-    //! Concatenation support.
+    //! Concatenation and reassembly support.
 
     pub use rusmpp_extra::concatenation::{MAX_PARTS, MIN_PARTS, owned::*};
 
     pub mod errors {
-        //! Errors related to concatenated message creation.
+        //! Errors related to concatenated message creation and reassembly.
 
         pub use rusmpp_extra::concatenation::errors::*;
     }
 }
+
+pub mod delivery_receipt {
+    //! Delivery receipt support.
+
+    pub use rusmpp_extra::delivery_receipt::{
+        DeliveryReceiptDate, format_stat, owned::*, parse_stat,
+    };
+
+    pub mod errors {
+        //! Errors related to delivery receipts.
+
+        pub use rusmpp_extra::delivery_receipt::errors::*;
+    }
+}
@@ -86,7 +86,7 @@
 //!
 //! - `tokio-codec`: Implements [`Encoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Encoder.html) and [`Decoder`](https://docs.rs/tokio-util/latest/tokio_util/codec/trait.Decoder.html) traits.
 //! - `verbose`: Enables verbose error reports.
-//! - `extra`: Enables encoding/decoding and concatenation support for [`SubmitSm`](crate::pdus::SubmitSm).
+//! - `extra`: Enables encoding/decoding and concatenation support for [`SubmitSm`](crate::pdus::SubmitSm), reassembly of concatenated [`DeliverSm`](crate::pdus::DeliverSm) and [`DataSm`](crate::pdus::DataSm), and delivery receipt support for [`DeliverSm`](crate::pdus::DeliverSm).
 //! - `serde`: Implements [`Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html) trait for all SMPP types.
 //! - `serde-deserialize-unchecked`: Implements [`Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) trait for all SMPP types, but does not check the validity of the data. Use with caution.
 //! - `tracing`: Enables logging using [`tracing`](https://docs.rs/tracing/latest/tracing/).
@@ -125,6 +125,8 @@ pub mod session;
 
 pub mod udhs;
 
+pub mod validate;
+
 #[cfg(feature = "extra")]
 #[cfg_attr(docsrs, doc(cfg(feature = "extra")))]
 pub mod extra;
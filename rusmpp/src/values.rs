@@ -14,19 +14,20 @@ pub mod parts {
 }
 
 pub use rusmpp_core::values::{
-    AddrSubunit, AlertOnMessageDelivery, Ansi41Cbs, Ansi41Specific, Ansi136, BearerType,
-    BroadcastAreaFormat, BroadcastAreaSuccess, BroadcastChannelIndicator, BroadcastContentType,
-    BroadcastFrequencyInterval, BroadcastMessageClass, BroadcastRepNum, CallbackNumPresInd,
-    CongestionState, DataCoding, DeliveryFailureReason, DestAddrNpResolution, DestFlag,
-    DisplayTime, DpfResult, EncodingContentType, ErrorCodeNetworkType, EsmClass,
+    AbsoluteTime, AddrSubunit, AlertOnMessageDelivery, Ansi41Cbs, Ansi41Specific, Ansi136,
+    BearerType, BroadcastAreaFormat, BroadcastAreaSuccess, BroadcastChannelIndicator,
+    BroadcastContentType, BroadcastFrequencyInterval, BroadcastMessageClass, BroadcastRepNum,
+    CallbackNumPresInd, CongestionState, DataCoding, DeliveryFailureReason, DestAddrNpResolution,
+    DestFlag, DisplayTime, DpfResult, EncodingContentType, ErrorCodeNetworkType, EsmClass,
     GenericServiceType, GsmCbs, GsmFeatures, GsmSms, Indicator, InterfaceVersion,
     IntermediateNotification, Is95, ItsReplyType, ItsSessionInfo, LanguageIndicator,
     MCDeliveryReceipt, MessageState, MessageType, MessagingMode, MoreMessagesToSend,
     MsAvailabilityStatus, MsMsgWaitFacilities, MsValidity, MsValidityBehavior,
     MsValidityInformation, NetworkErrorCode, NetworkType, Npi, NumberOfMessages, PayloadType,
     Presentation, PriorityFlag, PriorityFlagType, PrivacyIndicator, RegisteredDelivery,
-    ReplaceIfPresentFlag, Screening, SetDpf, SmeOriginatedAcknowledgement, SubaddressTag, Ton,
-    TypeOfMessage, TypeOfNetwork, UnitOfTime, UnitsOfTime, UserMessageReference, UssdServiceOp,
+    RelativeTime, ReplaceIfPresentFlag, Screening, SetDpf, SmeOriginatedAcknowledgement, SmppTime,
+    SmppTimeError, SubaddressTag, Ton, TypeOfMessage, TypeOfNetwork, UnitOfTime, UnitsOfTime,
+    UserMessageReference, UssdServiceOp,
     owned::{
         BroadcastAreaIdentifier, MessagePayload, ServiceType, Subaddress, UnsuccessSme,
         {DestAddress, DistributionListName, SmeAddress},
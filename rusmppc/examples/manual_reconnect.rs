@@ -1,4 +1,6 @@
-//! An opinionated reconnect logic implementation, since rusmppc does not handle reconnects.
+//! An opinionated reconnect logic implementation, built on top of the public API.
+//!
+//! For built-in reconnects, see `ConnectionBuilder::reconnect`.
 //!
 //! Run with
 //!
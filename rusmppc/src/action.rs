@@ -1,4 +1,7 @@
-use crate::{CloseRequest, PendingResponses, RegisteredRequest, Request, UnregisteredRequest};
+use crate::{
+    AbandonRequest, CloseRequest, DrainRequest, PendingResponses, RegisteredRequest, Request,
+    UnregisteredRequest,
+};
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
@@ -10,6 +13,14 @@ pub enum Action {
     Remove(u32),
     /// The connection will stop reading from the server, stop time keeping, close the requests channel, flush pending requests and terminate.
     Close(CloseRequest),
+    /// The connection will stop accepting new operations from the client and acknowledge when there are no more in-flight requests.
+    ///
+    /// Responses and [`Unbind`](rusmpp::Pdu::Unbind) are still accepted, see [`Client::shutdown`](crate::Client::shutdown).
+    Drain(DrainRequest),
+    /// The connection will fail the requests waiting to be sent and acknowledge with their sequence numbers and the pending responses.
+    ///
+    /// Sent after a [`Drain`](Action::Drain) timed out, see [`Client::shutdown`](crate::Client::shutdown).
+    Abandon(AbandonRequest),
     /// Sent from the client to the connection to check if the connection is closed or not.
     ///
     /// The client would fail to send this action through the channel if the connection is closed.
@@ -0,0 +1,121 @@
+//! Automatic responses to operations sent by the server.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use rusmpp::{
+    Command, CommandId, CommandStatus, Pdu,
+    pdus::{DataSmResp, DeliverSmResp},
+};
+
+/// Policy for responding to [`DeliverSm`](rusmpp::pdus::DeliverSm) and [`DataSm`](rusmpp::pdus::DataSm) operations sent by the server.
+///
+/// Servers stop delivering messages to clients that do not respond to these operations.
+///
+/// [`AlertNotification`](rusmpp::pdus::AlertNotification) has no response `PDU` and is always passed through the event stream as is.
+///
+/// See [`ConnectionBuilder::auto_response`](crate::ConnectionBuilder::auto_response).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AutoResponse {
+    /// The client responds to the operations, e.g. using [`Client::deliver_sm_resp`](crate::Client::deliver_sm_resp).
+    #[default]
+    Manual,
+    /// The connection responds with [`EsmeRok`](CommandStatus::EsmeRok) as soon as an operation is received,
+    /// before passing it through the event stream.
+    ///
+    /// Responses sent by the client for these operations are sent to the server as well.
+    Immediate,
+    /// The client responds to the operations after handling them.
+    ///
+    /// If the client does not respond within the `timeout`, the connection responds with the given `status`,
+    /// e.g. [`EsmeRxTAppn`](CommandStatus::EsmeRxTAppn) to let the server retry the delivery later.
+    AfterHandler {
+        /// How long the connection waits for the client to respond.
+        timeout: Duration,
+        /// The status of the response sent by the connection after the timeout.
+        status: CommandStatus,
+    },
+}
+
+impl AutoResponse {
+    /// Creates a new [`AutoResponse::AfterHandler`] policy.
+    pub const fn after_handler(timeout: Duration, status: CommandStatus) -> Self {
+        Self::AfterHandler { timeout, status }
+    }
+
+    /// Returns `true` if the policy applies to operations with the given `id`.
+    pub(crate) const fn applies_to(id: CommandId) -> bool {
+        matches!(id, CommandId::DeliverSm | CommandId::DataSm)
+    }
+}
+
+/// An operation sent by the server, waiting for a response from the client.
+#[derive(Debug)]
+pub(crate) struct PendingOperation {
+    id: CommandId,
+    sequence_number: u32,
+    deadline: Instant,
+}
+
+/// Operations waiting for a response from the client, ordered by their deadline.
+#[derive(Debug, Default)]
+pub(crate) struct PendingOperations {
+    operations: VecDeque<PendingOperation>,
+}
+
+impl PendingOperations {
+    /// Registers an operation sent by the server.
+    ///
+    /// All operations share the same timeout, so pushing to the back keeps the deadlines ordered.
+    pub(crate) fn push(&mut self, id: CommandId, sequence_number: u32, deadline: Instant) {
+        self.operations.push_back(PendingOperation {
+            id,
+            sequence_number,
+            deadline,
+        });
+    }
+
+    /// Removes the operation answered by the given response sent by the client.
+    pub(crate) fn respond(&mut self, response: &Command) {
+        let id = match response.id() {
+            CommandId::DeliverSmResp => CommandId::DeliverSm,
+            CommandId::DataSmResp => CommandId::DataSm,
+            _ => return,
+        };
+
+        self.operations.retain(|operation| {
+            operation.id != id || operation.sequence_number != response.sequence_number()
+        });
+    }
+
+    /// Returns the deadline of the next operation.
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.operations.front().map(|operation| operation.deadline)
+    }
+
+    /// Removes the next operation if its deadline is reached and returns the response to send with the given `status`.
+    pub(crate) fn pop_expired(&mut self, now: Instant, status: CommandStatus) -> Option<Command> {
+        if self.next_deadline()? > now {
+            return None;
+        }
+
+        let operation = self.operations.pop_front()?;
+
+        Some(response(operation.id, operation.sequence_number, status))
+    }
+}
+
+/// Creates the response to the operation with the given `id` and `sequence_number`.
+pub(crate) fn response(id: CommandId, sequence_number: u32, status: CommandStatus) -> Command {
+    let pdu = match id {
+        CommandId::DataSm => Pdu::DataSmResp(DataSmResp::default()),
+        _ => Pdu::DeliverSmResp(DeliverSmResp::default()),
+    };
+
+    Command::builder()
+        .status(status)
+        .sequence_number(sequence_number)
+        .pdu(pdu)
+}
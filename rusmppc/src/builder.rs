@@ -1,18 +1,31 @@
-use std::{net::SocketAddr, time::Duration};
+use std::{collections::BTreeMap, net::SocketAddr, time::Duration};
 
 use futures::Stream;
-use rusmpp::tokio_codec::CommandCodec;
+use rusmpp::{
+    CommandId,
+    pdus::{BindReceiver, BindTransceiver, BindTransmitter},
+    session::SessionState,
+    tokio_codec::{CommandCodec, RecoverableCommandCodec},
+    types::COctetString,
+    values::InterfaceVersion,
+};
 use tokio::{
     io::{AsyncRead, AsyncWrite},
-    net::TcpStream,
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::{mpsc, watch},
 };
+use tokio_stream::wrappers::UnboundedReceiverStream;
 use tokio_util::codec::Framed;
 
 use crate::{
-    Client, MaybeTlsStream,
+    Action, AutoResponse, Client, MaybeTlsStream,
     delay::TokioDelay,
     error::Error,
     event::{DefaultEventChannel, DiscardEventChannel, EventChannel, InsightEventChannel},
+    outbind::OutbindCredentials,
+    pool::{Balance, ClientPool, PoolBind, PoolEvent},
+    rate::{RateLimit, ThrottleRetry},
+    reconnect::{Backoff, Reconnector},
 };
 
 /// Builder for creating a new `SMPP` connection.
@@ -24,9 +37,26 @@ pub struct ConnectionBuilder<E = DefaultEventChannel> {
     pub(crate) enquire_link_response_timeout: Duration,
     /// Whether to automatically respond to enquire link requests from the server.
     pub(crate) auto_enquire_link_response: bool,
+    /// Policy for responding to deliver_sm and data_sm operations from the server.
+    pub(crate) auto_response: AutoResponse,
+    /// Whether to automatically respond to unbind requests from the server and close the connection.
+    pub(crate) auto_unbind_response: bool,
     /// Timeout for waiting for a response from the server.
     pub(crate) response_timeout: Option<Duration>,
+    /// Maximum number of requests waiting for a response from the server.
+    pub(crate) window: Option<usize>,
+    /// Rate limit applied to all operations sent by the client.
+    pub(crate) rate_limit: Option<RateLimit>,
+    /// Rate limits applied to operations with a specific command id.
+    pub(crate) command_rate_limits: BTreeMap<CommandId, RateLimit>,
+    pub(crate) throttle_retry: Option<ThrottleRetry>,
+    /// Interface version used in bind commands. If None, the interface version of the bind command is used.
+    pub(crate) interface_version: Option<InterfaceVersion>,
     pub(crate) check_interface_version: bool,
+    /// Whether to check commands against the session state.
+    pub(crate) check_session_state: bool,
+    /// Whether to validate outgoing commands before sending them.
+    pub(crate) validate_commands: bool,
     /// TLS configurations provided by the user. If None, default configurations will be used.
     #[cfg(feature = "rustls")]
     rustls_config: Option<rustls::ClientConfig>,
@@ -50,8 +80,17 @@ impl ConnectionBuilder<DefaultEventChannel> {
     /// - `enquire_link_interval`: 30 seconds
     /// - `enquire_link_response_timeout`: 5 seconds
     /// - `auto_enquire_link_response`: true
+    /// - `auto_response`: [`AutoResponse::Manual`]
+    /// - `auto_unbind_response`: false
     /// - `response_timeout`: 5 seconds
+    /// - `window`: None
+    /// - `rate_limit`: None
+    /// - `command_rate_limits`: None
+    /// - `throttle_retry`: None
+    /// - `interface_version`: None
     /// - `check_interface_version`: true
+    /// - `check_session_state`: true
+    /// - `validate_commands`: false
     /// - `rustls_config`: default configuration will be used if TLS is enabled. See [`rustls_config`](Self::rustls_config) for more details.
     /// - `native_tls_connector`: default connector will be used if TLS is enabled. See [`native_tls_connector`](Self::native_tls_connector) for more details.
     pub fn new() -> Self {
@@ -60,8 +99,17 @@ impl ConnectionBuilder<DefaultEventChannel> {
             enquire_link_interval: Some(Duration::from_secs(30)),
             enquire_link_response_timeout: Duration::from_secs(5),
             auto_enquire_link_response: true,
+            auto_response: AutoResponse::Manual,
+            auto_unbind_response: false,
             response_timeout: Some(Duration::from_secs(5)),
+            window: None,
+            rate_limit: None,
+            command_rate_limits: BTreeMap::new(),
+            throttle_retry: None,
+            interface_version: None,
             check_interface_version: true,
+            check_session_state: true,
+            validate_commands: false,
             #[cfg(feature = "rustls")]
             rustls_config: None,
             #[cfg(feature = "native-tls")]
@@ -83,6 +131,225 @@ impl<E> ConnectionBuilder<E> {
     pub fn events(self) -> EventsConnectionBuilder<E> {
         EventsConnectionBuilder { builder: self }
     }
+
+    /// Reconnects automatically when the connection is lost.
+    ///
+    /// When the connection is lost, the background task re-dials the URL, waiting between attempts
+    /// using an exponential backoff with optional jitter, and replays the last successful bind command
+    /// ([`BindTransmitter`](rusmpp::pdus::BindTransmitter), [`BindReceiver`](rusmpp::pdus::BindReceiver) or [`BindTransceiver`](rusmpp::pdus::BindTransceiver)).
+    ///
+    /// The progress is reported through the event stream as [`Reconnect`](crate::Reconnect) events.
+    ///
+    /// # Notes
+    ///
+    /// - The client does not reconnect if the session was unbound, by the client or by the server, before the connection was closed.
+    /// - Requests that were already sent to the lost connection fail with [`Error::ConnectionClosed`], since the server might have processed them.
+    /// - Requests that were not sent yet, e.g. held back by the rate limiter, are sent once the client is reconnected and rebound.
+    /// - Requests issued while reconnecting are queued and sent once the client is reconnected and rebound.
+    /// - [`Client::close`] stops reconnecting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use rusmppc::ConnectionBuilder;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (client, events) = ConnectionBuilder::new()
+    ///     .reconnect()
+    ///     .initial_delay(Duration::from_secs(1))
+    ///     .max_delay(Duration::from_secs(30))
+    ///     .connect("smpp://localhost:2775")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reconnect(self) -> ReconnectConnectionBuilder<E> {
+        ReconnectConnectionBuilder::new(self)
+    }
+
+    /// Accepts a connection initiated by the `SMPP` server.
+    ///
+    /// Instead of dialing out, the client waits for the server to connect, send an [`Outbind`](rusmpp::pdus::Outbind)
+    /// and then binds as a receiver or transceiver over the accepted connection.
+    ///
+    /// See [`OutbindConnectionBuilder`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use rusmpp::{pdus::BindReceiver, types::COctetString};
+    /// # use rusmppc::ConnectionBuilder;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (client, events) = ConnectionBuilder::new()
+    ///     .outbind()
+    ///     .system_id(COctetString::from_str("SMSC")?)
+    ///     .password(COctetString::from_str("secret")?)
+    ///     .bind_receiver(
+    ///         BindReceiver::builder()
+    ///             .system_id(COctetString::from_str("esme")?)
+    ///             .password(COctetString::from_str("esme")?)
+    ///             .build(),
+    ///     )
+    ///     .listen("0.0.0.0:2775")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outbind(self) -> OutbindConnectionBuilder<E> {
+        OutbindConnectionBuilder::new(self)
+    }
+
+    /// Connects a pool of clients.
+    ///
+    /// Every client of the pool is connected and bound using the same configurations.
+    /// Requests are distributed over the clients using a [`Balance`] strategy
+    /// and clients whose connection is closed are replaced in the background.
+    ///
+    /// See [`PoolConnectionBuilder`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rusmpp::pdus::SubmitSm;
+    /// # use rusmppc::{Balance, ConnectionBuilder};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (pool, events) = ConnectionBuilder::new()
+    ///     .pool()
+    ///     .size(4)
+    ///     .balance(Balance::LeastPending)
+    ///     .connect(["smpp://smsc-1:2775", "smpp://smsc-2:2775"])
+    ///     .await?;
+    ///
+    /// pool.client().await?.submit_sm(SubmitSm::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pool(self) -> PoolConnectionBuilder<E> {
+        PoolConnectionBuilder::new(self)
+    }
+}
+
+impl<E> ConnectionBuilder<E> {
+    /// Opens a (possibly TLS protected) stream to the `SMPP` server.
+    ///
+    /// See [`NoSpawnConnectionBuilder::connect`] for the supported URL schemes and errors.
+    pub(crate) async fn dial(&self, url: &str) -> Result<MaybeTlsStream<TcpStream>, Error> {
+        enum Scheme {
+            Smpp,
+            Ssmpp,
+        }
+
+        let url = url::Url::parse(url).map_err(|err| {
+            Error::Connect(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid URL: {err}"),
+            ))
+        })?;
+
+        let scheme = match url.scheme() {
+            "smpp" => Scheme::Smpp,
+            "ssmpp" | "smpps" => Scheme::Ssmpp,
+            scheme => {
+                return Err(Error::Connect(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Unsupported URL scheme: {scheme}, supported schemes are smpp and ssmpp/smpps"
+                    ),
+                )));
+            }
+        };
+
+        let domain = url.host_str().ok_or_else(|| {
+            Error::Connect(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "URL must have a host",
+            ))
+        })?;
+
+        let port = url.port().unwrap_or(2775);
+
+        tracing::debug!(target: "rusmppc::connection::dns", domain, "Resolving domain");
+
+        let resolver = hickory_resolver::TokioResolver::builder_tokio()
+            .map_err(|err| {
+                Error::Connect(std::io::Error::other(format!(
+                    "Failed to create DNS resolver: {err}"
+                )))
+            })?
+            .build();
+
+        let ip_addr = resolver
+            .lookup_ip(domain)
+            .await
+            .map_err(|err| {
+                Error::Connect(std::io::Error::other(format!("Failed to lookup IP: {err}")))
+            })?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::Connect(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    "No addresses found for the given host",
+                ))
+            })?;
+
+        let socket_addr = SocketAddr::new(ip_addr, port);
+
+        tracing::debug!(target: "rusmppc::connection::tcp", %socket_addr, "Connecting");
+
+        let stream = TcpStream::connect(socket_addr)
+            .await
+            .map_err(Error::Connect)?;
+
+        tracing::debug!(target: "rusmppc::connection::tcp", %socket_addr, "Connected");
+
+        let stream = match scheme {
+            Scheme::Smpp => MaybeTlsStream::plain(stream),
+            Scheme::Ssmpp => {
+                #[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+                {
+                    MaybeTlsStream::rustls(stream, domain, self.rustls_config.clone()).await?
+                }
+                // If both features are enabled, prefer rustls.
+                #[cfg(all(feature = "rustls", feature = "native-tls"))]
+                {
+                    tracing::warn!(target: "rusmppc::connection::tls", "Both `rustls` and `native-tls` features are enabled, preferring `rustls` for TLS connections");
+
+                    MaybeTlsStream::rustls(stream, domain, self.rustls_config.clone()).await?
+                }
+                #[cfg(all(not(feature = "rustls"), feature = "native-tls"))]
+                {
+                    MaybeTlsStream::native_tls(stream, domain, self.native_tls_connector.clone())
+                        .await?
+                }
+                #[cfg(not(any(feature = "rustls", feature = "native-tls")))]
+                {
+                    return Err(Error::Connect(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "TLS support is not enabled, enable the `rustls` or `native-tls` feature to use ssmpp/smpps",
+                    )));
+                }
+            }
+        };
+
+        Ok(stream)
+    }
+
+    /// Wraps the stream with the [`RecoverableCommandCodec`].
+    ///
+    /// Malformed commands do not end the stream, the connection answers them with a [`GenericNack`](rusmpp::Pdu::GenericNack).
+    pub(crate) fn framed<S>(&self, stream: S) -> Framed<S, RecoverableCommandCodec>
+    where
+        S: AsyncRead + AsyncWrite,
+    {
+        Framed::new(
+            stream,
+            CommandCodec::new()
+                .with_max_length(self.max_command_length)
+                .recoverable(),
+        )
+    }
 }
 
 impl<E: EventChannel> ConnectionBuilder<E> {
@@ -237,6 +504,46 @@ impl ConnectionBuilder {
         self
     }
 
+    /// Sets the policy for responding to [`DeliverSm`](rusmpp::pdus::DeliverSm) and [`DataSm`](rusmpp::pdus::DataSm) operations from the server.
+    ///
+    /// By default, this is set to [`AutoResponse::Manual`] and the client has to respond to every operation,
+    /// e.g. using [`Client::deliver_sm_resp`](crate::Client::deliver_sm_resp).
+    ///
+    /// See [`AutoResponse`] for the available policies.
+    pub fn auto_response(mut self, auto_response: AutoResponse) -> Self {
+        self.auto_response = auto_response;
+        self
+    }
+
+    /// Enables automatic responses to unbind requests from the server.
+    ///
+    /// See [`with_auto_unbind_response`](Self::with_auto_unbind_response) for more details.
+    pub fn enable_auto_unbind_response(mut self) -> Self {
+        self.auto_unbind_response = true;
+        self
+    }
+
+    /// Disables automatic responses to unbind requests from the server.
+    ///
+    /// See [`with_auto_unbind_response`](Self::with_auto_unbind_response) for more details.
+    pub fn disable_auto_unbind_response(mut self) -> Self {
+        self.auto_unbind_response = false;
+        self
+    }
+
+    /// Sets whether to automatically respond to unbind requests from the server.
+    ///
+    /// By default, this is set to `false`.
+    ///
+    /// When enabled, the connection responds to an [`Unbind`](rusmpp::Pdu::Unbind) from the server with an [`UnbindResp`](rusmpp::Pdu::UnbindResp),
+    /// passes the [`Unbind`](rusmpp::Pdu::Unbind) command through the event stream and closes gracefully, as if [`Client::close`](crate::Client::close) was called.
+    ///
+    /// When disabled, the client will need to respond to unbind requests manually using [`Client::unbind_resp`](crate::Client::unbind_resp).
+    pub fn with_auto_unbind_response(mut self, auto: bool) -> Self {
+        self.auto_unbind_response = auto;
+        self
+    }
+
     /// Sets the response timeout.
     ///
     /// This timeout is used to determine how long the client should wait for a response from the server.
@@ -263,6 +570,172 @@ impl ConnectionBuilder {
         self
     }
 
+    /// Sets the window size.
+    ///
+    /// The window is the maximum number of requests waiting for a response from the server.
+    /// When the window is full, additional requests wait until a slot is freed by a response, a failure or a timeout.
+    ///
+    /// A request occupies a slot from the moment it is sent to the connection until its response future completes or is dropped.
+    /// Requests sent with [`Client::no_wait`](crate::Client::no_wait) and responses to operations from the server are not limited.
+    ///
+    /// # Note
+    ///
+    /// The response timeout starts after the request has been sent to the server, the time spent waiting for a slot is not included.
+    /// A window size of `0` is treated as `1`.
+    pub fn window(mut self, size: usize) -> Self {
+        self.window = Some(size);
+        self
+    }
+
+    /// Disables the window.
+    ///
+    /// When disabled, the number of requests waiting for a response from the server is not limited.
+    pub fn no_window(mut self) -> Self {
+        self.window = None;
+        self
+    }
+
+    /// Sets the window size.
+    ///
+    /// If set to `None`, the number of requests waiting for a response from the server is not limited.
+    ///
+    /// See [`window`](Self::window) for more details.
+    pub fn with_window(mut self, size: Option<usize>) -> Self {
+        self.window = size;
+        self
+    }
+
+    /// Sets the rate limit applied to all operations sent by the client.
+    ///
+    /// Operations exceeding the rate limit are queued in the connection and sent as soon as the rate limit allows it.
+    /// Responses to operations from the server, such as [`DeliverSmResp`](rusmpp::pdus::DeliverSmResp), are never limited.
+    ///
+    /// If a [`command_rate_limit`](Self::command_rate_limit) is also set for the command id, both limits apply.
+    ///
+    /// # Note
+    ///
+    /// The response timeout starts after the request has been sent to the server, the time spent waiting for the rate limit is not included.
+    /// Operations are sent in order, an operation waiting for the rate limit also delays the operations behind it.
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Disables the rate limit applied to all operations.
+    ///
+    /// Rate limits set with [`command_rate_limit`](Self::command_rate_limit) still apply.
+    pub fn no_rate_limit(mut self) -> Self {
+        self.rate_limit = None;
+        self
+    }
+
+    /// Sets the rate limit applied to all operations sent by the client.
+    ///
+    /// If set to `None`, only the rate limits set with [`command_rate_limit`](Self::command_rate_limit) apply.
+    ///
+    /// See [`rate_limit`](Self::rate_limit) for more details.
+    pub fn with_rate_limit(mut self, rate_limit: Option<RateLimit>) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// Sets the rate limit applied to operations with the given command id.
+    ///
+    /// # Example
+    ///
+    /// Send at most 10 [`SubmitSm`](rusmpp::pdus::SubmitSm) per second.
+    ///
+    /// ```rust
+    /// use rusmpp::CommandId;
+    /// use rusmppc::{ConnectionBuilder, RateLimit};
+    ///
+    /// let builder = ConnectionBuilder::new()
+    ///     .command_rate_limit(CommandId::SubmitSm, RateLimit::per_second(10));
+    /// ```
+    ///
+    /// See [`rate_limit`](Self::rate_limit) for more details.
+    pub fn command_rate_limit(mut self, id: CommandId, rate_limit: RateLimit) -> Self {
+        self.command_rate_limits.insert(id, rate_limit);
+        self
+    }
+
+    /// Removes the rate limit applied to operations with the given command id.
+    pub fn no_command_rate_limit(mut self, id: CommandId) -> Self {
+        self.command_rate_limits.remove(&id);
+        self
+    }
+
+    /// Sets the rate limit applied to operations with the given command id.
+    ///
+    /// If set to `None`, the rate limit for the command id is removed.
+    ///
+    /// See [`command_rate_limit`](Self::command_rate_limit) for more details.
+    pub fn with_command_rate_limit(self, id: CommandId, rate_limit: Option<RateLimit>) -> Self {
+        match rate_limit {
+            Some(rate_limit) => self.command_rate_limit(id, rate_limit),
+            None => self.no_command_rate_limit(id),
+        }
+    }
+
+    /// Retries requests rejected by the server with [`EsmeRthrottled`](rusmpp::CommandStatus::EsmeRthrottled) or [`EsmeRmsgqful`](rusmpp::CommandStatus::EsmeRmsgqful).
+    ///
+    /// When a throttled response is received, the connection stops sending operations for the configured delay
+    /// and the request is sent again with a new sequence number, instead of failing with an [`Error::UnexpectedResponse`].
+    /// If the request is still throttled after the configured number of attempts, the error is returned to the caller.
+    ///
+    /// The response timeout applies to each attempt.
+    pub fn throttle_retry(mut self, throttle_retry: ThrottleRetry) -> Self {
+        self.throttle_retry = Some(throttle_retry);
+        self
+    }
+
+    /// Disables the throttle retry.
+    ///
+    /// Throttled responses are returned to the caller as an [`Error::UnexpectedResponse`].
+    pub fn no_throttle_retry(mut self) -> Self {
+        self.throttle_retry = None;
+        self
+    }
+
+    /// Sets the throttle retry.
+    ///
+    /// If set to `None`, throttled responses are returned to the caller as an [`Error::UnexpectedResponse`].
+    ///
+    /// See [`throttle_retry`](Self::throttle_retry) for more details.
+    pub fn with_throttle_retry(mut self, throttle_retry: Option<ThrottleRetry>) -> Self {
+        self.throttle_retry = throttle_retry;
+        self
+    }
+
+    /// Sets the interface version used in bind commands.
+    ///
+    /// See [`with_interface_version`](Self::with_interface_version) for more details.
+    pub fn interface_version(mut self, interface_version: InterfaceVersion) -> Self {
+        self.interface_version = Some(interface_version);
+        self
+    }
+
+    /// Uses the interface version of each bind command.
+    ///
+    /// See [`with_interface_version`](Self::with_interface_version) for more details.
+    pub fn no_interface_version(mut self) -> Self {
+        self.interface_version = None;
+        self
+    }
+
+    /// Sets the interface version used in bind commands.
+    ///
+    /// By default, this is set to `None` and the `interface_version` of each bind command is sent as is.
+    ///
+    /// When set, the `interface_version` of every bind command sent by the client is replaced with the given version.
+    /// Use [`InterfaceVersion::Smpp3_4`] to bind to `SMPP v3.4` servers.
+    ///
+    /// The version used by the session is negotiated with the server, see [`Client::interface_version`].
+    pub fn with_interface_version(mut self, interface_version: Option<InterfaceVersion>) -> Self {
+        self.interface_version = interface_version;
+        self
+    }
+
     /// Enables the interface version check.
     ///
     /// See [`with_interface_version_check`](Self::with_interface_version_check) for more details.
@@ -283,7 +756,12 @@ impl ConnectionBuilder {
     ///
     /// By default, the interface version check is enabled.
     ///
-    /// This library uses `SMPP v5` implementation to encode and decode commands.
+    /// This library supports `SMPP v3.4` and `SMPP v5.0`.
+    ///
+    /// When enabled:
+    /// - Bind commands with another interface version are rejected with an [`Error::UnsupportedInterfaceVersion`].
+    /// - Commands and TLVs that are not defined in the negotiated interface version (e.g. [`BroadcastSm`](rusmpp::pdus::BroadcastSm) in an `SMPP v3.4` session)
+    ///   are rejected locally with an [`Error::UnsupportedByInterfaceVersion`].
     ///
     /// Binding to a server with another `SMPP` version may cause issues encoding and decoding commands.
     /// Disable interface version check to allow binding to servers with any `SMPP` version.
@@ -292,11 +770,74 @@ impl ConnectionBuilder {
         self
     }
 
-    /// Sets a custom `rustls` client configuration.
+    /// Enables the session state check.
     ///
-    /// If not set, a default configuration will be used.
-    ///  - If the `rustls-tls-native-roots` feature is enabled, native root certificates are used.
-    ///  - If the `rustls-tls-webpki-roots` feature is enabled, webpki root certificates are used.
+    /// See [`with_session_state_check`](Self::with_session_state_check) for more details.
+    pub fn enable_session_state_check(mut self) -> Self {
+        self.check_session_state = true;
+        self
+    }
+
+    /// Disables the session state check.
+    ///
+    /// See [`with_session_state_check`](Self::with_session_state_check) for more details.
+    pub fn disable_session_state_check(mut self) -> Self {
+        self.check_session_state = false;
+        self
+    }
+
+    /// Enables or disables the session state check.
+    ///
+    /// By default, the session state check is enabled.
+    ///
+    /// The connection tracks the [`SessionState`](rusmpp::session::SessionState) through bind, unbind and outbind operations,
+    /// following the operation matrix of the `SMPP v5` specification.
+    ///
+    /// When enabled:
+    ///  - Commands that are not allowed in the current session state are rejected with an [`Error::InvalidSessionState`] without being sent to the server.
+    ///  - Operations from the server that are not allowed in the current session state are answered with a [`GenericNack`](rusmpp::Pdu::GenericNack)
+    ///    and reported through the event stream as an [`Error::UnexpectedCommand`].
+    ///
+    /// The session state is tracked regardless of this setting. See [`Client::session_state`].
+    pub fn with_session_state_check(mut self, check: bool) -> Self {
+        self.check_session_state = check;
+        self
+    }
+
+    /// Enables the command validation.
+    ///
+    /// See [`with_command_validation`](Self::with_command_validation) for more details.
+    pub fn enable_command_validation(mut self) -> Self {
+        self.validate_commands = true;
+        self
+    }
+
+    /// Disables the command validation.
+    ///
+    /// See [`with_command_validation`](Self::with_command_validation) for more details.
+    pub fn disable_command_validation(mut self) -> Self {
+        self.validate_commands = false;
+        self
+    }
+
+    /// Enables or disables the command validation.
+    ///
+    /// By default, the command validation is disabled.
+    ///
+    /// When enabled, every command sent by the client is checked against the cross-field rules of the `SMPP` protocol using [`Validate`](rusmpp::validate::Validate),
+    /// e.g. `short_message` and `message_payload` set at the same time or TLVs that are not allowed in the PDU.
+    ///
+    /// Invalid commands are rejected with an [`Error::InvalidCommand`] without being sent to the server.
+    pub fn with_command_validation(mut self, validate: bool) -> Self {
+        self.validate_commands = validate;
+        self
+    }
+
+    /// Sets a custom `rustls` client configuration.
+    ///
+    /// If not set, a default configuration will be used.
+    ///  - If the `rustls-tls-native-roots` feature is enabled, native root certificates are used.
+    ///  - If the `rustls-tls-webpki-roots` feature is enabled, webpki root certificates are used.
     #[cfg(feature = "rustls")]
     pub fn rustls_config(mut self, config: rustls::ClientConfig) -> Self {
         self.rustls_config = Some(config);
@@ -333,9 +874,8 @@ impl<E: EventChannel> NoSpawnConnectionBuilder<E> {
     /// - If the connection to the server fails.
     /// - If TLS is enabled (when using `ssmpp` or `smpps` schemes) but the `rustls` or `native-tls` features are not enabled.
     /// - If TLS handshake fails.
-    #[allow(unused_mut)]
     pub async fn connect(
-        mut self,
+        self,
         url: impl AsRef<str>,
     ) -> Result<
         (
@@ -345,131 +885,533 @@ impl<E: EventChannel> NoSpawnConnectionBuilder<E> {
         ),
         Error,
     > {
-        enum Scheme {
-            Smpp,
-            Ssmpp,
+        let stream = self.builder.dial(url.as_ref()).await?;
+
+        Ok(self.connected(stream))
+    }
+
+    /// Creates a client from an existing connection without spawning the connection in the background.
+    pub fn connected<S>(
+        self,
+        stream: S,
+    ) -> (
+        Client,
+        impl Stream<Item = E::Event> + Unpin + 'static,
+        impl Future<Output = ()>,
+    )
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let framed = self.builder.framed(stream);
+
+        self.raw(framed, TokioDelay::new(), TokioDelay::new())
+    }
+}
+
+/// Builder for creating a new `SMPP` connection that reconnects automatically.
+///
+/// See [`ConnectionBuilder::reconnect`] for more details.
+#[derive(Debug)]
+pub struct ReconnectConnectionBuilder<E = DefaultEventChannel> {
+    builder: ConnectionBuilder<E>,
+    backoff: Backoff,
+}
+
+impl<E> ReconnectConnectionBuilder<E> {
+    /// Creates a new [`ReconnectConnectionBuilder`] with default configurations.
+    ///
+    /// # Defaults
+    /// - `initial_delay`: 1 second
+    /// - `max_delay`: 60 seconds
+    /// - `multiplier`: 2
+    /// - `jitter`: true
+    /// - `max_attempts`: unlimited
+    const fn new(builder: ConnectionBuilder<E>) -> Self {
+        Self {
+            builder,
+            backoff: Backoff::new(),
         }
+    }
 
-        let url = url::Url::parse(url.as_ref()).map_err(|err| {
-            Error::Connect(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                format!("Invalid URL: {err}"),
-            ))
-        })?;
+    /// Sets the delay before the first reconnect attempt.
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.backoff.initial_delay = initial_delay;
+        self
+    }
 
-        let scheme = match url.scheme() {
-            "smpp" => Scheme::Smpp,
-            "ssmpp" | "smpps" => Scheme::Ssmpp,
-            scheme => {
-                return Err(Error::Connect(std::io::Error::new(
-                    std::io::ErrorKind::InvalidInput,
-                    format!(
-                        "Unsupported URL scheme: {scheme}, supported schemes are smpp and ssmpp/smpps"
-                    ),
-                )));
-            }
-        };
+    /// Sets the upper bound of the delay between reconnect attempts.
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.backoff.max_delay = max_delay;
+        self
+    }
 
-        let domain = url.host_str().ok_or_else(|| {
-            Error::Connect(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "URL must have a host",
-            ))
-        })?;
+    /// Sets the factor by which the delay is multiplied after each failed attempt.
+    pub const fn multiplier(mut self, multiplier: u32) -> Self {
+        self.backoff.multiplier = multiplier;
+        self
+    }
 
-        let port = url.port().unwrap_or(2775);
+    /// Enables jitter.
+    ///
+    /// See [`with_jitter`](Self::with_jitter) for more details.
+    pub const fn enable_jitter(mut self) -> Self {
+        self.backoff.jitter = true;
+        self
+    }
 
-        tracing::debug!(target: "rusmppc::connection::dns", domain, "Resolving domain");
+    /// Disables jitter.
+    ///
+    /// See [`with_jitter`](Self::with_jitter) for more details.
+    pub const fn disable_jitter(mut self) -> Self {
+        self.backoff.jitter = false;
+        self
+    }
 
-        let resolver = hickory_resolver::TokioResolver::builder_tokio()
-            .map_err(|err| {
-                Error::Connect(std::io::Error::other(format!(
-                    "Failed to create DNS resolver: {err}"
-                )))
-            })?
-            .build();
+    /// Sets whether to randomize the delay between reconnect attempts.
+    ///
+    /// By default, this is set to `true`.
+    ///
+    /// When enabled, a random delay between half of the computed delay and the computed delay is used.
+    /// This prevents multiple clients from reconnecting to the server at the same time.
+    pub const fn with_jitter(mut self, jitter: bool) -> Self {
+        self.backoff.jitter = jitter;
+        self
+    }
 
-        let ip_addr = resolver
-            .lookup_ip(domain)
-            .await
-            .map_err(|err| {
-                Error::Connect(std::io::Error::other(format!("Failed to lookup IP: {err}")))
-            })?
+    /// Sets the maximum number of consecutive reconnect attempts.
+    ///
+    /// When exhausted, an [`Error::ReconnectAttemptsExhausted`] is sent through the event stream and the connection is closed.
+    pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.backoff.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Reconnects indefinitely.
+    pub const fn no_max_attempts(mut self) -> Self {
+        self.backoff.max_attempts = None;
+        self
+    }
+
+    /// Sets the maximum number of consecutive reconnect attempts.
+    ///
+    /// If set to `None`, the client will try to reconnect indefinitely.
+    pub const fn with_max_attempts(mut self, max_attempts: Option<u32>) -> Self {
+        self.backoff.max_attempts = max_attempts;
+        self
+    }
+}
+
+impl<E: EventChannel> ReconnectConnectionBuilder<E> {
+    /// Connects to the `SMPP` server.
+    ///
+    /// Opens and manages a connection in the background and returns a client and an event stream.
+    /// The connection is re-established using the same URL whenever it is lost.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the first connection could not be established.
+    /// See [`ConnectionBuilder::connect`] for more details.
+    pub async fn connect(
+        self,
+        url: impl AsRef<str>,
+    ) -> Result<(Client, impl Stream<Item = E::Event> + Unpin + 'static), Error> {
+        let url = url.as_ref().to_owned();
+
+        let stream = self.builder.dial(&url).await?;
+
+        let (events_tx, events_rx) = mpsc::unbounded_channel::<E::Event>();
+        let (watch_tx, watch_rx) = watch::channel(());
+        let (session_state_tx, session_state_rx) = watch::channel(SessionState::Closed);
+        let (interface_version_tx, interface_version_rx) = watch::channel(None);
+        let (actions_tx, actions_rx) = mpsc::unbounded_channel::<Action>();
+
+        let client = Client::new(
+            actions_tx,
+            self.builder.response_timeout,
+            self.builder.interface_version,
+            self.builder.check_interface_version,
+            self.builder.window,
+            self.builder.throttle_retry,
+            session_state_rx,
+            interface_version_rx,
+            watch_tx,
+        );
+
+        let reconnector = Reconnector::new(
+            url,
+            self.builder,
+            self.backoff,
+            actions_rx,
+            events_tx,
+            session_state_tx,
+            interface_version_tx,
+            watch_rx,
+            client.sequence_number(),
+        );
+
+        tokio::spawn(reconnector.run(stream));
+
+        Ok((client, UnboundedReceiverStream::new(events_rx)))
+    }
+}
+
+/// Builder for creating a pool of `SMPP` clients.
+///
+/// See [`ConnectionBuilder::pool`] for more details.
+#[derive(Debug)]
+pub struct PoolConnectionBuilder<E = DefaultEventChannel> {
+    builder: ConnectionBuilder<E>,
+    size: usize,
+    balance: Balance,
+    bind: PoolBind,
+    backoff: Backoff,
+}
+
+impl<E> PoolConnectionBuilder<E> {
+    /// Creates a new [`PoolConnectionBuilder`] with default configurations.
+    ///
+    /// # Defaults
+    /// - `size`: 4
+    /// - `balance`: [`Balance::RoundRobin`]
+    /// - `bind`: [`BindTransceiver::default`]
+    /// - `initial_delay`: 1 second
+    /// - `max_delay`: 60 seconds
+    fn new(builder: ConnectionBuilder<E>) -> Self {
+        Self {
+            builder,
+            size: 4,
+            balance: Balance::RoundRobin,
+            bind: PoolBind::Transceiver(BindTransceiver::default()),
+            backoff: Backoff::new(),
+        }
+    }
+
+    /// Sets the number of clients in the pool.
+    ///
+    /// The clients are assigned to the URLs in a round robin fashion, a pool of 4 clients connected to 2 URLs has 2 clients per URL.
+    ///
+    /// A size of `0` is treated as `1`.
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size.max(1);
+        self
+    }
+
+    /// Sets the strategy used to pick the client for the next request.
+    pub fn balance(mut self, balance: Balance) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    /// Binds every client as a transmitter.
+    pub fn bind_transmitter(mut self, bind: impl Into<BindTransmitter>) -> Self {
+        self.bind = PoolBind::Transmitter(bind.into());
+        self
+    }
+
+    /// Binds every client as a receiver.
+    pub fn bind_receiver(mut self, bind: impl Into<BindReceiver>) -> Self {
+        self.bind = PoolBind::Receiver(bind.into());
+        self
+    }
+
+    /// Binds every client as a transceiver.
+    pub fn bind_transceiver(mut self, bind: impl Into<BindTransceiver>) -> Self {
+        self.bind = PoolBind::Transceiver(bind.into());
+        self
+    }
+
+    /// Sets the delay before the first attempt to replace a closed client.
+    ///
+    /// The delay grows exponentially between attempts, see [`ReconnectConnectionBuilder::initial_delay`].
+    pub const fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.backoff.initial_delay = initial_delay;
+        self
+    }
+
+    /// Sets the upper bound of the delay between attempts to replace a closed client.
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.backoff.max_delay = max_delay;
+        self
+    }
+}
+
+impl<E: EventChannel> PoolConnectionBuilder<E> {
+    /// Connects and binds the clients of the pool.
+    ///
+    /// Returns the pool and an event stream, merging the events of every client, tagged with the slot of the client.
+    /// The event stream also reports the removal and the replacement of closed clients.
+    ///
+    /// Clients that could not be connected are replaced in the background.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no URL is given or if no client could be connected and bound.
+    /// See [`ConnectionBuilder::connect`] for the supported URL schemes.
+    pub async fn connect(
+        self,
+        urls: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<
+        (
+            ClientPool,
+            impl Stream<Item = PoolEvent<E::Event>> + Unpin + 'static,
+        ),
+        Error,
+    > {
+        let urls = urls
             .into_iter()
-            .next()
-            .ok_or_else(|| {
-                Error::Connect(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    "No addresses found for the given host",
-                ))
-            })?;
+            .map(|url| url.as_ref().to_owned())
+            .collect();
 
-        let socket_addr = SocketAddr::new(ip_addr, port);
+        let (events_tx, events_rx) = mpsc::unbounded_channel::<PoolEvent<E::Event>>();
 
-        tracing::debug!(target: "rusmppc::connection::tcp", %socket_addr, "Connecting");
+        let pool = crate::pool::pool(
+            self.builder,
+            urls,
+            self.size,
+            self.balance,
+            self.bind,
+            self.backoff,
+            events_tx,
+        )
+        .await?;
 
-        let stream = TcpStream::connect(socket_addr)
-            .await
-            .map_err(Error::Connect)?;
+        Ok((pool, UnboundedReceiverStream::new(events_rx)))
+    }
+}
 
-        tracing::debug!(target: "rusmppc::connection::tcp", %socket_addr, "Connected");
+/// Builder for accepting a `SMPP` connection initiated by the server.
+///
+/// See [`ConnectionBuilder::outbind`] for more details.
+pub struct OutbindConnectionBuilder<E = DefaultEventChannel> {
+    builder: ConnectionBuilder<E>,
+    credentials: OutbindCredentials,
+    timeout: Option<Duration>,
+    bind: OutbindBind,
+    /// TLS configurations used to accept connections. If None, connections are accepted as plain TCP.
+    #[cfg(feature = "rustls")]
+    rustls_config: Option<std::sync::Arc<rustls::ServerConfig>>,
+    #[cfg(feature = "native-tls")]
+    native_tls_acceptor: Option<native_tls::TlsAcceptor>,
+}
 
-        let stream = match scheme {
-            Scheme::Smpp => MaybeTlsStream::plain(stream),
-            Scheme::Ssmpp => {
-                #[cfg(all(feature = "rustls", not(feature = "native-tls")))]
-                {
-                    MaybeTlsStream::rustls(stream, domain, self.builder.rustls_config.take())
-                        .await?
-                }
-                // If both features are enabled, prefer rustls.
-                #[cfg(all(feature = "rustls", feature = "native-tls"))]
-                {
-                    tracing::warn!(target: "rusmppc::connection::tls", "Both `rustls` and `native-tls` features are enabled, preferring `rustls` for TLS connections");
+impl<E: std::fmt::Debug> std::fmt::Debug for OutbindConnectionBuilder<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("OutbindConnectionBuilder");
 
-                    MaybeTlsStream::rustls(stream, domain, self.builder.rustls_config.take())
-                        .await?
-                }
-                #[cfg(all(not(feature = "rustls"), feature = "native-tls"))]
-                {
-                    MaybeTlsStream::native_tls(
-                        stream,
-                        domain,
-                        self.builder.native_tls_connector.take(),
-                    )
-                    .await?
-                }
-                #[cfg(not(any(feature = "rustls", feature = "native-tls")))]
-                {
-                    return Err(Error::Connect(std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "TLS support is not enabled, enable the `rustls` or `native-tls` feature to use ssmpp/smpps",
-                    )));
-                }
-            }
-        };
+        debug
+            .field("builder", &self.builder)
+            .field("credentials", &self.credentials)
+            .field("timeout", &self.timeout)
+            .field("bind", &self.bind);
 
-        Ok(self.connected(stream))
+        #[cfg(feature = "rustls")]
+        debug.field("rustls_config", &self.rustls_config);
+
+        // `native_tls::TlsAcceptor` does not implement `Debug`.
+        #[cfg(feature = "native-tls")]
+        debug.field(
+            "native_tls_acceptor",
+            &self.native_tls_acceptor.as_ref().map(|_| "TlsAcceptor"),
+        );
+
+        debug.finish()
     }
+}
 
-    /// Creates a client from an existing connection without spawning the connection in the background.
-    pub fn connected<S>(
+/// The bind command sent after a successful outbind.
+#[derive(Debug)]
+enum OutbindBind {
+    Receiver(BindReceiver),
+    Transceiver(BindTransceiver),
+}
+
+impl<E> OutbindConnectionBuilder<E> {
+    /// Creates a new [`OutbindConnectionBuilder`] with default configurations.
+    ///
+    /// # Defaults
+    /// - `system_id`: any
+    /// - `password`: any
+    /// - `timeout`: 30 seconds
+    /// - `bind`: [`BindReceiver::default`]
+    fn new(builder: ConnectionBuilder<E>) -> Self {
+        Self {
+            builder,
+            credentials: OutbindCredentials::default(),
+            timeout: Some(Duration::from_secs(30)),
+            bind: OutbindBind::Receiver(BindReceiver::default()),
+            #[cfg(feature = "rustls")]
+            rustls_config: None,
+            #[cfg(feature = "native-tls")]
+            native_tls_acceptor: None,
+        }
+    }
+
+    /// Sets the `system_id` the server must send in the [`Outbind`](rusmpp::pdus::Outbind).
+    ///
+    /// If not set, any `system_id` is accepted.
+    pub fn system_id(mut self, system_id: COctetString<1, 16>) -> Self {
+        self.credentials.system_id = Some(system_id);
+        self
+    }
+
+    /// Sets the `password` the server must send in the [`Outbind`](rusmpp::pdus::Outbind).
+    ///
+    /// If not set, any `password` is accepted.
+    pub fn password(mut self, password: COctetString<1, 9>) -> Self {
+        self.credentials.password = Some(password);
+        self
+    }
+
+    /// Sets the timeout for waiting for the [`Outbind`](rusmpp::pdus::Outbind) after the connection is accepted.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Waits for the [`Outbind`](rusmpp::pdus::Outbind) indefinitely.
+    pub fn no_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Sets the timeout for waiting for the [`Outbind`](rusmpp::pdus::Outbind) after the connection is accepted.
+    ///
+    /// If set to `None`, the client will wait indefinitely.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Binds as a receiver after a successful outbind.
+    pub fn bind_receiver(mut self, bind: impl Into<BindReceiver>) -> Self {
+        self.bind = OutbindBind::Receiver(bind.into());
+        self
+    }
+
+    /// Binds as a transceiver after a successful outbind.
+    pub fn bind_transceiver(mut self, bind: impl Into<BindTransceiver>) -> Self {
+        self.bind = OutbindBind::Transceiver(bind.into());
+        self
+    }
+
+    /// Accepts TLS connections using the given [`rustls::ServerConfig`].
+    ///
+    /// Only applies to connections accepted with [`listen`](Self::listen) or [`accept`](Self::accept).
+    /// Takes precedence over [`native_tls_acceptor`](Self::native_tls_acceptor) if both are set.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub fn rustls_config(mut self, config: rustls::ServerConfig) -> Self {
+        self.rustls_config = Some(std::sync::Arc::new(config));
+        self
+    }
+
+    /// Accepts TLS connections using the given [`native_tls::TlsAcceptor`].
+    ///
+    /// Only applies to connections accepted with [`listen`](Self::listen) or [`accept`](Self::accept).
+    #[cfg(feature = "native-tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
+    pub fn native_tls_acceptor(mut self, acceptor: native_tls::TlsAcceptor) -> Self {
+        self.native_tls_acceptor = Some(acceptor);
+        self
+    }
+}
+
+impl<E: EventChannel> OutbindConnectionBuilder<E> {
+    /// Listens on the given address and accepts a single connection from the `SMPP` server.
+    ///
+    /// See [`accept`](Self::accept) for more details.
+    pub async fn listen(
+        self,
+        addr: impl ToSocketAddrs,
+    ) -> Result<(Client, impl Stream<Item = E::Event> + Unpin + 'static), Error> {
+        let listener = TcpListener::bind(addr).await.map_err(Error::Connect)?;
+
+        self.accept(&listener).await
+    }
+
+    /// Accepts a single connection from the `SMPP` server on the given listener.
+    ///
+    /// Waits for an [`Outbind`](rusmpp::pdus::Outbind), verifies its `system_id` and `password`
+    /// and binds as a receiver or transceiver.
+    ///
+    /// Opens and manages the connection in the background and returns a bound client and an event stream.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error in the following cases:
+    ///
+    /// - If accepting the connection fails.
+    /// - If TLS is configured and the TLS handshake fails.
+    /// - If the server does not send an outbind within the configured timeout: [`Error::OutbindTimeout`].
+    /// - If the outbind does not match the configured `system_id` or `password`: [`Error::OutbindRejected`].
+    /// - If the bind fails.
+    pub async fn accept(
+        self,
+        listener: &TcpListener,
+    ) -> Result<
+        (
+            Client,
+            impl Stream<Item = E::Event> + Unpin + 'static + use<E>,
+        ),
+        Error,
+    > {
+        let (stream, peer_addr) = listener.accept().await.map_err(Error::Connect)?;
+
+        tracing::debug!(target: "rusmppc::connection::tcp", %peer_addr, "Accepted");
+
+        let stream = self.handshake(stream).await?;
+
+        self.accepted(stream).await
+    }
+
+    /// Accepts a TLS connection over the accepted stream, if TLS is configured.
+    async fn handshake(&self, stream: TcpStream) -> Result<MaybeTlsStream<TcpStream>, Error> {
+        #[cfg(feature = "rustls")]
+        if let Some(config) = self.rustls_config.clone() {
+            return MaybeTlsStream::rustls_server(stream, config).await;
+        }
+
+        #[cfg(feature = "native-tls")]
+        if let Some(acceptor) = self.native_tls_acceptor.clone() {
+            return MaybeTlsStream::native_tls_server(stream, acceptor).await;
+        }
+
+        Ok(MaybeTlsStream::plain(stream))
+    }
+
+    /// Completes the outbind procedure over an already accepted connection.
+    ///
+    /// See [`accept`](Self::accept) for more details.
+    pub async fn accepted<S>(
         self,
         stream: S,
-    ) -> (
-        Client,
-        impl Stream<Item = E::Event> + Unpin + 'static,
-        impl Future<Output = ()>,
-    )
+    ) -> Result<(Client, impl Stream<Item = E::Event> + Unpin + 'static), Error>
     where
         S: AsyncRead + AsyncWrite + Send + 'static,
     {
-        let framed = Framed::new(
-            stream,
-            CommandCodec::new().with_max_length(self.builder.max_command_length),
-        );
+        let mut framed = Box::pin(self.builder.framed(stream));
 
-        self.raw(framed, TokioDelay::new(), TokioDelay::new())
+        crate::outbind::outbind(&mut framed, &self.credentials, self.timeout).await?;
+
+        let (client, events, connection) =
+            self.builder
+                .no_spawn()
+                .raw(framed, TokioDelay::new(), TokioDelay::new());
+
+        tokio::spawn(connection);
+
+        let bound = match self.bind {
+            OutbindBind::Receiver(bind) => client.bind_receiver(bind).await.map(drop),
+            OutbindBind::Transceiver(bind) => client.bind_transceiver(bind).await.map(drop),
+        };
+
+        if let Err(err) = bound {
+            let _ = client.close().await;
+
+            return Err(err);
+        }
+
+        Ok((client, events))
     }
 }
 
@@ -487,8 +1429,17 @@ impl<E> EventsConnectionBuilder<E> {
             enquire_link_interval: self.builder.enquire_link_interval,
             enquire_link_response_timeout: self.builder.enquire_link_response_timeout,
             auto_enquire_link_response: self.builder.auto_enquire_link_response,
+            auto_response: self.builder.auto_response,
+            auto_unbind_response: self.builder.auto_unbind_response,
             response_timeout: self.builder.response_timeout,
+            window: self.builder.window,
+            rate_limit: self.builder.rate_limit,
+            command_rate_limits: self.builder.command_rate_limits,
+            throttle_retry: self.builder.throttle_retry,
+            interface_version: self.builder.interface_version,
             check_interface_version: self.builder.check_interface_version,
+            check_session_state: self.builder.check_session_state,
+            validate_commands: self.builder.validate_commands,
             #[cfg(feature = "rustls")]
             rustls_config: self.builder.rustls_config,
             #[cfg(feature = "native-tls")]
@@ -504,8 +1455,17 @@ impl<E> EventsConnectionBuilder<E> {
             enquire_link_interval: self.builder.enquire_link_interval,
             enquire_link_response_timeout: self.builder.enquire_link_response_timeout,
             auto_enquire_link_response: self.builder.auto_enquire_link_response,
+            auto_response: self.builder.auto_response,
+            auto_unbind_response: self.builder.auto_unbind_response,
             response_timeout: self.builder.response_timeout,
+            window: self.builder.window,
+            rate_limit: self.builder.rate_limit,
+            command_rate_limits: self.builder.command_rate_limits,
+            throttle_retry: self.builder.throttle_retry,
+            interface_version: self.builder.interface_version,
             check_interface_version: self.builder.check_interface_version,
+            check_session_state: self.builder.check_session_state,
+            validate_commands: self.builder.validate_commands,
             #[cfg(feature = "rustls")]
             rustls_config: self.builder.rustls_config,
             #[cfg(feature = "native-tls")]
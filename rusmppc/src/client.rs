@@ -15,13 +15,15 @@ use rusmpp::{
         DataSmResp, DeliverSmResp, QueryBroadcastSm, QueryBroadcastSmResp, QuerySm, QuerySmResp,
         ReplaceSm, SubmitMulti, SubmitMultiResp, SubmitSm, SubmitSmResp,
     },
+    session::SessionState,
     values::InterfaceVersion,
 };
 use tokio::sync::{mpsc::UnboundedSender, watch};
 
 use crate::{
-    Action, CloseRequest, CommandExt, ConnectionBuilder, PendingResponses, RegisteredRequest,
-    RequestFutureGuard, UnregisteredRequest, error::Error,
+    AbandonRequest, Action, CloseRequest, CommandExt, ConnectionBuilder, DrainRequest,
+    PendingResponses, RegisteredRequest, RequestFutureGuard, UnregisteredRequest, error::Error,
+    rate::ThrottleRetry, window::Window,
 };
 
 const TARGET: &str = "rusmppc::client";
@@ -43,17 +45,28 @@ impl Clone for Client {
 }
 
 impl Client {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         actions: UnboundedSender<Action>,
         response_timeout: Option<Duration>,
+        bind_interface_version: Option<InterfaceVersion>,
         check_interface_version: bool,
+        window: Option<usize>,
+        throttle_retry: Option<ThrottleRetry>,
+        session_state: watch::Receiver<SessionState>,
+        interface_version: watch::Receiver<Option<InterfaceVersion>>,
         watch: watch::Sender<()>,
     ) -> Self {
         Self {
             inner: Arc::new(ClientInner::new(
                 actions,
                 response_timeout,
+                bind_interface_version,
                 check_interface_version,
+                Window::new(window),
+                throttle_retry,
+                session_state,
+                interface_version,
                 watch,
             )),
         }
@@ -239,6 +252,62 @@ impl Client {
         Ok(())
     }
 
+    /// Shuts the connection down gracefully.
+    ///
+    /// The shutdown is performed in the following order:
+    ///  - The connection stops accepting new operations from the client.
+    ///    Responses and [`Unbind`](Pdu::Unbind) are still accepted, other requests fail with [`Error::ShuttingDown`].
+    ///  - Waits for at most `timeout` until every in-flight request was sent and its response arrived or timed out.
+    ///  - Sends an [`Unbind`](Pdu::Unbind) and waits for the [`UnbindResp`](Pdu::UnbindResp), if the client is bound.
+    ///  - Closes the connection and waits for it to terminate.
+    ///
+    /// Returns the sequence numbers of the requests that were still waiting to be sent or waiting for a response after the `timeout`.
+    /// These requests are abandoned: requests waiting to be sent, e.g. held back by the rate limiter, fail with [`Error::ShuttingDown`] and are never sent,
+    /// requests waiting for a response fail with [`Error::ConnectionClosed`] once the connection is closed.
+    pub async fn shutdown(&self, timeout: Duration) -> Result<Vec<u32>, Error> {
+        let (request, drained) = DrainRequest::new();
+
+        self.inner
+            .actions
+            .send(Action::Drain(request))
+            .map_err(|_| Error::ConnectionClosed)?;
+
+        let abandoned = match tokio::time::timeout(timeout, drained).await {
+            Ok(drained) => {
+                drained.map_err(|_| Error::ConnectionClosed)?;
+
+                Vec::new()
+            }
+            Err(_) => {
+                let (request, abandoned) = AbandonRequest::new();
+
+                self.inner
+                    .actions
+                    .send(Action::Abandon(request))
+                    .map_err(|_| Error::ConnectionClosed)?;
+
+                let abandoned = abandoned.await.map_err(|_| Error::ConnectionClosed)?;
+
+                tracing::warn!(target: TARGET, ?timeout, ?abandoned, "Drain timed out");
+
+                abandoned
+            }
+        };
+
+        if self.session_state().is_bound()
+            && let Err(err) = self.unbind().await
+        {
+            tracing::warn!(target: TARGET, ?err, "Unbind failed");
+        }
+
+        // The server may close the connection right after the unbind response.
+        let _ = self.close().await;
+
+        self.closed().await;
+
+        Ok(abandoned)
+    }
+
     /// Checks if the connection is active.
     ///
     /// The connection is considered active if:
@@ -258,6 +327,43 @@ impl Client {
         self.inner.actions.send(Action::Ping).is_ok()
     }
 
+    /// Returns the current session state of the connection.
+    ///
+    /// The state is tracked by the connection through bind, unbind and outbind operations.
+    /// It is [`SessionState::Open`] right after connecting and [`SessionState::Closed`] after the connection is closed.
+    pub fn session_state(&self) -> SessionState {
+        *self.inner.session_state.borrow()
+    }
+
+    /// Returns the interface version negotiated with the server.
+    ///
+    /// The version is negotiated on every successful bind: the lower of the `interface_version` sent in the bind command
+    /// and the `sc_interface_version` returned in the bind response is used.
+    /// If the server does not return an `sc_interface_version`, the version sent in the bind command is assumed.
+    ///
+    /// It is `None` until the client is bound.
+    pub fn interface_version(&self) -> Option<InterfaceVersion> {
+        *self.inner.interface_version.borrow()
+    }
+
+    /// Returns the configured window size.
+    ///
+    /// The window is the maximum number of requests waiting for a response from the server.
+    /// `None` means that the number of requests is not limited.
+    ///
+    /// See [`ConnectionBuilder::window`] for more details.
+    pub fn window_size(&self) -> Option<usize> {
+        self.inner.window.size()
+    }
+
+    /// Returns the number of requests occupying the window.
+    ///
+    /// These are the requests that were sent, or are about to be sent, and are waiting for a response from the server.
+    /// Requests sent with [`Client::no_wait`] are not counted.
+    pub fn in_flight(&self) -> usize {
+        self.inner.window.in_flight()
+    }
+
     /// Returns a vector of pending responses.
     pub async fn pending_responses(&self) -> Result<Vec<u32>, Error> {
         let (pending_responses, ack) = PendingResponses::new();
@@ -301,29 +407,49 @@ impl Client {
     const fn no_wait_request(&'_ self) -> NoWaitRequestBuilder<'_> {
         NoWaitRequestBuilder::new(self, CommandStatus::EsmeRok)
     }
+
+    pub(crate) fn sequence_number(&self) -> SequenceNumber {
+        self.inner.sequence_number.clone()
+    }
 }
 
 #[derive(Debug)]
 struct ClientInner {
     actions: UnboundedSender<Action>,
     response_timeout: Option<Duration>,
-    sequence_number: AtomicU32,
+    sequence_number: SequenceNumber,
+    bind_interface_version: Option<InterfaceVersion>,
     check_interface_version: bool,
+    window: Window,
+    throttle_retry: Option<ThrottleRetry>,
+    session_state: watch::Receiver<SessionState>,
+    interface_version: watch::Receiver<Option<InterfaceVersion>>,
     watch: watch::Sender<()>,
 }
 
 impl ClientInner {
-    const fn new(
+    #[allow(clippy::too_many_arguments)]
+    fn new(
         actions: UnboundedSender<Action>,
         response_timeout: Option<Duration>,
+        bind_interface_version: Option<InterfaceVersion>,
         check_interface_version: bool,
+        window: Window,
+        throttle_retry: Option<ThrottleRetry>,
+        session_state: watch::Receiver<SessionState>,
+        interface_version: watch::Receiver<Option<InterfaceVersion>>,
         watch: watch::Sender<()>,
     ) -> Self {
         Self {
             actions,
             response_timeout,
-            sequence_number: AtomicU32::new(1),
+            sequence_number: SequenceNumber::new(),
+            bind_interface_version,
             check_interface_version,
+            window,
+            throttle_retry,
+            session_state,
+            interface_version,
             watch,
         }
     }
@@ -331,7 +457,7 @@ impl ClientInner {
 
 impl ClientInner {
     fn next_sequence_number(&self) -> u32 {
-        self.sequence_number.fetch_add(2, Ordering::Relaxed)
+        self.sequence_number.next()
     }
 
     async fn close(&self) -> Result<(), Error> {
@@ -345,6 +471,27 @@ impl ClientInner {
     }
 }
 
+/// Sequence number counter of the client's requests.
+///
+/// Shared with the background tasks that send requests on behalf of the client, e.g. a rebind after a reconnect.
+#[derive(Debug, Clone)]
+pub(crate) struct SequenceNumber {
+    next: Arc<AtomicU32>,
+}
+
+impl SequenceNumber {
+    fn new() -> Self {
+        Self {
+            next: Arc::new(AtomicU32::new(1)),
+        }
+    }
+
+    /// Returns the next sequence number.
+    pub(crate) fn next(&self) -> u32 {
+        self.next.fetch_add(2, Ordering::Relaxed)
+    }
+}
+
 #[derive(Debug)]
 pub struct UnregisteredRequestBuilder<'a> {
     client: &'a Client,
@@ -586,24 +733,60 @@ impl<'a> RegisteredRequestBuilder<'a> {
         self
     }
 
-    fn check_interface_version(&self, interface_version: InterfaceVersion) -> Result<(), Error> {
+    /// Applies the configured interface version and checks that it is supported.
+    fn interface_version(&self, interface_version: &mut InterfaceVersion) -> Result<(), Error> {
+        if let Some(version) = self.client.inner.bind_interface_version {
+            *interface_version = version;
+        }
+
         if self.client.inner.check_interface_version
-            && !matches!(interface_version, InterfaceVersion::Smpp5_0)
+            && !matches!(
+                interface_version,
+                InterfaceVersion::Smpp3_4 | InterfaceVersion::Smpp5_0
+            )
         {
-            return Err(Error::unsupported_interface_version(interface_version));
+            return Err(Error::unsupported_interface_version(*interface_version));
         }
 
         Ok(())
     }
 
-    fn request(&self, pdu: impl Into<Pdu>) -> impl Future<Output = Result<Command, Error>> {
+    /// Sends a [`Pdu`] to the server and waits for the response.
+    ///
+    /// Requests rejected by the server because of throttling are sent again, if configured.
+    async fn request(&self, pdu: impl Into<Pdu>) -> Result<Command, Error> {
+        let pdu = pdu.into();
+
+        let Some(retry) = self.client.inner.throttle_retry else {
+            return self.send_request(pdu).await;
+        };
+
+        let mut attempts = 0;
+
+        loop {
+            let response = self.send_request(pdu.clone()).await?;
+
+            if !ThrottleRetry::is_throttled(response.status()) || attempts >= retry.max_attempts {
+                return Ok(response);
+            }
+
+            attempts += 1;
+
+            tracing::debug!(target: TARGET, sequence_number = response.sequence_number(), status = ?response.status(), attempts, "Retrying throttled request");
+        }
+    }
+
+    fn send_request(&self, pdu: Pdu) -> impl Future<Output = Result<Command, Error>> {
         let sequence_number = self.client.inner.next_sequence_number();
 
         let future = async move {
+            // Held until the response is received, the request failed or the future is dropped.
+            let _permit = self.client.inner.window.acquire().await?;
+
             let command = Command::builder()
                 .status(self.status)
                 .sequence_number(sequence_number)
-                .pdu(pdu.into());
+                .pdu(pdu);
 
             let sequence_number = command.sequence_number();
             let status = command.status();
@@ -625,8 +808,8 @@ impl<'a> RegisteredRequestBuilder<'a> {
 
             tracing::trace!(target: TARGET, sequence_number, ?status, ?id, response_timeout = ?self.client.inner.response_timeout, "Starting response timer");
 
-            match self.client.inner.response_timeout {
-                None => response.await.map_err(|_| Error::ConnectionClosed),
+            let response = match self.client.inner.response_timeout {
+                None => response.await.map_err(|_| Error::ConnectionClosed)??,
                 Some(timeout) => tokio::time::timeout(timeout, response)
                     .await
                     .inspect_err(|_| {
@@ -637,8 +820,16 @@ impl<'a> RegisteredRequestBuilder<'a> {
                             .ok();
                     })
                     .map_err(|_| Error::response_timeout(sequence_number, timeout))?
-                    .map_err(|_| Error::ConnectionClosed),
+                    .map_err(|_| Error::ConnectionClosed)??,
+            };
+
+            if let CommandId::GenericNack = response.id() {
+                tracing::warn!(target: TARGET, sequence_number, status=?response.status(), ?id, "Rejected with generic nack");
+
+                return Err(Error::generic_nack(sequence_number, response.status()));
             }
+
+            Ok(response)
         };
 
         RequestFutureGuard::new(&self.client.inner.actions, sequence_number, future)
@@ -685,9 +876,9 @@ impl<'a> RegisteredRequestBuilder<'a> {
         &self,
         bind: impl Into<BindTransmitter>,
     ) -> Result<BindTransmitterResp, Error> {
-        let bind: BindTransmitter = bind.into();
+        let mut bind: BindTransmitter = bind.into();
 
-        self.check_interface_version(bind.interface_version)?;
+        self.interface_version(&mut bind.interface_version)?;
 
         self.request_extract(bind, extract!(BindTransmitterResp))
             .await
@@ -698,9 +889,9 @@ impl<'a> RegisteredRequestBuilder<'a> {
         &self,
         bind: impl Into<BindReceiver>,
     ) -> Result<BindReceiverResp, Error> {
-        let bind: BindReceiver = bind.into();
+        let mut bind: BindReceiver = bind.into();
 
-        self.check_interface_version(bind.interface_version)?;
+        self.interface_version(&mut bind.interface_version)?;
 
         self.request_extract(bind, extract!(BindReceiverResp)).await
     }
@@ -710,9 +901,9 @@ impl<'a> RegisteredRequestBuilder<'a> {
         &self,
         bind: impl Into<BindTransceiver>,
     ) -> Result<BindTransceiverResp, Error> {
-        let bind: BindTransceiver = bind.into();
+        let mut bind: BindTransceiver = bind.into();
 
-        self.check_interface_version(bind.interface_version)?;
+        self.interface_version(&mut bind.interface_version)?;
 
         self.request_extract(bind, extract!(BindTransceiverResp))
             .await
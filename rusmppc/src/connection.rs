@@ -2,22 +2,29 @@ use std::{
     collections::{BTreeMap, VecDeque},
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use crate::{
-    Action, Client, Request, Timer,
+    Action, AutoResponse, Client, ConnectionBuilder, Request, Response, Timer,
+    auto_response::{self, PendingOperations},
     builder::NoSpawnConnectionBuilder,
     delay::Delay,
     error::Error,
     event::{EventChannel, Insight},
+    rate::{RateLimiter, ThrottleRetry},
     request::ObligatedRequest,
 };
 use futures::{FutureExt, Sink, SinkExt, Stream};
 use pin_project_lite::pin_project;
 use rusmpp::{
     Command, CommandId, CommandStatus, Pdu,
-    tokio_codec::{DecodeError, EncodeError},
+    pdus::{BindReceiver, BindTransceiver, BindTransmitter, DataSmResp, DeliverSmResp},
+    session::SessionState,
+    tlvs::Tlv,
+    tokio_codec::{DecodeError, EncodeError, MalformedCommand},
+    validate::Validate,
+    values::InterfaceVersion,
 };
 use tokio::sync::{
     mpsc::{self, UnboundedSender},
@@ -50,13 +57,33 @@ pin_project! {
         state: State,
         sequence_number: u32,
         requests: VecDeque<Request>,
+        // Requests waiting for the rate limiter before being moved to `requests`.
+        limited: VecDeque<Request>,
+        limiter: RateLimiter,
         // This is a request that has been written to the sink using start_send, but not yet flushed.
         pending_request: Option<Request>,
-        responses: BTreeMap<u32, oneshot::Sender<Command>>,
+        responses: BTreeMap<u32, oneshot::Sender<Response>>,
         enquire_link_interval: Option<Duration>,
         last_enquire_link_sequence_number: Option<u32>,
         enquire_link_response_timeout: Duration,
         auto_enquire_link_response: bool,
+        check_interface_version: bool,
+        check_session_state: bool,
+        validate_commands: bool,
+        auto_response: AutoResponse,
+        auto_unbind_response: bool,
+        // Operations from the server waiting for a response from the client, see `AutoResponse::AfterHandler`.
+        pending_operations: PendingOperations,
+        // Set by a drain request, new operations from the client are rejected.
+        draining: bool,
+        // Acknowledged when there are no more in-flight requests.
+        drained: Option<oneshot::Sender<()>>,
+        // Shared with the client to expose the current session state
+        session_state: watch::Sender<SessionState>,
+        // Shared with the client to expose the negotiated interface version
+        interface_version: watch::Sender<Option<InterfaceVersion>>,
+        // The interface version of the last bind sent to the server
+        requested_interface_version: InterfaceVersion,
         events: E,
         // Used to let the client wait for the connection to be closed
         _watch: watch::Receiver<()>,
@@ -64,6 +91,12 @@ pin_project! {
         enquire_link_timer: Timer<D1>,
         #[pin]
         enquire_link_response_timer: Timer<D2>,
+        // Wakes the connection when the rate limiter has a token available.
+        #[pin]
+        rate_limit_timer: Timer<D1>,
+        // Wakes the connection when the next pending operation expires.
+        #[pin]
+        auto_response_timer: Timer<D1>,
         #[pin]
         framed: F,
         #[pin]
@@ -72,47 +105,65 @@ pin_project! {
 }
 
 impl<D1: Delay, D2: Delay, E: EventChannel> Connection<(), D1, D2, E> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         enquire_link_interval: Option<Duration>,
         enquire_link_response_timeout: Duration,
         auto_enquire_link_response: bool,
+        check_interface_version: bool,
+        check_session_state: bool,
+        validate_commands: bool,
+        auto_response: AutoResponse,
+        auto_unbind_response: bool,
+        limiter: RateLimiter,
         enquire_link_timer_delay: D1,
         enquire_link_response_timer_delay: D2,
-    ) -> (
-        Self,
-        watch::Sender<()>,
-        UnboundedSender<Action>,
-        UnboundedReceiverStream<E::Event>,
-    ) {
-        let (events_tx, events_rx) = mpsc::unbounded_channel::<E::Event>();
-        let events = E::new(events_tx);
-
+        events: E,
+        session_state: watch::Sender<SessionState>,
+        interface_version: watch::Sender<Option<InterfaceVersion>>,
+        watch: watch::Receiver<()>,
+    ) -> (Self, UnboundedSender<Action>) {
         let (actions_tx, actions_rx) = mpsc::unbounded_channel::<Action>();
-        let (watch_tx, watch_rx) = watch::channel(());
+
+        session_state.send_replace(SessionState::Open);
+        interface_version.send_replace(None);
 
         (
             Self {
                 state: State::Active,
                 sequence_number: 2,
                 requests: VecDeque::new(),
+                limited: VecDeque::new(),
+                limiter,
                 pending_request: None,
                 responses: BTreeMap::new(),
                 enquire_link_interval,
                 last_enquire_link_sequence_number: None,
                 enquire_link_response_timeout,
                 auto_enquire_link_response,
+                check_interface_version,
+                check_session_state,
+                validate_commands,
+                auto_response,
+                auto_unbind_response,
+                pending_operations: PendingOperations::default(),
+                draining: false,
+                drained: None,
+                session_state,
+                interface_version,
+                requested_interface_version: InterfaceVersion::Smpp5_0,
                 enquire_link_timer: enquire_link_interval
                     .map(|duration| Timer::active(enquire_link_timer_delay, duration))
                     .unwrap_or_default(),
                 enquire_link_response_timer: Timer::inactive(enquire_link_response_timer_delay),
-                _watch: watch_rx,
+                rate_limit_timer: Timer::default(),
+                auto_response_timer: Timer::default(),
+                _watch: watch,
                 events,
                 framed: (),
                 actions: UnboundedReceiverStream::new(actions_rx),
             },
-            watch_tx,
             actions_tx,
-            UnboundedReceiverStream::new(events_rx),
         )
     }
 
@@ -121,16 +172,31 @@ impl<D1: Delay, D2: Delay, E: EventChannel> Connection<(), D1, D2, E> {
             state: self.state,
             sequence_number: self.sequence_number,
             requests: self.requests,
+            limited: self.limited,
+            limiter: self.limiter,
             pending_request: self.pending_request,
             responses: self.responses,
             enquire_link_interval: self.enquire_link_interval,
             last_enquire_link_sequence_number: self.last_enquire_link_sequence_number,
             enquire_link_response_timeout: self.enquire_link_response_timeout,
             auto_enquire_link_response: self.auto_enquire_link_response,
+            check_interface_version: self.check_interface_version,
+            check_session_state: self.check_session_state,
+            validate_commands: self.validate_commands,
+            auto_response: self.auto_response,
+            auto_unbind_response: self.auto_unbind_response,
+            pending_operations: self.pending_operations,
+            draining: self.draining,
+            drained: self.drained,
+            session_state: self.session_state,
+            interface_version: self.interface_version,
+            requested_interface_version: self.requested_interface_version,
             events: self.events,
             _watch: self._watch,
             enquire_link_timer: self.enquire_link_timer,
             enquire_link_response_timer: self.enquire_link_response_timer,
+            rate_limit_timer: self.rate_limit_timer,
+            auto_response_timer: self.auto_response_timer,
             framed,
             actions: self.actions,
         }
@@ -139,13 +205,14 @@ impl<D1: Delay, D2: Delay, E: EventChannel> Connection<(), D1, D2, E> {
 
 impl<F, D1: Delay, D2: Delay, E> Connection<F, D1, D2, E>
 where
-    F: Stream<Item = Result<Command, DecodeError>> + for<'a> Sink<&'a Command, Error = EncodeError>,
+    F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
+        + for<'a> Sink<&'a Command, Error = EncodeError>,
     E: EventChannel,
 {
     fn insert_response(
         self: Pin<&mut Self>,
         sequence_number: u32,
-        response: oneshot::Sender<Command>,
+        response: oneshot::Sender<Response>,
     ) {
         self.project().responses.insert(sequence_number, response);
     }
@@ -153,7 +220,7 @@ where
     fn remove_response(
         self: Pin<&mut Self>,
         sequence_number: u32,
-    ) -> Option<oneshot::Sender<Command>> {
+    ) -> Option<oneshot::Sender<Response>> {
         self.project().responses.remove(&sequence_number)
     }
 
@@ -217,6 +284,227 @@ where
         tracing::trace!(target: TIMER, ?delay, "Activated enquire_link_response_timer");
     }
 
+    /// Moves the limited requests to the requests queue, as long as the rate limiter allows it.
+    ///
+    /// If a request has to wait, the rate limit timer is activated and polled to register the waker.
+    fn release_limited_requests(mut self: Pin<&mut Self>, cx: &mut Context<'_>) {
+        loop {
+            let Some(id) = self.limited.front().map(|request| request.command().id()) else {
+                return;
+            };
+
+            match self
+                .as_mut()
+                .project()
+                .limiter
+                .try_acquire(id, Instant::now())
+            {
+                Ok(()) => {
+                    let this = self.as_mut().project();
+
+                    if let Some(request) = this.limited.pop_front() {
+                        this.requests.push_back(request);
+                    }
+                }
+                Err(delay) => {
+                    tracing::trace!(target: CONN, ?id, ?delay, "Rate limited");
+
+                    self.as_mut().project().rate_limit_timer.activate(delay);
+
+                    match self.as_mut().project().rate_limit_timer.poll(cx) {
+                        Poll::Ready(()) => continue,
+                        Poll::Pending => return,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Responds to the operations the client did not respond to in time, see [`AutoResponse::AfterHandler`].
+    ///
+    /// If an operation is still waiting, the auto response timer is activated for its deadline and polled to register the waker.
+    fn respond_expired_operations(mut self: Pin<&mut Self>, cx: &mut Context<'_>) {
+        let AutoResponse::AfterHandler { status, .. } = self.auto_response else {
+            return;
+        };
+
+        loop {
+            let now = Instant::now();
+
+            while let Some(response) = self
+                .as_mut()
+                .project()
+                .pending_operations
+                .pop_expired(now, status)
+            {
+                tracing::warn!(target: CONN, sequence_number=response.sequence_number(), ?status, id=?response.id(), "Operation not responded in time");
+
+                self.as_mut()
+                    .requests_push_back(Request::Obligated(ObligatedRequest::new(response)));
+            }
+
+            let Some(deadline) = self.pending_operations.next_deadline() else {
+                self.as_mut().project().auto_response_timer.deactivate();
+
+                return;
+            };
+
+            self.as_mut()
+                .project()
+                .auto_response_timer
+                .activate(deadline.saturating_duration_since(now));
+
+            match self.as_mut().project().auto_response_timer.poll(cx) {
+                Poll::Ready(()) => continue,
+                Poll::Pending => return,
+            }
+        }
+    }
+
+    /// Acknowledges the drain request, if there are no more in-flight requests.
+    ///
+    /// In-flight requests are the requests waiting to be sent and the requests waiting for a response from the server.
+    fn notify_drained(self: Pin<&mut Self>) {
+        if self.drained.is_none()
+            || !self.requests.is_empty()
+            || !self.limited.is_empty()
+            || self.pending_request.is_some()
+            || !self.responses.is_empty()
+        {
+            return;
+        }
+
+        if let Some(drained) = self.project().drained.take() {
+            tracing::debug!(target: CONN, "Drained");
+
+            let _ = drained.send(());
+        }
+    }
+
+    /// Fails the client's requests waiting to be sent and returns their sequence numbers along with the pending responses.
+    ///
+    /// Responses and requests issued by the connection itself are still sent.
+    fn abandon(self: Pin<&mut Self>) -> Vec<u32> {
+        let this = self.project();
+
+        let mut abandoned: Vec<u32> = this.responses.keys().copied().collect();
+
+        for queue in [this.requests, this.limited] {
+            let (kept, unsent): (VecDeque<_>, VecDeque<_>) = queue.drain(..).partition(|request| {
+                matches!(request, Request::Obligated(_)) || request.command().id().is_response()
+            });
+
+            *queue = kept;
+
+            for request in unsent {
+                let id = request.command().id();
+
+                abandoned.push(request.command().sequence_number());
+
+                let _ = request.send_ack(Err(Error::shutting_down(id)));
+            }
+        }
+
+        abandoned
+    }
+
+    /// Closes the actions channel and returns the client's actions the connection did not process.
+    ///
+    /// Operations waiting to be sent, e.g. held back by the rate limiter, are returned as requests.
+    /// Responses and requests issued by the connection itself only belong to this connection and are dropped.
+    fn take_unprocessed(self: Pin<&mut Self>) -> Vec<Action> {
+        let this = self.project();
+
+        let unsent = this
+            .requests
+            .drain(..)
+            .chain(this.limited.drain(..))
+            .filter(|request| {
+                !matches!(request, Request::Obligated(_)) && !request.command().id().is_response()
+            })
+            .map(Action::Request);
+
+        let mut actions: Vec<Action> = unsent.collect();
+
+        let receiver = this.actions.get_mut().as_mut();
+
+        receiver.close();
+
+        while let Ok(action) = receiver.try_recv() {
+            actions.push(action);
+        }
+
+        actions
+    }
+
+    fn session_state(&self) -> SessionState {
+        *self.session_state.borrow()
+    }
+
+    fn set_session_state(self: Pin<&mut Self>, state: SessionState) {
+        let previous = self.session_state.send_replace(state);
+
+        if previous != state {
+            tracing::debug!(target: CONN, ?previous, ?state, "Session state changed");
+        }
+    }
+
+    fn interface_version(&self) -> Option<InterfaceVersion> {
+        *self.interface_version.borrow()
+    }
+
+    /// Updates the session state after a command was sent to the server.
+    fn on_sent(self: Pin<&mut Self>, command: &Command) {
+        match command.pdu() {
+            Some(
+                Pdu::BindTransmitter(BindTransmitter {
+                    interface_version, ..
+                })
+                | Pdu::BindReceiver(BindReceiver {
+                    interface_version, ..
+                })
+                | Pdu::BindTransceiver(BindTransceiver {
+                    interface_version, ..
+                }),
+            ) => {
+                *self.project().requested_interface_version = *interface_version;
+            }
+            Some(Pdu::UnbindResp) if command.status() == CommandStatus::EsmeRok => {
+                self.set_session_state(SessionState::Unbound);
+            }
+            _ => {}
+        }
+    }
+
+    /// Updates the session state after a command was received from the server.
+    fn on_received(mut self: Pin<&mut Self>, command: &Command) {
+        let state = match (command.id(), command.status()) {
+            (CommandId::BindTransmitterResp, CommandStatus::EsmeRok) => SessionState::BoundTx,
+            (CommandId::BindReceiverResp, CommandStatus::EsmeRok) => SessionState::BoundRx,
+            (CommandId::BindTransceiverResp, CommandStatus::EsmeRok) => SessionState::BoundTrx,
+            (CommandId::UnbindResp, CommandStatus::EsmeRok) => SessionState::Unbound,
+            (CommandId::Outbind, _) => SessionState::Outbound,
+            _ => return,
+        };
+
+        if state.is_bound() {
+            let sc_interface_version = match command.pdu() {
+                Some(Pdu::BindTransmitterResp(response)) => response.sc_interface_version(),
+                Some(Pdu::BindReceiverResp(response)) => response.sc_interface_version(),
+                Some(Pdu::BindTransceiverResp(response)) => response.sc_interface_version(),
+                _ => None,
+            };
+
+            let version = negotiate(self.requested_interface_version, sc_interface_version);
+
+            tracing::debug!(target: CONN, ?version, ?sc_interface_version, "Negotiated interface version");
+
+            self.interface_version.send_replace(Some(version));
+        }
+
+        self.as_mut().set_session_state(state);
+    }
+
     /// [`Self::sequence_number`] is incremented by 2 after each call.
     ///
     /// The clients also hold an atomic sequence number, which is incremented by 2 for each request, starting from 1.
@@ -234,7 +522,8 @@ where
 
 impl<F, D1: Delay, D2: Delay, E> Future for Connection<F, D1, D2, E>
 where
-    F: Stream<Item = Result<Command, DecodeError>> + for<'a> Sink<&'a Command, Error = EncodeError>,
+    F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
+        + for<'a> Sink<&'a Command, Error = EncodeError>,
     E: EventChannel,
 {
     type Output = ();
@@ -294,6 +583,13 @@ where
                     }
                     Poll::Pending => {}
                 }
+
+                match self.as_mut().project().auto_response_timer.poll(cx) {
+                    Poll::Ready(()) => {
+                        self.as_mut().respond_expired_operations(cx);
+                    }
+                    Poll::Pending => {}
+                }
             }
 
             if matches!(self.state, State::Active | State::Closing) {
@@ -332,6 +628,62 @@ where
                                     "Received request"
                                 );
 
+                                let id = request.command().id();
+                                let state = self.session_state();
+
+                                if self.draining && !id.is_response() && id != CommandId::Unbind {
+                                    tracing::warn!(target: CONN, ?id, "Shutting down");
+
+                                    let _ = request.send_ack(Err(Error::shutting_down(id)));
+
+                                    continue 'actions;
+                                }
+
+                                if self.check_session_state && !state.can_send_as_esme(id) {
+                                    tracing::warn!(target: CONN, ?id, ?state, "Invalid session state");
+
+                                    let _ = request
+                                        .send_ack(Err(Error::invalid_session_state(id, state)));
+
+                                    continue 'actions;
+                                }
+
+                                if self.check_interface_version
+                                    && let Some(version) = self.interface_version()
+                                    && let Err(err) =
+                                        check_interface_version(request.command(), version)
+                                {
+                                    tracing::warn!(target: CONN, ?id, ?version, "Not supported by interface version");
+
+                                    let _ = request.send_ack(Err(err));
+
+                                    continue 'actions;
+                                }
+
+                                if self.validate_commands {
+                                    let violations = request.command().validate();
+
+                                    if !violations.is_empty() {
+                                        tracing::warn!(target: CONN, ?id, ?violations, "Invalid command");
+
+                                        let _ = request
+                                            .send_ack(Err(Error::invalid_command(id, violations)));
+
+                                        continue 'actions;
+                                    }
+                                }
+
+                                self.as_mut()
+                                    .project()
+                                    .pending_operations
+                                    .respond(request.command());
+
+                                if self.limiter.limits(id) {
+                                    self.as_mut().project().limited.push_back(request);
+
+                                    continue 'actions;
+                                }
+
                                 self.as_mut().requests_push_back(request);
                             }
                             Action::Remove(sequence_number) => {
@@ -339,6 +691,21 @@ where
 
                                 self.as_mut().remove_response(sequence_number);
                             }
+                            Action::Drain(request) => {
+                                tracing::debug!(target: CONN, "Received drain");
+
+                                let this = self.as_mut().project();
+
+                                *this.draining = true;
+                                *this.drained = Some(request.ack);
+                            }
+                            Action::Abandon(request) => {
+                                tracing::debug!(target: CONN, "Received abandon");
+
+                                let abandoned = self.as_mut().abandon();
+
+                                let _ = request.ack.send(abandoned);
+                            }
                             Action::Close(request) => {
                                 tracing::debug!(target: CONN, "Received close");
 
@@ -372,6 +739,8 @@ where
                     }
                 }
 
+                self.as_mut().release_limited_requests(cx);
+
                 let mut i: u8 = 0;
 
                 'sink: loop {
@@ -397,6 +766,8 @@ where
                                 Poll::Ready(Ok(_)) => {
                                     tracing::debug!(target: CONN, sequence_number, ?status, ?id, "Sent command");
 
+                                    self.as_mut().on_sent(request.command());
+
                                     match request {
                                         Request::Registered(request) => {
                                             tracing::debug!(target: CONN, sequence_number, ?status, ?id, "Registered");
@@ -538,6 +909,13 @@ where
                             tracing::trace!(target: CONN, "No requests in queue");
 
                             if matches!(self.state, State::Closing) {
+                                if !self.limited.is_empty() {
+                                    tracing::trace!(target: CONN, "Waiting for rate limited requests");
+
+                                    // The rate limit timer is registered, see `release_limited_requests`.
+                                    return Poll::Pending;
+                                }
+
                                 tracing::debug!(target: CONN, "Closed");
 
                                 // We set the state to `Errored` here to stop further processing in the next poll.
@@ -552,6 +930,8 @@ where
                         }
                     }
                 }
+
+                self.as_mut().notify_drained();
             }
 
             if matches!(self.state, State::Active) {
@@ -571,13 +951,111 @@ where
                     }
 
                     match self.as_mut().project().framed.poll_next(cx) {
-                        Poll::Ready(Some(Ok(command))) => {
+                        Poll::Ready(Some(Ok(Err(malformed)))) => {
+                            let sequence_number = malformed.sequence_number();
+                            let status = malformed.status();
+                            let id = malformed.id();
+
+                            tracing::warn!(target: CONN, sequence_number, ?status, ?id, error=%malformed.error(), "Malformed command");
+
+                            // The frame was skipped, the connection keeps running.
+                            // Responses are not answered, the pending request fails with the error instead.
+                            if !id.is_response() {
+                                let response = Command::builder()
+                                    .status(malformed.generic_nack_status())
+                                    .sequence_number(sequence_number)
+                                    .pdu(Pdu::GenericNack);
+
+                                let request = ObligatedRequest::new(response);
+
+                                self.as_mut()
+                                    .requests_push_front(Request::Obligated(request));
+                            }
+
+                            let error = Error::malformed_command(malformed);
+
+                            let error = match id.is_response() {
+                                true => match self.as_mut().remove_response(sequence_number) {
+                                    Some(response) => {
+                                        tracing::trace!(target: CONN, sequence_number, ?status, ?id, "Found response");
+
+                                        response.send(Err(error)).err().and_then(Result::err)
+                                    }
+                                    None => Some(error),
+                                },
+                                false => Some(error),
+                            };
+
+                            if let Some(error) = error {
+                                // Client not waiting, return the error as an event instead
+                                let _ = self.as_mut().events.send_error(error);
+                            }
+
+                            self.as_mut().notify_drained();
+
+                            continue 'main;
+                        }
+                        Poll::Ready(Some(Ok(Ok(command)))) => {
                             let sequence_number = command.sequence_number();
                             let status = command.status();
                             let id = command.id();
 
                             tracing::debug!(target: CONN, sequence_number, ?status, ?id, "Received command");
 
+                            // Operations unknown to the library are rejected, we can not respond to them properly.
+                            if !id.is_response() && matches!(command.pdu(), Some(Pdu::Other { .. }))
+                            {
+                                tracing::warn!(target: CONN, sequence_number, ?status, ?id, "Unsupported command");
+
+                                let response = Command::builder()
+                                    .status(CommandStatus::EsmeRinvcmdid)
+                                    .sequence_number(sequence_number)
+                                    .pdu(Pdu::GenericNack);
+
+                                let request = ObligatedRequest::new(response);
+
+                                self.as_mut()
+                                    .requests_push_front(Request::Obligated(request));
+
+                                let _ = self
+                                    .as_mut()
+                                    .events
+                                    .send_error(Error::unsupported_command(command));
+
+                                continue 'main;
+                            }
+
+                            // Operations from the server must be allowed in the current session state.
+                            if !id.is_response() && self.check_session_state {
+                                let state = self.session_state();
+
+                                if !state.can_receive_as_esme(id) {
+                                    tracing::warn!(target: CONN, sequence_number, ?status, ?id, ?state, "Invalid session state");
+
+                                    let response = Command::builder()
+                                        .status(CommandStatus::EsmeRinvbndsts)
+                                        .sequence_number(sequence_number)
+                                        .pdu(invalid_session_state_response(
+                                            id,
+                                            self.interface_version(),
+                                        ));
+
+                                    let request = ObligatedRequest::new(response);
+
+                                    self.as_mut()
+                                        .requests_push_front(Request::Obligated(request));
+
+                                    let _ = self
+                                        .as_mut()
+                                        .events
+                                        .send_error(Error::unexpected_command(command, state));
+
+                                    continue 'main;
+                                }
+                            }
+
+                            self.as_mut().on_received(&command);
+
                             // Auto respond to enquire link requests from the server only if auto_enquire_link_response is enabled.
                             if let CommandId::EnquireLink = command.id()
                                 && self.auto_enquire_link_response
@@ -600,6 +1078,31 @@ where
                                 continue 'main;
                             }
 
+                            // Auto respond to unbind requests from the server and close the connection only if auto_unbind_response is enabled.
+                            if let CommandId::Unbind = id
+                                && self.auto_unbind_response
+                            {
+                                tracing::debug!(target: CONN, sequence_number, "Unbound by the server, closing");
+
+                                let response = Command::builder()
+                                    .status(CommandStatus::EsmeRok)
+                                    .sequence_number(sequence_number)
+                                    .pdu(Pdu::UnbindResp);
+
+                                let request = ObligatedRequest::new(response);
+
+                                self.as_mut()
+                                    .requests_push_front(Request::Obligated(request));
+
+                                let _ = self.as_mut().events.send_incoming(command);
+
+                                self.as_mut().set_state(State::Closing);
+
+                                self.as_mut().project().actions.close();
+
+                                continue 'main;
+                            }
+
                             // Enquire link responses not matching the last sent enquire link are ignored and must be passed to the client. (The client sent an enquire link manually)
                             if let CommandId::EnquireLinkResp = command.id() {
                                 if let Some(last_sequence_number) =
@@ -626,15 +1129,25 @@ where
                             }
 
                             if id.is_response() {
+                                if ThrottleRetry::is_throttled(status)
+                                    && let Some(delay) =
+                                        self.as_mut().project().limiter.throttled(Instant::now())
+                                {
+                                    tracing::warn!(target: CONN, sequence_number, ?status, ?id, ?delay, "Throttled");
+                                }
+
                                 match self.as_mut().remove_response(sequence_number) {
                                     Some(response) => {
                                         tracing::trace!(target: CONN, sequence_number, ?status, ?id, "Found response");
 
-                                        match response.send(command) {
+                                        match response.send(Ok(command)) {
                                             Ok(()) => {
                                                 // Sent, do nothing
                                             }
-                                            Err(command) => {
+                                            Err(Err(_)) => {
+                                                unreachable!()
+                                            }
+                                            Err(Ok(command)) => {
                                                 // Client not waiting, return the command as an incoming event instead
 
                                                 tracing::trace!(target: CONN, sequence_number, ?status, ?id, "Client not waiting");
@@ -652,20 +1165,83 @@ where
                                     }
                                 }
 
+                                self.as_mut().notify_drained();
+
                                 continue 'stream;
                             }
 
                             // Command is an operation from the server.
+                            if AutoResponse::applies_to(id) {
+                                match self.auto_response {
+                                    AutoResponse::Manual => {}
+                                    AutoResponse::Immediate => {
+                                        let response = auto_response::response(
+                                            id,
+                                            sequence_number,
+                                            CommandStatus::EsmeRok,
+                                        );
+
+                                        let request = ObligatedRequest::new(response);
+
+                                        self.as_mut()
+                                            .requests_push_back(Request::Obligated(request));
+
+                                        let _ = self.as_mut().events.send_incoming(command);
+
+                                        continue 'main;
+                                    }
+                                    AutoResponse::AfterHandler { timeout, .. } => {
+                                        let first =
+                                            self.pending_operations.next_deadline().is_none();
+
+                                        self.as_mut().project().pending_operations.push(
+                                            id,
+                                            sequence_number,
+                                            Instant::now() + timeout,
+                                        );
+
+                                        // Activate the auto response timer for the first pending operation.
+                                        // Otherwise the timer is already active and will be reactivated when it fires.
+                                        if first {
+                                            self.as_mut().respond_expired_operations(cx);
+                                        }
+                                    }
+                                }
+                            }
+
                             let _ = self.as_mut().events.send_incoming(command);
                         }
                         Poll::Ready(Some(Err(err))) => {
                             tracing::error!(target: CONN, ?err);
 
-                            self.as_mut().set_state(State::Errored);
+                            let Some(status) = err.generic_nack_status() else {
+                                self.as_mut().set_state(State::Errored);
+
+                                let _ = self.as_mut().events.send_error(Error::from(err));
+
+                                return Poll::Ready(());
+                            };
+
+                            // The stream can not be read after a decode error.
+                            // The server is notified with a generic nack before the connection is closed.
+                            // The header could not be read, so the sequence number of the command is unknown.
+                            let response = Command::builder()
+                                .status(status)
+                                .sequence_number(0)
+                                .pdu(Pdu::GenericNack);
+
+                            let request = ObligatedRequest::new(response);
+
+                            self.as_mut()
+                                .requests_push_front(Request::Obligated(request));
 
                             let _ = self.as_mut().events.send_error(Error::from(err));
 
-                            return Poll::Ready(());
+                            self.as_mut().set_state(State::Closing);
+
+                            self.as_mut().project().actions.close();
+
+                            continue 'main;
                         }
                         Poll::Ready(None) => {
                             tracing::debug!(target: CONN, "Connection closed by the server");
@@ -693,6 +1269,102 @@ where
     }
 }
 
+/// Negotiates the interface version of a session.
+///
+/// The lower of the requested version and the `sc_interface_version` returned in the bind response is used.
+/// If the server did not return an `sc_interface_version`, the requested version is assumed.
+fn negotiate(
+    requested: InterfaceVersion,
+    sc_interface_version: Option<InterfaceVersion>,
+) -> InterfaceVersion {
+    match sc_interface_version {
+        Some(sc_interface_version) if u8::from(sc_interface_version) < u8::from(requested) => {
+            sc_interface_version
+        }
+        _ => requested,
+    }
+}
+
+/// Checks that the command and its TLVs are defined in the negotiated interface version.
+///
+/// TLVs are refused altogether if the command carries none in that version,
+/// e.g. the `message_payload` of a [`ReplaceSm`](Pdu::ReplaceSm) in `SMPP v3.4`.
+fn check_interface_version(command: &Command, version: InterfaceVersion) -> Result<(), Error> {
+    let id = command.id();
+
+    if !version.supports_command(id) {
+        return Err(Error::unsupported_by_interface_version(id, None, version));
+    }
+
+    let tlvs: &[Tlv] = match command.pdu() {
+        Some(Pdu::BindTransmitterResp(pdu)) => single(pdu.sc_interface_version_tlv()),
+        Some(Pdu::BindReceiverResp(pdu)) => single(pdu.sc_interface_version_tlv()),
+        Some(Pdu::BindTransceiverResp(pdu)) => single(pdu.sc_interface_version_tlv()),
+        Some(Pdu::AlertNotification(pdu)) => single(pdu.ms_availability_status_tlv()),
+        Some(Pdu::SubmitSm(pdu)) => pdu.tlvs(),
+        Some(Pdu::SubmitSmResp(pdu)) => pdu.tlvs(),
+        Some(Pdu::DeliverSm(pdu)) => pdu.tlvs(),
+        Some(Pdu::DeliverSmResp(pdu)) => pdu.tlvs(),
+        Some(Pdu::DataSm(pdu)) => pdu.tlvs(),
+        Some(Pdu::DataSmResp(pdu)) => pdu.tlvs(),
+        Some(Pdu::ReplaceSm(pdu)) => single(pdu.message_payload_tlv()),
+        Some(Pdu::SubmitMulti(pdu)) => pdu.tlvs(),
+        Some(Pdu::SubmitMultiResp(pdu)) => pdu.tlvs(),
+        Some(Pdu::BroadcastSm(pdu)) => pdu.tlvs(),
+        Some(Pdu::BroadcastSmResp(pdu)) => pdu.tlvs(),
+        Some(Pdu::QueryBroadcastSm(pdu)) => single(pdu.user_message_reference_tlv()),
+        Some(Pdu::QueryBroadcastSmResp(pdu)) => pdu.tlvs(),
+        Some(Pdu::CancelBroadcastSm(pdu)) => pdu.tlvs(),
+        _ => &[],
+    };
+
+    let unsupported = match version.supports_tlvs(id) {
+        true => tlvs.iter().find(|tlv| !version.supports_tlv(tlv.tag())),
+        false => tlvs.first(),
+    };
+
+    match unsupported {
+        Some(tlv) => Err(Error::unsupported_by_interface_version(
+            id,
+            Some(tlv.tag()),
+            version,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the TLV of a PDU field holding at most one TLV as a slice.
+fn single(tlv: Option<&Tlv>) -> &[Tlv] {
+    tlv.map_or(&[], core::slice::from_ref)
+}
+
+/// Creates the response to an operation received in an invalid session state.
+///
+/// `SMPP v3.4` reserves the [`GenericNack`](Pdu::GenericNack) for invalid headers,
+/// operations with a response are answered with their own (empty) response instead.
+fn invalid_session_state_response(id: CommandId, version: Option<InterfaceVersion>) -> Pdu {
+    if version != Some(InterfaceVersion::Smpp3_4) {
+        return Pdu::GenericNack;
+    }
+
+    match id {
+        CommandId::DeliverSm => DeliverSmResp::default().into(),
+        CommandId::DataSm => DataSmResp::default().into(),
+        CommandId::Unbind => Pdu::UnbindResp,
+        CommandId::EnquireLink => Pdu::EnquireLinkResp,
+        _ => Pdu::GenericNack,
+    }
+}
+
+/// The outcome of a session, returned by the connection future created with [`ConnectionBuilder::session`].
+#[derive(Debug)]
+pub(crate) struct Ended {
+    /// The session state when the connection terminated.
+    pub(crate) session_state: SessionState,
+    /// The client's actions the connection did not process, see [`Connection::take_unprocessed`].
+    pub(crate) unprocessed: Vec<Action>,
+}
+
 impl<E: EventChannel> NoSpawnConnectionBuilder<E> {
     /// Consumes the builder and creates a new [`Client`] along with the connection future and event stream (from raw parts).
     pub(crate) fn raw<F, D1, D2>(
@@ -708,37 +1380,142 @@ impl<E: EventChannel> NoSpawnConnectionBuilder<E> {
     where
         D1: Delay,
         D2: Delay,
-        F: Stream<Item = Result<Command, DecodeError>>
+        F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
+            + for<'a> Sink<&'a Command, Error = EncodeError>,
+    {
+        self.builder.raw(
+            framed,
+            enquire_link_timer_delay,
+            enquire_link_response_timer_delay,
+        )
+    }
+}
+
+impl<E: EventChannel> ConnectionBuilder<E> {
+    /// Creates a new [`Client`] along with the connection future and event stream (from raw parts).
+    pub(crate) fn raw<F, D1, D2>(
+        &self,
+        framed: F,
+        enquire_link_timer_delay: D1,
+        enquire_link_response_timer_delay: D2,
+    ) -> (
+        Client,
+        impl Stream<Item = E::Event> + Unpin + 'static + use<F, D1, D2, E>,
+        impl Future<Output = ()> + use<F, D1, D2, E>,
+    )
+    where
+        D1: Delay,
+        D2: Delay,
+        F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
             + for<'a> Sink<&'a Command, Error = EncodeError>,
     {
-        let (connection, watch, actions, events) = Connection::<_, _, _, E>::new(
-            self.builder.enquire_link_interval,
-            self.builder.enquire_link_response_timeout,
-            self.builder.auto_enquire_link_response,
+        let (events_tx, events_rx) = mpsc::unbounded_channel::<E::Event>();
+        let (watch_tx, watch_rx) = watch::channel(());
+        let (session_state_tx, session_state_rx) = watch::channel(SessionState::Closed);
+        let (interface_version_tx, interface_version_rx) = watch::channel(None);
+
+        let (actions, connection) = self.session(
+            framed,
             enquire_link_timer_delay,
             enquire_link_response_timer_delay,
+            E::new(events_tx),
+            session_state_tx,
+            interface_version_tx,
+            watch_rx,
         );
 
         let client = Client::new(
             actions,
-            self.builder.response_timeout,
-            self.builder.check_interface_version,
+            self.response_timeout,
+            self.interface_version,
+            self.check_interface_version,
+            self.window,
+            self.throttle_retry,
+            session_state_rx,
+            interface_version_rx,
+            watch_tx,
+        );
+
+        (
+            client,
+            UnboundedReceiverStream::new(events_rx),
+            connection.map(drop),
+        )
+    }
+
+    /// Creates the connection future of a single session over the given framed transport.
+    ///
+    /// Returns the actions channel used to communicate with the connection.
+    /// The connection future resolves to the [`Ended`] outcome of the session.
+    ///
+    /// Events are sent through the given event channel, the session state and the negotiated interface version are published
+    /// through the given `session_state` and `interface_version` senders and the connection holds the given `watch` receiver until it terminates.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn session<F, D1, D2>(
+        &self,
+        framed: F,
+        enquire_link_timer_delay: D1,
+        enquire_link_response_timer_delay: D2,
+        events: E,
+        session_state: watch::Sender<SessionState>,
+        interface_version: watch::Sender<Option<InterfaceVersion>>,
+        watch: watch::Receiver<()>,
+    ) -> (
+        UnboundedSender<Action>,
+        impl Future<Output = Ended> + use<F, D1, D2, E>,
+    )
+    where
+        D1: Delay,
+        D2: Delay,
+        F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
+            + for<'a> Sink<&'a Command, Error = EncodeError>,
+    {
+        let (connection, actions) = Connection::<_, _, _, E>::new(
+            self.enquire_link_interval,
+            self.enquire_link_response_timeout,
+            self.auto_enquire_link_response,
+            self.check_interface_version,
+            self.check_session_state,
+            self.validate_commands,
+            self.auto_response,
+            self.auto_unbind_response,
+            RateLimiter::new(
+                self.rate_limit,
+                &self.command_rate_limits,
+                self.throttle_retry.map(|retry| retry.delay),
+            ),
+            enquire_link_timer_delay,
+            enquire_link_response_timer_delay,
+            events,
+            session_state.clone(),
+            interface_version,
             watch,
         );
 
-        (client, events, async move {
+        (actions, async move {
             let mut framed = std::pin::pin!(framed);
 
-            let connection = connection.with_framed(&mut framed);
+            let unprocessed = {
+                let mut connection = std::pin::pin!(connection.with_framed(&mut framed));
+
+                connection.as_mut().await;
 
-            // See comments on Connection struct to understand why we fuse the connection future.
-            connection.fuse().await;
+                // See comments on Connection struct to understand why the connection is dropped right after completion.
+                connection.take_unprocessed()
+            };
+
+            let ended = Ended {
+                session_state: session_state.send_replace(SessionState::Closed),
+                unprocessed,
+            };
 
             tracing::debug!(target: "rusmppc::connection::tcp", "Shutting down stream");
 
             if let Err(err) = framed.close().await {
                 tracing::error!(target: "rusmppc::connection::tcp", ?err, "Failed to shutdown stream");
             }
+
+            ended
         })
     }
 }
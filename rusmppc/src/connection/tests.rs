@@ -70,16 +70,17 @@ async fn server_ddos_client_should_still_send_requests_and_connection_should_sti
 
     // This framed sends an AlertNotification pdu none stop to simulate a server DDOSing the client.
     framed.expect_poll_next_pin().returning(|_ctx| {
-        Poll::Ready(Some(Ok(Command::builder()
+        Poll::Ready(Some(Ok(Ok(Command::builder()
             .status(CommandStatus::EsmeRok)
             .sequence_number(0)
-            .pdu(Pdu::AlertNotification(Default::default())))))
+            .pdu(Pdu::AlertNotification(Default::default()))))))
     });
 
     let enquire_link_timer_delay = MockDelay::new().delay_after_seconds();
     let enquire_link_response_timer_delay = MockDelay::new().delay_after_seconds();
 
     let (client, events, future) = ConnectionBuilder::new()
+        .disable_session_state_check()
         // Send an enquire link every 50 polls
         .enquire_link_interval(Duration::from_secs(50))
         // Wait for 5 polls for the enquire link response
@@ -144,7 +145,7 @@ async fn client_ddos_and_server_ddos_connection_should_still_respond_to_enquire_
             .unwrap()
             .push(seq_number);
 
-        Poll::Ready(Some(Ok(command)))
+        Poll::Ready(Some(Ok(Ok(command))))
     });
 
     // Ignore everything sent to you. except the enquire link response
@@ -163,6 +164,7 @@ async fn client_ddos_and_server_ddos_connection_should_still_respond_to_enquire_
     let enquire_link_response_timer_delay = MockDelay::new().delay_after_seconds();
 
     let (client, events, future) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .no_enquire_link_interval()
         .no_spawn()
         .raw(
@@ -238,7 +240,7 @@ async fn client_ddos_and_server_ddos_connection_should_still_respond_to_enquire_
             .unwrap()
             .push(seq_number);
 
-        Poll::Ready(Some(Ok(command)))
+        Poll::Ready(Some(Ok(Ok(command))))
     });
 
     // Ignore everything sent to you. except the enquire link response
@@ -257,6 +259,7 @@ async fn client_ddos_and_server_ddos_connection_should_still_respond_to_enquire_
     let enquire_link_response_timer_delay = MockDelay::new().delay_after_seconds();
 
     let (client, events, future) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .no_enquire_link_interval()
         .no_spawn()
         .raw(
@@ -323,6 +326,7 @@ async fn sink_first_poll_ready_pending_pending_request_should_be_sent() {
     let enquire_link_response_timer_delay = MockDelay::new().delay_after_seconds();
 
     let (client, events, future) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .no_enquire_link_interval()
         .no_spawn()
         .raw(
@@ -385,6 +389,7 @@ async fn sink_first_poll_flush_pending_pending_request_should_be_sent() {
     let enquire_link_response_timer_delay = MockDelay::new().delay_after_seconds();
 
     let (client, events, future) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .no_enquire_link_interval()
         .no_spawn()
         .raw(
@@ -3,8 +3,12 @@
 use std::time::Duration;
 
 use rusmpp::{
-    Command,
-    tokio_codec::{DecodeError, EncodeError},
+    Command, CommandId, CommandStatus,
+    session::SessionState,
+    tlvs::TlvTag,
+    tokio_codec::{DecodeError, EncodeError, MalformedCommand},
+    types::COctetString,
+    validate::Violation,
     values::InterfaceVersion,
 };
 
@@ -92,18 +96,155 @@ pub enum Error {
         /// The response that was received from the server.
         response: Box<Command>,
     },
+    /// The server rejected the request with a [`GenericNack`](rusmpp::Pdu::GenericNack).
+    ///
+    /// The server could not process the request, e.g. the command id or the command length is invalid.
+    ///
+    /// This error is returned by methods that send commands and wait for a response, such as [`bind_transceiver`](crate::client::Client::bind_transceiver) and [`submit_sm`](crate::client::Client::submit_sm).
+    #[error(
+        "Request rejected with generic nack: sequence number: {sequence_number}, status: {status:?}"
+    )]
+    GenericNack {
+        /// The sequence number of the rejected request.
+        sequence_number: u32,
+        /// The status of the [`GenericNack`](rusmpp::Pdu::GenericNack).
+        status: CommandStatus,
+    },
     /// The client used an interface version that is not supported by the library.
     ///
-    /// The library supports only `SMPP v5.0`.
+    /// The library supports `SMPP v3.4` and `SMPP v5.0`.
     ///
     /// This error is returned by methods that send bind commands, such as [`bind_transceiver`](crate::client::Client::bind_transceiver), [`bind_receiver`](crate::client::Client::bind_receiver), and [`bind_transmitter`](crate::client::Client::bind_transmitter).
     #[error("Unsupported interface version: {version:?}, supported version: {supported_version:?}")]
     UnsupportedInterfaceVersion {
         /// The requested interface version.
         version: InterfaceVersion,
-        /// The version that is supported by the library.
+        /// The latest version that is supported by the library.
         supported_version: InterfaceVersion,
     },
+    /// The command or one of its TLVs is not defined in the interface version negotiated with the server.
+    ///
+    /// The command was rejected locally and was not sent to the server.
+    /// For example, a client bound with `SMPP v3.4` can not send a [`BroadcastSm`](rusmpp::pdus::BroadcastSm).
+    ///
+    /// This error is returned by methods that send commands, such as [`broadcast_sm`](crate::client::Client::broadcast_sm), unless the interface version check is disabled.
+    /// See [`ConnectionBuilder::with_interface_version_check`](crate::builder::ConnectionBuilder::with_interface_version_check).
+    #[error("Not supported by interface version: id: {id:?}, tlv: {tlv:?}, version: {version:?}")]
+    UnsupportedByInterfaceVersion {
+        /// The id of the rejected command.
+        id: CommandId,
+        /// The tag of the rejected TLV, if the command itself is supported.
+        tlv: Option<TlvTag>,
+        /// The negotiated interface version.
+        version: InterfaceVersion,
+    },
+    /// The command is not allowed in the current session state.
+    ///
+    /// The command was rejected locally and was not sent to the server.
+    /// For example, a client bound as a receiver can not send a [`SubmitSm`](rusmpp::pdus::SubmitSm).
+    ///
+    /// This error is returned by methods that send commands, such as [`submit_sm`](crate::client::Client::submit_sm), unless the session state check is disabled.
+    /// See [`ConnectionBuilder::with_session_state_check`](crate::builder::ConnectionBuilder::with_session_state_check).
+    #[error("Command not allowed in session state: id: {id:?}, state: {state:?}")]
+    InvalidSessionState {
+        /// The id of the rejected command.
+        id: CommandId,
+        /// The session state at the time of the request.
+        state: SessionState,
+    },
+    /// The command violates one or more rules of the `SMPP` protocol.
+    ///
+    /// The command was rejected locally and was not sent to the server.
+    /// For example, a [`SubmitSm`](rusmpp::pdus::SubmitSm) with both a `short_message` and a `message_payload`.
+    ///
+    /// This error is returned by methods that send commands, such as [`submit_sm`](crate::client::Client::submit_sm), if the command validation is enabled.
+    /// See [`ConnectionBuilder::with_command_validation`](crate::builder::ConnectionBuilder::with_command_validation).
+    #[error("Invalid command: id: {id:?}, violations: {violations:?}")]
+    InvalidCommand {
+        /// The id of the rejected command.
+        id: CommandId,
+        /// The violated rules.
+        violations: Vec<Violation>,
+    },
+    /// The connection is shutting down and does not accept new operations.
+    ///
+    /// The command was rejected locally and was not sent to the server.
+    /// Responses and [`Unbind`](rusmpp::Pdu::Unbind) are still accepted while the connection is shutting down.
+    ///
+    /// This error is returned by methods that send commands, such as [`submit_sm`](crate::client::Client::submit_sm), after [`Client::shutdown`](crate::client::Client::shutdown) was called.
+    #[error("Connection is shutting down: id: {id:?}")]
+    ShuttingDown {
+        /// The id of the rejected command.
+        id: CommandId,
+    },
+    /// The server sent an operation that is not allowed in the current session state.
+    ///
+    /// The connection responds with a [`GenericNack`](rusmpp::Pdu::GenericNack) with the status [`EsmeRinvbndsts`](rusmpp::CommandStatus::EsmeRinvbndsts)
+    /// and the command is not passed as an [`Event::Incoming`](crate::event::Event::Incoming).
+    ///
+    /// This error goes through the event stream as an [`Event::Error`](crate::event::Event::Error), unless the session state check is disabled.
+    /// See [`ConnectionBuilder::with_session_state_check`](crate::builder::ConnectionBuilder::with_session_state_check).
+    #[error("Unexpected command from the server: state: {state:?}, command: {command:?}")]
+    UnexpectedCommand {
+        /// The command that was received from the server.
+        command: Box<Command>,
+        /// The session state at the time the command was received.
+        state: SessionState,
+    },
+    /// The server sent a command with a valid header but a body that could not be decoded.
+    ///
+    /// The connection responds with a [`GenericNack`](rusmpp::Pdu::GenericNack) for the sequence number of the command and keeps running.
+    /// Malformed responses are not answered, the request waiting for the response fails with this error instead.
+    ///
+    /// This error goes through the event stream as an [`Event::Error`](crate::event::Event::Error), unless a request was waiting for the response.
+    #[error("Malformed command from the server: {0}")]
+    MalformedCommand(#[source] Box<MalformedCommand>),
+    /// The server sent an operation with a command id that is unknown to the library.
+    ///
+    /// The connection responds with a [`GenericNack`](rusmpp::Pdu::GenericNack) with the status [`EsmeRinvcmdid`](rusmpp::CommandStatus::EsmeRinvcmdid)
+    /// and the command is not passed as an [`Event::Incoming`](crate::event::Event::Incoming).
+    ///
+    /// This error goes through the event stream as an [`Event::Error`](crate::event::Event::Error).
+    #[error("Unsupported command from the server: command: {command:?}")]
+    UnsupportedCommand {
+        /// The command that was received from the server.
+        command: Box<Command>,
+    },
+    /// The client could not reconnect to the `SMPP` server within the configured number of attempts.
+    ///
+    /// This error goes through the event stream as an [`Event::Error`](crate::event::Event::Error) before the connection is closed.
+    ///
+    /// This error is only sent by connections created with [`ConnectionBuilder::reconnect`](crate::builder::ConnectionBuilder::reconnect).
+    #[error("Reconnect attempts exhausted: attempts: {attempts}")]
+    ReconnectAttemptsExhausted {
+        /// The number of failed attempts.
+        attempts: u32,
+    },
+    /// No client of the [`ClientPool`](crate::ClientPool) is currently active.
+    ///
+    /// Closed clients are replaced in the background, the request can be retried later.
+    ///
+    /// This error is returned by [`ClientPool::client`](crate::ClientPool::client).
+    #[error("No client available in the pool")]
+    NoClientAvailable,
+    /// The `SMPP` server did not send an [`Outbind`](rusmpp::pdus::Outbind) within the specified timeout.
+    ///
+    /// This error is returned by methods that accept outbind connections, such as [`OutbindConnectionBuilder::accept`](crate::builder::OutbindConnectionBuilder::accept).
+    #[error("Server did not send outbind: timeout: {timeout:?}")]
+    OutbindTimeout {
+        /// The timeout duration.
+        timeout: Duration,
+    },
+    /// The [`Outbind`](rusmpp::pdus::Outbind) sent by the `SMPP` server did not match the expected `system_id` or `password`.
+    ///
+    /// The connection is closed without binding.
+    ///
+    /// This error is returned by methods that accept outbind connections, such as [`OutbindConnectionBuilder::accept`](crate::builder::OutbindConnectionBuilder::accept).
+    #[error("Outbind rejected: system_id: {system_id}")]
+    OutbindRejected {
+        /// The `system_id` sent by the server.
+        system_id: COctetString<1, 16>,
+    },
 }
 
 impl Error {
@@ -113,6 +254,23 @@ impl Error {
         }
     }
 
+    pub(crate) const fn generic_nack(sequence_number: u32, status: CommandStatus) -> Self {
+        Self::GenericNack {
+            sequence_number,
+            status,
+        }
+    }
+
+    pub(crate) fn malformed_command(command: MalformedCommand) -> Self {
+        Self::MalformedCommand(Box::new(command))
+    }
+
+    pub(crate) fn unsupported_command(command: impl Into<Box<Command>>) -> Self {
+        Self::UnsupportedCommand {
+            command: command.into(),
+        }
+    }
+
     pub(crate) const fn unsupported_interface_version(version: InterfaceVersion) -> Self {
         Self::UnsupportedInterfaceVersion {
             version,
@@ -120,6 +278,36 @@ impl Error {
         }
     }
 
+    pub(crate) const fn unsupported_by_interface_version(
+        id: CommandId,
+        tlv: Option<TlvTag>,
+        version: InterfaceVersion,
+    ) -> Self {
+        Self::UnsupportedByInterfaceVersion { id, tlv, version }
+    }
+
+    pub(crate) const fn invalid_session_state(id: CommandId, state: SessionState) -> Self {
+        Self::InvalidSessionState { id, state }
+    }
+
+    pub(crate) const fn invalid_command(id: CommandId, violations: Vec<Violation>) -> Self {
+        Self::InvalidCommand { id, violations }
+    }
+
+    pub(crate) const fn shutting_down(id: CommandId) -> Self {
+        Self::ShuttingDown { id }
+    }
+
+    pub(crate) fn unexpected_command(
+        command: impl Into<Box<Command>>,
+        state: SessionState,
+    ) -> Self {
+        Self::UnexpectedCommand {
+            command: command.into(),
+            state,
+        }
+    }
+
     pub(crate) const fn response_timeout(sequence_number: u32, timeout: Duration) -> Self {
         Self::ResponseTimeout {
             sequence_number,
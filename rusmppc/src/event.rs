@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use rusmpp::Command;
 use tokio::sync::mpsc::error::SendError;
@@ -15,6 +15,10 @@ pub enum Event {
     Incoming(Command),
     /// An error occurred.
     Error(Error),
+    /// A reconnect event.
+    ///
+    /// Only sent by connections created with [`ConnectionBuilder::reconnect`](crate::ConnectionBuilder::reconnect).
+    Reconnect(Reconnect),
 }
 
 /// `SMPP` event with insights
@@ -29,6 +33,10 @@ pub enum InsightEvent {
     Error(Error),
     /// An insight event.
     Insight(Insight),
+    /// A reconnect event.
+    ///
+    /// Only sent by connections created with [`ConnectionBuilder::reconnect`](crate::ConnectionBuilder::reconnect).
+    Reconnect(Reconnect),
 }
 
 /// Connection insight event.
@@ -45,8 +53,32 @@ pub enum Insight {
     SentEnquireLinkResp(u32),
 }
 
-pub trait EventChannel: Send + 'static {
-    type Event;
+/// Reconnect event.
+#[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum Reconnect {
+    /// The connection was lost, the client will try to reconnect after the given delay.
+    Reconnecting {
+        /// The reconnect attempt, starting from 1.
+        attempt: u32,
+        /// The delay before the attempt.
+        delay: Duration,
+    },
+    /// The client reconnected to the server.
+    Reconnected {
+        /// The reconnect attempt that succeeded.
+        attempt: u32,
+    },
+    /// The client replayed the last successful bind command and the server accepted it.
+    Rebound {
+        /// The bind response received from the server.
+        response: Command,
+    },
+}
+
+pub trait EventChannel: Send + Sync + 'static {
+    type Event: Send;
 
     /// Creates a new [`EventChannel`] instance.
     fn new(sender: tokio::sync::mpsc::UnboundedSender<Self::Event>) -> Self
@@ -61,6 +93,9 @@ pub trait EventChannel: Send + 'static {
 
     /// Sends an [`Insight`] event through the event channel.
     fn send_insight(&self, insight: Insight) -> Result<(), SendError<Self::Event>>;
+
+    /// Sends a [`Reconnect`] event through the event channel.
+    fn send_reconnect(&self, reconnect: Reconnect) -> Result<(), SendError<Self::Event>>;
 }
 
 /// The default [`EventChannel`] implementation that sends [`Event`]s through the event stream.
@@ -94,6 +129,10 @@ impl EventChannel for DefaultEventChannel {
 
         Ok(())
     }
+
+    fn send_reconnect(&self, reconnect: Reconnect) -> Result<(), SendError<Self::Event>> {
+        self.sender.send(Event::Reconnect(reconnect))
+    }
 }
 
 /// An [`EventChannel`] implementation that discards all events.
@@ -127,6 +166,10 @@ impl EventChannel for DiscardEventChannel {
     fn send_insight(&self, _insight: Insight) -> Result<(), SendError<Self::Event>> {
         Ok(())
     }
+
+    fn send_reconnect(&self, _reconnect: Reconnect) -> Result<(), SendError<Self::Event>> {
+        Ok(())
+    }
 }
 
 /// An [`EventChannel`] implementation that sends [`InsightEvent`]s through the event stream.
@@ -158,4 +201,8 @@ impl EventChannel for InsightEventChannel {
     fn send_insight(&self, insight: Insight) -> Result<(), SendError<Self::Event>> {
         self.sender.send(InsightEvent::Insight(insight))
     }
+
+    fn send_reconnect(&self, reconnect: Reconnect) -> Result<(), SendError<Self::Event>> {
+        self.sender.send(InsightEvent::Reconnect(reconnect))
+    }
 }
@@ -1,6 +1,6 @@
 //! # Rusmppc
 //!
-//! A [`tokio`](https://docs.rs/tokio/latest/tokio/) based [SMPP v5](https://smpp.org/SMPP_v5.pdf) client.
+//! A [`tokio`](https://docs.rs/tokio/latest/tokio/) based [SMPP v5](https://smpp.org/SMPP_v5.pdf) client, supporting `SMPP v3.4` sessions.
 //!
 //! ## Features
 //!
@@ -109,16 +109,20 @@ mod builder;
 pub use builder::ConnectionBuilder;
 
 mod event;
-pub use event::{Event, Insight, InsightEvent};
+pub use event::{Event, Insight, InsightEvent, Reconnect};
 
 mod request;
-pub(crate) use request::{CloseRequest, RegisteredRequest, Request, UnregisteredRequest};
+pub(crate) use request::{
+    AbandonRequest, CloseRequest, DrainRequest, RegisteredRequest, Request, Response,
+    UnregisteredRequest,
+};
 
 mod timer;
 pub(crate) use timer::Timer;
 
 mod client;
 pub use client::Client;
+pub(crate) use client::SequenceNumber;
 
 pub mod error;
 
@@ -136,6 +140,21 @@ pub(crate) use tcp_stream::MaybeTlsStream;
 
 mod delay;
 
+mod reconnect;
+
+mod outbind;
+
+mod window;
+
+mod rate;
+pub use rate::{RateLimit, ThrottleRetry};
+
+mod auto_response;
+pub use auto_response::AutoResponse;
+
+mod pool;
+pub use pool::{Balance, ClientPool, PoolEvent};
+
 #[cfg(test)]
 mod tests;
 
@@ -73,7 +73,10 @@ pub mod io {
 
 pub mod framed {
     use futures::{Sink, Stream};
-    use rusmpp::{Command, tokio_codec::DecodeError, tokio_codec::EncodeError};
+    use rusmpp::{
+        Command,
+        tokio_codec::{DecodeError, EncodeError, MalformedCommand},
+    };
 
     use super::*;
 
@@ -83,7 +86,7 @@ pub mod framed {
         fn poll_next_pin<'a>(
             self: Pin<&mut Self>,
             cx: &mut Context<'a>,
-        ) -> Poll<Option<Result<Command, DecodeError>>>;
+        ) -> Poll<Option<Result<Result<Command, MalformedCommand>, DecodeError>>>;
 
         fn poll_ready_pin<'a>(
             self: Pin<&mut Self>,
@@ -104,7 +107,7 @@ pub mod framed {
     }
 
     impl Stream for MockFramed {
-        type Item = Result<Command, DecodeError>;
+        type Item = Result<Result<Command, MalformedCommand>, DecodeError>;
 
         fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
             self.poll_next_pin(cx)
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use rusmpp::{
+    Command, CommandStatus, Pdu,
+    pdus::Outbind,
+    tokio_codec::{DecodeError, EncodeError, MalformedCommand},
+    types::COctetString,
+};
+
+use crate::error::Error;
+
+const OUTBIND: &str = "rusmppc::connection::outbind";
+
+/// The credentials an [`Outbind`] must carry to be accepted.
+#[derive(Debug, Default)]
+pub(crate) struct OutbindCredentials {
+    pub(crate) system_id: Option<COctetString<1, 16>>,
+    pub(crate) password: Option<COctetString<1, 9>>,
+}
+
+impl OutbindCredentials {
+    fn verify(&self, outbind: &Outbind) -> bool {
+        self.system_id
+            .as_ref()
+            .is_none_or(|system_id| *system_id == outbind.system_id)
+            && self
+                .password
+                .as_ref()
+                .is_none_or(|password| *password == outbind.password)
+    }
+}
+
+/// Waits for an [`Outbind`] on a freshly accepted connection and verifies it.
+///
+/// [`EnquireLink`](Pdu::EnquireLink) commands received in the meantime are answered,
+/// any other operation is rejected with a [`GenericNack`](Pdu::GenericNack).
+pub(crate) async fn outbind<F>(
+    framed: &mut F,
+    credentials: &OutbindCredentials,
+    timeout: Option<Duration>,
+) -> Result<Outbind, Error>
+where
+    F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
+        + for<'a> Sink<&'a Command, Error = EncodeError>
+        + Unpin,
+{
+    tracing::debug!(target: OUTBIND, "Awaiting outbind");
+
+    let outbind = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, receive(framed))
+            .await
+            .map_err(|_| Error::OutbindTimeout { timeout })??,
+        None => receive(framed).await?,
+    };
+
+    if !credentials.verify(&outbind) {
+        tracing::warn!(target: OUTBIND, system_id = %outbind.system_id, "Outbind rejected");
+
+        if let Err(err) = framed.close().await {
+            tracing::error!(target: OUTBIND, ?err, "Failed to shutdown stream");
+        }
+
+        return Err(Error::OutbindRejected {
+            system_id: outbind.system_id,
+        });
+    }
+
+    tracing::debug!(target: OUTBIND, system_id = %outbind.system_id, "Outbind accepted");
+
+    Ok(outbind)
+}
+
+async fn receive<F>(framed: &mut F) -> Result<Outbind, Error>
+where
+    F: Stream<Item = Result<Result<Command, MalformedCommand>, DecodeError>>
+        + for<'a> Sink<&'a Command, Error = EncodeError>
+        + Unpin,
+{
+    loop {
+        let command = match framed.next().await.ok_or(Error::ConnectionClosedByPeer)?? {
+            Ok(command) => command,
+            Err(malformed) => {
+                let sequence_number = malformed.sequence_number();
+                let id = malformed.id();
+
+                tracing::warn!(target: OUTBIND, sequence_number, ?id, error=%malformed.error(), "Received malformed command");
+
+                if !id.is_response() {
+                    framed
+                        .send(&Command::new(
+                            malformed.generic_nack_status(),
+                            sequence_number,
+                            Pdu::GenericNack,
+                        ))
+                        .await?;
+                }
+
+                continue;
+            }
+        };
+
+        let id = command.id();
+        let sequence_number = command.sequence_number();
+
+        tracing::debug!(target: OUTBIND, sequence_number, ?id, "Received command");
+
+        if id.is_response() {
+            tracing::warn!(target: OUTBIND, sequence_number, ?id, "Received unexpected response");
+
+            continue;
+        }
+
+        let pdu = match command.into_parts().raw().3 {
+            Some(Pdu::Outbind(outbind)) => return Ok(outbind),
+            Some(Pdu::EnquireLink) => Pdu::EnquireLinkResp,
+            _ => {
+                tracing::warn!(target: OUTBIND, sequence_number, ?id, "Received command before outbind");
+
+                Pdu::GenericNack
+            }
+        };
+
+        let status = match pdu {
+            Pdu::GenericNack => CommandStatus::EsmeRinvbndsts,
+            _ => CommandStatus::EsmeRok,
+        };
+
+        framed
+            .send(&Command::new(status, sequence_number, pdu))
+            .await?;
+    }
+}
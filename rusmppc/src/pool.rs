@@ -0,0 +1,426 @@
+//! A pool of `SMPP` clients balancing requests over several binds.
+
+use std::{
+    pin::Pin,
+    sync::{
+        Arc, Mutex, MutexGuard, Weak,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use futures::{Stream, StreamExt};
+use rusmpp::pdus::{BindReceiver, BindTransceiver, BindTransmitter};
+use tokio::sync::{mpsc::UnboundedSender, watch};
+
+use crate::{
+    Client, ConnectionBuilder, delay::TokioDelay, error::Error, event::EventChannel,
+    reconnect::Backoff,
+};
+
+const TARGET: &str = "rusmppc::pool";
+
+/// Event stream of a client in the pool.
+type Events<E> = Pin<Box<dyn Stream<Item = <E as EventChannel>::Event> + Send>>;
+
+/// Strategy used by a [`ClientPool`] to pick the client for the next request.
+///
+/// See [`PoolConnectionBuilder::balance`](crate::builder::PoolConnectionBuilder::balance).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Balance {
+    /// Picks the clients one after another.
+    #[default]
+    RoundRobin,
+    /// Picks the client with the fewest responses pending, see [`Client::pending_responses`].
+    ///
+    /// Ties are broken in a round robin fashion.
+    LeastPending,
+}
+
+/// Event sent through the event stream of a [`ClientPool`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum PoolEvent<T> {
+    /// An event sent by the client in the given slot.
+    Client {
+        /// The slot of the client.
+        slot: usize,
+        /// The event sent by the client.
+        event: T,
+    },
+    /// The connection of the client in the given slot was closed and the client was removed from the pool.
+    Removed {
+        /// The slot of the removed client.
+        slot: usize,
+    },
+    /// A replacement for the removed client could not be connected or bound.
+    ///
+    /// The pool tries again after a delay.
+    ReplaceFailed {
+        /// The slot of the removed client.
+        slot: usize,
+        /// The attempt that failed, starting from 1.
+        attempt: u32,
+        /// The reason of the failure.
+        error: Error,
+    },
+    /// The removed client was replaced with a new bound client.
+    Replaced {
+        /// The slot of the new client.
+        slot: usize,
+        /// The attempt that succeeded.
+        attempt: u32,
+    },
+}
+
+/// The bind command sent by every client of the pool.
+#[derive(Debug, Clone)]
+pub(crate) enum PoolBind {
+    Transmitter(BindTransmitter),
+    Receiver(BindReceiver),
+    Transceiver(BindTransceiver),
+}
+
+impl PoolBind {
+    async fn bind(&self, client: &Client) -> Result<(), Error> {
+        match self {
+            Self::Transmitter(bind) => client.bind_transmitter(bind.clone()).await.map(drop),
+            Self::Receiver(bind) => client.bind_receiver(bind.clone()).await.map(drop),
+            Self::Transceiver(bind) => client.bind_transceiver(bind.clone()).await.map(drop),
+        }
+    }
+}
+
+/// A pool of bound `SMPP` clients.
+///
+/// Servers usually limit the throughput of a single bind, the pool distributes requests over several binds,
+/// possibly to different servers.
+///
+/// Clients whose connection is closed are removed from the pool and replaced in the background,
+/// the progress is reported through the event stream as [`PoolEvent`]s.
+///
+/// See [`ConnectionBuilder::pool`](crate::ConnectionBuilder::pool).
+#[derive(Debug, Clone)]
+pub struct ClientPool {
+    inner: Arc<PoolInner>,
+}
+
+#[derive(Debug)]
+struct PoolInner {
+    /// The clients of the pool, `None` while a client is being replaced.
+    slots: Mutex<Vec<Option<Client>>>,
+    balance: Balance,
+    next: AtomicUsize,
+    /// Set to `true` when the pool is closed, stops replacing clients.
+    closed: watch::Sender<bool>,
+}
+
+impl ClientPool {
+    /// Returns the number of slots in the pool.
+    pub fn size(&self) -> usize {
+        self.inner.slots().len()
+    }
+
+    /// Returns the clients that are currently active.
+    ///
+    /// See [`Client::is_active`].
+    pub fn clients(&self) -> Vec<Client> {
+        self.inner
+            .slots()
+            .iter()
+            .flatten()
+            .filter(|client| client.is_active())
+            .cloned()
+            .collect()
+    }
+
+    /// Picks a client for the next request, using the configured [`Balance`] strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoClientAvailable`] if no client is currently active.
+    pub async fn client(&self) -> Result<Client, Error> {
+        let clients = self.clients();
+
+        if clients.is_empty() {
+            return Err(Error::NoClientAvailable);
+        }
+
+        let start = self.inner.next.fetch_add(1, Ordering::Relaxed) % clients.len();
+
+        match self.inner.balance {
+            Balance::RoundRobin => Ok(clients[start].clone()),
+            Balance::LeastPending => {
+                let mut least: Option<(usize, &Client)> = None;
+
+                for client in clients.iter().cycle().skip(start).take(clients.len()) {
+                    // A client that can not report its pending responses is closing.
+                    let Ok(pending) = client.pending_responses().await else {
+                        continue;
+                    };
+
+                    if least.is_none_or(|(least, _)| pending.len() < least) {
+                        least = Some((pending.len(), client));
+                    }
+                }
+
+                least
+                    .map(|(_, client)| client.clone())
+                    .ok_or(Error::NoClientAvailable)
+            }
+        }
+    }
+
+    /// Closes every client of the pool and waits for the connections to terminate.
+    ///
+    /// Closed clients are no longer replaced.
+    pub async fn close(&self) {
+        self.inner.closed.send_replace(true);
+
+        let clients: Vec<Client> = self
+            .inner
+            .slots()
+            .iter_mut()
+            .flat_map(Option::take)
+            .collect();
+
+        for client in clients {
+            let _ = client.close().await;
+
+            client.closed().await;
+        }
+    }
+
+    /// Checks if the pool is closed.
+    pub fn is_closed(&self) -> bool {
+        *self.inner.closed.borrow()
+    }
+}
+
+impl PoolInner {
+    fn slots(&self) -> MutexGuard<'_, Vec<Option<Client>>> {
+        self.slots.lock().expect("Pool lock poisoned")
+    }
+}
+
+/// Connects a client to the given URL and binds it.
+async fn connect<E: EventChannel>(
+    builder: &ConnectionBuilder<E>,
+    url: &str,
+    bind: &PoolBind,
+) -> Result<(Client, Events<E>), Error> {
+    let stream = builder.dial(url).await?;
+
+    let (client, events, connection) =
+        builder.raw(builder.framed(stream), TokioDelay::new(), TokioDelay::new());
+
+    tokio::spawn(connection);
+
+    if let Err(err) = bind.bind(&client).await {
+        let _ = client.close().await;
+
+        return Err(err);
+    }
+
+    Ok((client, Box::pin(events)))
+}
+
+/// Connects and binds the clients of the pool, one for each slot.
+///
+/// The URLs are assigned to the slots in a round robin fashion.
+/// Slots that could not be connected are replaced in the background.
+///
+/// Fails if no client could be connected.
+pub(crate) async fn pool<E: EventChannel>(
+    builder: ConnectionBuilder<E>,
+    urls: Vec<String>,
+    size: usize,
+    balance: Balance,
+    bind: PoolBind,
+    backoff: Backoff,
+    events: UnboundedSender<PoolEvent<E::Event>>,
+) -> Result<ClientPool, Error> {
+    if urls.is_empty() {
+        return Err(Error::Connect(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "At least one URL is required",
+        )));
+    }
+
+    let builder = Arc::new(builder);
+
+    let mut connected = Vec::with_capacity(size);
+    let mut error = None;
+
+    for slot in 0..size {
+        let url = &urls[slot % urls.len()];
+
+        match connect(&builder, url, &bind).await {
+            Ok(client) => connected.push(Some(client)),
+            Err(err) => {
+                tracing::error!(target: TARGET, slot, url, ?err, "Failed to connect");
+
+                connected.push(None);
+                error = Some(err);
+            }
+        }
+    }
+
+    if let Some(err) = error
+        && connected.iter().all(Option::is_none)
+    {
+        return Err(err);
+    }
+
+    let (closed, _) = watch::channel(false);
+
+    let inner = Arc::new(PoolInner {
+        slots: Mutex::new(
+            connected
+                .iter()
+                .map(|client| client.as_ref().map(|(client, _)| client.clone()))
+                .collect(),
+        ),
+        balance,
+        next: AtomicUsize::new(0),
+        closed,
+    });
+
+    for (slot, client) in connected.into_iter().enumerate() {
+        let replacer = Replacer {
+            slot,
+            url: urls[slot % urls.len()].clone(),
+            builder: builder.clone(),
+            bind: bind.clone(),
+            backoff: backoff.clone(),
+            pool: Arc::downgrade(&inner),
+            closed: inner.closed.subscribe(),
+            events: events.clone(),
+        };
+
+        tokio::spawn(replacer.run(client.map(|(_, events)| events)));
+    }
+
+    Ok(ClientPool { inner })
+}
+
+/// Background task that forwards the events of the client in a slot and replaces the client when its connection is closed.
+struct Replacer<E: EventChannel> {
+    slot: usize,
+    url: String,
+    builder: Arc<ConnectionBuilder<E>>,
+    bind: PoolBind,
+    backoff: Backoff,
+    pool: Weak<PoolInner>,
+    // Closed when the pool is dropped
+    closed: watch::Receiver<bool>,
+    events: UnboundedSender<PoolEvent<E::Event>>,
+}
+
+impl<E: EventChannel> Replacer<E> {
+    /// Runs the slot, starting with the event stream of the connected client, if any.
+    async fn run(mut self, mut client_events: Option<Events<E>>) {
+        let slot = self.slot;
+
+        loop {
+            if let Some(mut client_events) = client_events.take() {
+                // The event stream ends when the connection is closed.
+                while let Some(event) = client_events.next().await {
+                    let _ = self.events.send(PoolEvent::Client { slot, event });
+                }
+
+                let Some(pool) = self.pool.upgrade() else {
+                    return;
+                };
+
+                pool.slots()[slot] = None;
+
+                if self.is_closed() {
+                    return;
+                }
+
+                tracing::debug!(target: TARGET, slot, "Removed");
+
+                let _ = self.events.send(PoolEvent::Removed { slot });
+            }
+
+            match self.replace().await {
+                Some(events) => client_events = Some(events),
+                None => return,
+            }
+        }
+    }
+
+    /// Connects and binds a new client for the slot.
+    ///
+    /// Returns `None` if the pool was closed or dropped in the meantime.
+    async fn replace(&mut self) -> Option<Events<E>> {
+        let slot = self.slot;
+        let mut attempt: u32 = 0;
+
+        loop {
+            attempt = attempt.saturating_add(1);
+
+            let delay = self.backoff.delay(attempt);
+
+            tracing::debug!(target: TARGET, slot, attempt, ?delay, "Replacing");
+
+            until(&mut self.closed, tokio::time::sleep(delay)).await?;
+
+            let (client, events) = match until(
+                &mut self.closed,
+                connect(&self.builder, &self.url, &self.bind),
+            )
+            .await?
+            {
+                Ok(connected) => connected,
+                Err(error) => {
+                    tracing::error!(target: TARGET, slot, attempt, ?error, "Failed to replace");
+
+                    let _ = self.events.send(PoolEvent::ReplaceFailed {
+                        slot,
+                        attempt,
+                        error,
+                    });
+
+                    continue;
+                }
+            };
+
+            let pool = self.pool.upgrade();
+
+            match pool {
+                Some(pool) if !self.is_closed() => {
+                    pool.slots()[slot] = Some(client);
+                }
+                _ => {
+                    let _ = client.close().await;
+
+                    return None;
+                }
+            }
+
+            tracing::debug!(target: TARGET, slot, attempt, "Replaced");
+
+            let _ = self.events.send(PoolEvent::Replaced { slot, attempt });
+
+            return Some(events);
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        *self.closed.borrow()
+    }
+}
+
+/// Drives the future to completion, unless the pool is closed or dropped in the meantime.
+async fn until<F: Future>(closed: &mut watch::Receiver<bool>, future: F) -> Option<F::Output> {
+    if *closed.borrow() {
+        return None;
+    }
+
+    tokio::select! {
+        output = future => Some(output),
+        // Fails if the pool was dropped
+        _ = closed.wait_for(|closed| *closed) => None,
+    }
+}
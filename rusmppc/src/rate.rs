@@ -0,0 +1,288 @@
+//! Outgoing rate limiting.
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use rusmpp::{CommandId, CommandStatus};
+
+/// Token bucket rate limit.
+///
+/// Allows a number of requests per interval.
+/// Tokens are refilled continuously, one every `interval / tokens`, up to the [`burst`](Self::burst) capacity.
+///
+/// See [`ConnectionBuilder::rate_limit`](crate::ConnectionBuilder::rate_limit) and [`ConnectionBuilder::command_rate_limit`](crate::ConnectionBuilder::command_rate_limit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RateLimit {
+    tokens: u32,
+    interval: Duration,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Creates a new [`RateLimit`] allowing `tokens` requests per `interval`.
+    ///
+    /// The burst capacity defaults to `tokens`.
+    /// A value of `0` tokens is treated as `1`.
+    pub const fn new(tokens: u32, interval: Duration) -> Self {
+        let tokens = if tokens == 0 { 1 } else { tokens };
+
+        Self {
+            tokens,
+            interval,
+            burst: tokens,
+        }
+    }
+
+    /// Creates a new [`RateLimit`] allowing `tokens` requests per second.
+    pub const fn per_second(tokens: u32) -> Self {
+        Self::new(tokens, Duration::from_secs(1))
+    }
+
+    /// Sets the maximum number of requests that can be sent at once after an idle period.
+    ///
+    /// A value of `0` is treated as `1`.
+    pub const fn burst(mut self, burst: u32) -> Self {
+        self.burst = if burst == 0 { 1 } else { burst };
+        self
+    }
+
+    /// Returns the time it takes to refill a single token.
+    fn period(&self) -> Duration {
+        self.interval / self.tokens
+    }
+}
+
+/// Retry policy for requests rejected by the server with [`EsmeRthrottled`](CommandStatus::EsmeRthrottled) or [`EsmeRmsgqful`](CommandStatus::EsmeRmsgqful).
+///
+/// See [`ConnectionBuilder::throttle_retry`](crate::ConnectionBuilder::throttle_retry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThrottleRetry {
+    pub(crate) delay: Duration,
+    pub(crate) max_attempts: u32,
+}
+
+impl ThrottleRetry {
+    /// Creates a new [`ThrottleRetry`].
+    ///
+    /// - `delay`: how long the connection stops sending requests after a throttled response.
+    /// - `max_attempts`: how many times a throttled request is sent again before the response is returned to the caller.
+    pub const fn new(delay: Duration, max_attempts: u32) -> Self {
+        Self {
+            delay,
+            max_attempts,
+        }
+    }
+
+    /// Returns `true` if the status indicates that the server is throttling the client.
+    pub(crate) const fn is_throttled(status: CommandStatus) -> bool {
+        matches!(
+            status,
+            CommandStatus::EsmeRthrottled | CommandStatus::EsmeRmsgqful
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    /// Available tokens, in units of [`RateLimit::period`].
+    available: Duration,
+    last: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            available: Self::capacity(limit),
+            last: now,
+        }
+    }
+
+    fn capacity(limit: RateLimit) -> Duration {
+        limit.period() * limit.burst
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last);
+
+        self.available = (self.available + elapsed).min(Self::capacity(self.limit));
+        self.last = now;
+    }
+
+    /// Returns the time to wait until a token is available.
+    fn wait(&mut self, now: Instant) -> Duration {
+        self.refill(now);
+
+        self.limit.period().saturating_sub(self.available)
+    }
+
+    fn take(&mut self) {
+        self.available = self.available.saturating_sub(self.limit.period());
+    }
+}
+
+/// Shapes the operations sent by the client.
+///
+/// Responses are never limited.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    global: Option<Bucket>,
+    commands: BTreeMap<CommandId, Bucket>,
+    /// How long to stop sending operations after a throttled response.
+    throttle_delay: Option<Duration>,
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(
+        global: Option<RateLimit>,
+        commands: &BTreeMap<CommandId, RateLimit>,
+        throttle_delay: Option<Duration>,
+    ) -> Self {
+        let now = Instant::now();
+
+        Self {
+            global: global.map(|limit| Bucket::new(limit, now)),
+            commands: commands
+                .iter()
+                .map(|(id, limit)| (*id, Bucket::new(*limit, now)))
+                .collect(),
+            throttle_delay,
+            paused_until: None,
+        }
+    }
+
+    /// Returns `true` if the command must go through the limiter.
+    pub(crate) fn limits(&self, id: CommandId) -> bool {
+        if id.is_response() {
+            return false;
+        }
+
+        self.global.is_some() || self.throttle_delay.is_some() || self.commands.contains_key(&id)
+    }
+
+    /// Takes a token for the command.
+    ///
+    /// Returns the time to wait before trying again if no token is available.
+    pub(crate) fn try_acquire(&mut self, id: CommandId, now: Instant) -> Result<(), Duration> {
+        if let Some(until) = self.paused_until {
+            if until > now {
+                return Err(until - now);
+            }
+
+            self.paused_until = None;
+        }
+
+        let global = self.global.as_mut().map(|bucket| bucket.wait(now));
+        let command = self.commands.get_mut(&id).map(|bucket| bucket.wait(now));
+
+        let wait = global.max(command).unwrap_or_default();
+
+        if !wait.is_zero() {
+            return Err(wait);
+        }
+
+        if let Some(bucket) = self.global.as_mut() {
+            bucket.take();
+        }
+
+        if let Some(bucket) = self.commands.get_mut(&id) {
+            bucket.take();
+        }
+
+        Ok(())
+    }
+
+    /// Stops sending operations for the configured throttle delay.
+    ///
+    /// Returns the delay if the limiter was paused.
+    pub(crate) fn throttled(&mut self, now: Instant) -> Option<Duration> {
+        let delay = self.throttle_delay?;
+
+        self.paused_until = Some(now + delay);
+
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_should_allow_burst_then_wait_for_refill() {
+        let now = Instant::now();
+
+        let mut commands = BTreeMap::new();
+        commands.insert(
+            CommandId::SubmitSm,
+            RateLimit::new(2, Duration::from_millis(100)),
+        );
+
+        let mut limiter = RateLimiter::new(None, &commands, None);
+
+        assert!(limiter.try_acquire(CommandId::SubmitSm, now).is_ok());
+        assert!(limiter.try_acquire(CommandId::SubmitSm, now).is_ok());
+        assert_eq!(
+            limiter.try_acquire(CommandId::SubmitSm, now),
+            Err(Duration::from_millis(50))
+        );
+
+        // Other commands are not limited
+        assert!(limiter.try_acquire(CommandId::QuerySm, now).is_ok());
+        assert!(!limiter.limits(CommandId::QuerySm));
+
+        let now = now + Duration::from_millis(50);
+
+        assert!(limiter.try_acquire(CommandId::SubmitSm, now).is_ok());
+        assert!(limiter.try_acquire(CommandId::SubmitSm, now).is_err());
+    }
+
+    #[test]
+    fn global_and_command_limits_should_both_apply() {
+        let now = Instant::now();
+
+        let mut commands = BTreeMap::new();
+        commands.insert(CommandId::SubmitSm, RateLimit::per_second(1));
+
+        let mut limiter = RateLimiter::new(Some(RateLimit::per_second(10)), &commands, None);
+
+        assert!(limiter.try_acquire(CommandId::SubmitSm, now).is_ok());
+        assert_eq!(
+            limiter.try_acquire(CommandId::SubmitSm, now),
+            Err(Duration::from_secs(1))
+        );
+
+        // A failed attempt must not consume a global token
+        for _ in 0..9 {
+            assert!(limiter.try_acquire(CommandId::QuerySm, now).is_ok());
+        }
+
+        assert!(limiter.try_acquire(CommandId::QuerySm, now).is_err());
+    }
+
+    #[test]
+    fn throttled_should_pause_all_operations() {
+        let now = Instant::now();
+
+        let mut limiter = RateLimiter::new(None, &BTreeMap::new(), Some(Duration::from_secs(1)));
+
+        assert!(limiter.limits(CommandId::SubmitSm));
+        assert!(!limiter.limits(CommandId::DeliverSmResp));
+
+        assert_eq!(limiter.throttled(now), Some(Duration::from_secs(1)));
+
+        assert_eq!(
+            limiter.try_acquire(CommandId::SubmitSm, now + Duration::from_millis(400)),
+            Err(Duration::from_millis(600))
+        );
+        assert!(
+            limiter
+                .try_acquire(CommandId::SubmitSm, now + Duration::from_secs(1))
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,569 @@
+use std::{
+    collections::VecDeque,
+    hash::{BuildHasher, RandomState},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use rusmpp::{
+    Command, CommandId, CommandStatus, Pdu, session::SessionState, values::InterfaceVersion,
+};
+use tokio::{
+    net::TcpStream,
+    sync::{
+        mpsc::{UnboundedReceiver, UnboundedSender},
+        oneshot, watch,
+    },
+    task::JoinHandle,
+};
+
+use crate::{
+    Action, ConnectionBuilder, MaybeTlsStream, RegisteredRequest, Request, SequenceNumber,
+    connection::Ended,
+    delay::TokioDelay,
+    error::Error,
+    event::{EventChannel, Reconnect},
+};
+
+const TARGET: &str = "rusmppc::connection::reconnect";
+
+/// Exponential backoff used between reconnect attempts.
+#[derive(Debug, Clone)]
+pub(crate) struct Backoff {
+    pub(crate) initial_delay: Duration,
+    pub(crate) max_delay: Duration,
+    pub(crate) multiplier: u32,
+    pub(crate) jitter: bool,
+    pub(crate) max_attempts: Option<u32>,
+}
+
+impl Backoff {
+    pub(crate) const fn new() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2,
+            jitter: true,
+            max_attempts: None,
+        }
+    }
+
+    /// Returns the delay before the given attempt, starting from 1.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt.saturating_sub(1));
+
+        let delay = self
+            .initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay);
+
+        if !self.jitter {
+            return delay;
+        }
+
+        // Keep half of the delay and randomize the other half.
+        let half = delay / 2;
+
+        half + half.mul_f64(random())
+    }
+}
+
+/// Returns a pseudo random number in `[0, 1)`.
+///
+/// Each [`RandomState`] is seeded differently, which is good enough to spread reconnect attempts.
+fn random() -> f64 {
+    (RandomState::new().hash_one(()) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// A single connection to the server.
+#[derive(Debug)]
+struct Session {
+    actions: UnboundedSender<Action>,
+    connection: JoinHandle<Ended>,
+}
+
+/// Why the client stopped using a session.
+enum Served {
+    /// The connection was lost.
+    Lost,
+    /// The connection was closed after the session was unbound by the client or the server.
+    Unbound,
+    /// The client closed the connection or was dropped.
+    Closed,
+}
+
+/// Receives the client's actions and holds them while there is no session to forward them to.
+struct Inbox<E: EventChannel> {
+    actions: UnboundedReceiver<Action>,
+    /// Requests waiting for the next session.
+    queue: VecDeque<Request>,
+    /// The last successful bind command.
+    bind: Arc<Mutex<Option<Pdu>>>,
+    events: UnboundedSender<E::Event>,
+}
+
+impl<E: EventChannel> Inbox<E> {
+    /// Watches the responses of bind and unbind requests to keep track of the last successful bind command.
+    fn observe(&self, action: Action) -> Action {
+        let Action::Request(Request::Registered(mut request)) = action else {
+            return action;
+        };
+
+        let id = request.command.id();
+
+        if !matches!(
+            id,
+            CommandId::BindTransmitter
+                | CommandId::BindReceiver
+                | CommandId::BindTransceiver
+                | CommandId::Unbind
+        ) {
+            return Action::registered_request(request);
+        }
+
+        let (response, observed) = oneshot::channel();
+        let client = std::mem::replace(&mut request.response, response);
+
+        let pdu = request.command.pdu().cloned();
+        let bind = self.bind.clone();
+        let events = E::new(self.events.clone());
+
+        tokio::spawn(async move {
+            // The request was removed or the connection was lost.
+            let Ok(response) = observed.await else {
+                return;
+            };
+
+            if let Ok(command) = &response
+                && command.status() == CommandStatus::EsmeRok
+                && command.id() == id.matching_response()
+            {
+                let pdu = match id {
+                    CommandId::Unbind => None,
+                    _ => pdu,
+                };
+
+                tracing::debug!(target: TARGET, ?id, "Updating bind command");
+
+                *bind.lock().expect("Bind lock poisoned") = pdu;
+            }
+
+            match client.send(response) {
+                Ok(()) => {}
+                // Client not waiting, return the command as an incoming event instead
+                Err(Ok(command)) => {
+                    let _ = events.send_incoming(command);
+                }
+                Err(Err(error)) => {
+                    let _ = events.send_error(error);
+                }
+            }
+        });
+
+        Action::registered_request(request)
+    }
+
+    /// Holds the action until the next session.
+    fn hold(&mut self, action: Action) {
+        match action {
+            Action::Request(request) => self.queue.push_back(request),
+            Action::PendingResponses(pending_responses) => {
+                let _ = pending_responses.ack.send(Ok(Vec::new()));
+            }
+            Action::Remove(_) | Action::Ping => {
+                // No session holds the response, the client is still active.
+            }
+            Action::Close(request) => {
+                // No session to close.
+                let _ = request.ack.send(());
+            }
+            Action::Drain(request) => {
+                // No session holds in-flight requests, the held requests are abandoned on close.
+                let _ = request.ack.send(());
+            }
+            Action::Abandon(request) => {
+                let (kept, unsent): (VecDeque<_>, VecDeque<_>) =
+                    self.queue.drain(..).partition(|request| {
+                        matches!(request, Request::Obligated(_))
+                            || request.command().id().is_response()
+                    });
+
+                self.queue = kept;
+
+                let abandoned = unsent
+                    .into_iter()
+                    .map(|request| {
+                        let id = request.command().id();
+                        let sequence_number = request.command().sequence_number();
+
+                        let _ = request.send_ack(Err(Error::shutting_down(id)));
+
+                        sequence_number
+                    })
+                    .collect();
+
+                let _ = request.ack.send(abandoned);
+            }
+        }
+    }
+
+    /// Drives the future to completion while holding the client's actions.
+    ///
+    /// Returns `None` if the client closed the connection or was dropped in the meantime.
+    async fn until<F: Future>(&mut self, future: F) -> Option<F::Output> {
+        let mut future = std::pin::pin!(future);
+
+        loop {
+            tokio::select! {
+                output = &mut future => return Some(output),
+                action = self.actions.recv() => match action {
+                    None => {
+                        tracing::trace!(target: TARGET, "Client dropped");
+
+                        return None;
+                    }
+                    Some(Action::Close(request)) => {
+                        tracing::debug!(target: TARGET, "Received close");
+
+                        self.actions.close();
+
+                        let _ = request.ack.send(());
+
+                        return None;
+                    }
+                    Some(action) => {
+                        let action = self.observe(action);
+
+                        self.hold(action);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Background task that manages the client's sessions and reconnects when a session is lost.
+pub(crate) struct Reconnector<E: EventChannel> {
+    url: String,
+    builder: ConnectionBuilder<E>,
+    backoff: Backoff,
+    inbox: Inbox<E>,
+    events: E,
+    // Shared with the client and every session
+    session_state: watch::Sender<SessionState>,
+    interface_version: watch::Sender<Option<InterfaceVersion>>,
+    // Used to let the client wait for the connection to be closed
+    watch: watch::Receiver<()>,
+    // Shared with the client, so the rebind does not collide with the client's pending requests
+    sequence_number: SequenceNumber,
+}
+
+impl<E: EventChannel> Reconnector<E> {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        url: String,
+        builder: ConnectionBuilder<E>,
+        backoff: Backoff,
+        actions: UnboundedReceiver<Action>,
+        events: UnboundedSender<E::Event>,
+        session_state: watch::Sender<SessionState>,
+        interface_version: watch::Sender<Option<InterfaceVersion>>,
+        watch: watch::Receiver<()>,
+        sequence_number: SequenceNumber,
+    ) -> Self {
+        Self {
+            url,
+            builder,
+            backoff,
+            inbox: Inbox {
+                actions,
+                queue: VecDeque::new(),
+                bind: Arc::new(Mutex::new(None)),
+                events: events.clone(),
+            },
+            events: E::new(events),
+            session_state,
+            interface_version,
+            watch,
+            sequence_number,
+        }
+    }
+
+    /// Runs the client's sessions, starting with the given stream.
+    pub(crate) async fn run(mut self, stream: MaybeTlsStream<TcpStream>) {
+        let mut session = self.session(stream);
+
+        loop {
+            match self.serve(&mut session).await {
+                Served::Closed => {
+                    // Dropping the actions channel terminates the connection, if not already closing.
+                    drop(session.actions);
+
+                    let _ = session.connection.await;
+
+                    return;
+                }
+                Served::Unbound => {
+                    tracing::debug!(target: TARGET, "Session unbound, not reconnecting");
+
+                    return;
+                }
+                Served::Lost => {
+                    tracing::debug!(target: TARGET, "Connection lost");
+                }
+            }
+
+            match self.reconnect().await {
+                Some(next) => session = next,
+                None => return,
+            }
+        }
+    }
+
+    fn session(&self, stream: MaybeTlsStream<TcpStream>) -> Session {
+        let (actions, connection) = self.builder.session(
+            self.builder.framed(stream),
+            TokioDelay::new(),
+            TokioDelay::new(),
+            E::new(self.inbox.events.clone()),
+            self.session_state.clone(),
+            self.interface_version.clone(),
+            self.watch.clone(),
+        );
+
+        Session {
+            actions,
+            connection: tokio::spawn(connection),
+        }
+    }
+
+    /// Forwards the client's actions to the session until the session is lost or closed.
+    async fn serve(&mut self, session: &mut Session) -> Served {
+        while let Some(request) = self.inbox.queue.pop_front() {
+            if let Err(err) = session.actions.send(Action::Request(request)) {
+                self.inbox.hold(err.0);
+            }
+        }
+
+        loop {
+            tokio::select! {
+                ended = &mut session.connection => {
+                    let Ok(ended) = ended else {
+                        return Served::Lost;
+                    };
+
+                    if ended.session_state == SessionState::Unbound {
+                        return Served::Unbound;
+                    }
+
+                    // Requests that did not reach the server are sent again once the client is rebound.
+                    for action in ended.unprocessed {
+                        self.inbox.hold(action);
+                    }
+
+                    return Served::Lost;
+                }
+                action = self.inbox.actions.recv() => match action {
+                    None => {
+                        tracing::trace!(target: TARGET, "Client dropped");
+
+                        return Served::Closed;
+                    }
+                    Some(Action::Close(request)) => {
+                        tracing::debug!(target: TARGET, "Received close");
+
+                        self.inbox.actions.close();
+
+                        if let Err(err) = session.actions.send(Action::Close(request)) {
+                            self.inbox.hold(err.0);
+                        }
+
+                        return Served::Closed;
+                    }
+                    Some(action) => {
+                        let action = self.inbox.observe(action);
+
+                        if let Err(err) = session.actions.send(action) {
+                            // The connection is terminating, keep the action for the next session.
+                            self.inbox.hold(err.0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reconnects and rebinds using the last successful bind command.
+    ///
+    /// Returns `None` if the client closed the connection, was dropped or the attempts were exhausted.
+    async fn reconnect(&mut self) -> Option<Session> {
+        let mut attempt: u32 = 0;
+
+        loop {
+            attempt = attempt.saturating_add(1);
+
+            if let Some(max_attempts) = self.backoff.max_attempts
+                && attempt > max_attempts
+            {
+                tracing::error!(target: TARGET, max_attempts, "Reconnect attempts exhausted");
+
+                let _ = self.events.send_error(Error::ReconnectAttemptsExhausted {
+                    attempts: max_attempts,
+                });
+
+                return None;
+            }
+
+            let delay = self.backoff.delay(attempt);
+
+            tracing::debug!(target: TARGET, attempt, ?delay, "Reconnecting");
+
+            let _ = self
+                .events
+                .send_reconnect(Reconnect::Reconnecting { attempt, delay });
+
+            self.inbox.until(tokio::time::sleep(delay)).await?;
+
+            let stream = match self.inbox.until(self.builder.dial(&self.url)).await? {
+                Ok(stream) => stream,
+                Err(err) => {
+                    tracing::error!(target: TARGET, attempt, ?err, "Failed to reconnect");
+
+                    let _ = self.events.send_error(err);
+
+                    continue;
+                }
+            };
+
+            let session = self.session(stream);
+
+            tracing::debug!(target: TARGET, attempt, "Reconnected");
+
+            let _ = self
+                .events
+                .send_reconnect(Reconnect::Reconnected { attempt });
+
+            let bind = self.inbox.bind.lock().expect("Bind lock poisoned").clone();
+
+            let Some(bind) = bind else {
+                return Some(session);
+            };
+
+            let rebind = rebind(
+                &session,
+                bind,
+                self.sequence_number.next(),
+                self.builder.response_timeout,
+            );
+
+            match self.inbox.until(rebind).await {
+                Some(Ok(response)) => {
+                    tracing::debug!(target: TARGET, attempt, "Rebound");
+
+                    let _ = self.events.send_reconnect(Reconnect::Rebound { response });
+
+                    return Some(session);
+                }
+                Some(Err(err)) => {
+                    tracing::error!(target: TARGET, attempt, ?err, "Failed to rebind");
+
+                    let _ = self.events.send_error(err);
+
+                    // Dropping the actions channel terminates the connection.
+                    drop(session.actions);
+
+                    let _ = session.connection.await;
+                }
+                None => {
+                    drop(session.actions);
+
+                    let _ = session.connection.await;
+
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Sends the bind command through the session and waits for a successful response.
+async fn rebind(
+    session: &Session,
+    bind: Pdu,
+    sequence_number: u32,
+    response_timeout: Option<Duration>,
+) -> Result<Command, Error> {
+    let command = Command::builder()
+        .status(CommandStatus::EsmeRok)
+        .sequence_number(sequence_number)
+        .pdu(bind);
+
+    let (request, ack, response) = RegisteredRequest::new(command);
+
+    session
+        .actions
+        .send(Action::registered_request(request))
+        .map_err(|_| Error::ConnectionClosed)?;
+
+    ack.await.map_err(|_| Error::ConnectionClosed)??;
+
+    let response = match response_timeout {
+        None => response.await.map_err(|_| Error::ConnectionClosed)??,
+        Some(timeout) => tokio::time::timeout(timeout, response)
+            .await
+            .map_err(|_| Error::response_timeout(sequence_number, timeout))?
+            .map_err(|_| Error::ConnectionClosed)??,
+    };
+
+    if response.status() != CommandStatus::EsmeRok {
+        return Err(Error::unexpected_response(response));
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_without_jitter_grows_exponentially_up_to_max_delay() {
+        let backoff = Backoff {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2,
+            jitter: false,
+            max_attempts: None,
+        };
+
+        let delays = (1..=6)
+            .map(|attempt| backoff.delay(attempt))
+            .collect::<Vec<_>>();
+
+        let expected = [1, 2, 4, 8, 10, 10].map(Duration::from_secs);
+
+        assert_eq!(delays, expected);
+    }
+
+    #[test]
+    fn delay_with_jitter_stays_between_half_and_full_delay() {
+        let backoff = Backoff {
+            initial_delay: Duration::from_secs(4),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2,
+            jitter: true,
+            max_attempts: None,
+        };
+
+        for attempt in 1..=10 {
+            let delay = backoff.delay(attempt);
+            let full = Backoff {
+                jitter: false,
+                ..backoff.clone()
+            }
+            .delay(attempt);
+
+            assert!(delay >= full / 2 && delay <= full, "{delay:?} not in range");
+        }
+    }
+}
@@ -37,6 +37,9 @@ impl Request {
     }
 }
 
+/// A command from the server answering a [`RegisteredRequest`], or the error that replaced it.
+pub type Response = Result<Command, Error>;
+
 #[derive(Debug)]
 pub struct RegisteredRequest {
     pub command: Command,
@@ -46,7 +49,8 @@ pub struct RegisteredRequest {
     ///
     /// The background connection can only pass commands from the server with a matching sequence number without any validation.
     /// It's the client's responsibility to handle error commands.
-    pub response: oneshot::Sender<Command>,
+    /// A response that could not be decoded fails the request with [`Error::MalformedCommand`].
+    pub response: oneshot::Sender<Response>,
 }
 
 impl RegisteredRequest {
@@ -55,7 +59,7 @@ impl RegisteredRequest {
     ) -> (
         Self,
         oneshot::Receiver<Result<(), Error>>,
-        oneshot::Receiver<Command>,
+        oneshot::Receiver<Response>,
     ) {
         let (ack, ack_rx) = oneshot::channel();
         let (response, response_rx) = oneshot::channel();
@@ -111,3 +115,31 @@ impl CloseRequest {
         (Self { ack }, rx)
     }
 }
+
+#[derive(Debug)]
+pub struct DrainRequest {
+    /// ack result means that the connection has no more in-flight requests.
+    pub ack: oneshot::Sender<()>,
+}
+
+impl DrainRequest {
+    pub fn new() -> (Self, oneshot::Receiver<()>) {
+        let (ack, rx) = oneshot::channel();
+
+        (Self { ack }, rx)
+    }
+}
+
+#[derive(Debug)]
+pub struct AbandonRequest {
+    /// ack result contains the sequence numbers of the abandoned requests.
+    pub ack: oneshot::Sender<Vec<u32>>,
+}
+
+impl AbandonRequest {
+    pub fn new() -> (Self, oneshot::Receiver<Vec<u32>>) {
+        let (ack, rx) = oneshot::channel();
+
+        (Self { ack }, rx)
+    }
+}
@@ -14,6 +14,8 @@ pub enum MaybeTlsStream<S> {
     Plain(S),
     #[cfg(feature = "rustls")]
     Rustls(Box<tokio_rustls::client::TlsStream<S>>),
+    #[cfg(feature = "rustls")]
+    RustlsServer(Box<tokio_rustls::server::TlsStream<S>>),
     #[cfg(feature = "native-tls")]
     NativeTls(Box<tokio_native_tls::TlsStream<S>>),
 }
@@ -97,6 +99,43 @@ impl<S: AsyncRead + AsyncWrite + Unpin> MaybeTlsStream<S> {
         Ok(Self::Rustls(Box::new(stream)))
     }
 
+    /// Creates a new [`MaybeTlsStream::RustlsServer`] by accepting a TLS connection.
+    #[cfg(feature = "rustls")]
+    pub async fn rustls_server(
+        stream: S,
+        config: std::sync::Arc<rustls::ServerConfig>,
+    ) -> Result<Self, crate::error::Error> {
+        let acceptor = tokio_rustls::TlsAcceptor::from(config);
+
+        tracing::debug!(target: "rusmppc::connection::tls::rustls", "Accepting TLS connection");
+
+        let stream = acceptor
+            .accept(stream)
+            .await
+            .map_err(crate::error::Error::Connect)?;
+
+        Ok(Self::RustlsServer(Box::new(stream)))
+    }
+
+    /// Creates a new [`MaybeTlsStream::NativeTls`] by accepting a TLS connection.
+    #[cfg(feature = "native-tls")]
+    pub async fn native_tls_server(
+        stream: S,
+        acceptor: native_tls::TlsAcceptor,
+    ) -> Result<Self, crate::error::Error> {
+        let acceptor = tokio_native_tls::TlsAcceptor::from(acceptor);
+
+        tracing::debug!(target: "rusmppc::connection::tls::native-tls", "Accepting TLS connection");
+
+        let stream = acceptor
+            .accept(stream)
+            .await
+            .map_err(std::io::Error::other)
+            .map_err(crate::error::Error::Connect)?;
+
+        Ok(Self::NativeTls(Box::new(stream)))
+    }
+
     /// Creates a new [`MaybeTlsStream::NativeTls`].
     #[cfg(feature = "native-tls")]
     pub async fn native_tls(
@@ -135,6 +174,8 @@ impl<S: AsyncRead + AsyncWrite + Unpin> AsyncRead for MaybeTlsStream<S> {
             MaybeTlsStream::Plain(s) => Pin::new(s).poll_read(cx, buf),
             #[cfg(feature = "rustls")]
             MaybeTlsStream::Rustls(s) => Pin::new(s).poll_read(cx, buf),
+            #[cfg(feature = "rustls")]
+            MaybeTlsStream::RustlsServer(s) => Pin::new(s).poll_read(cx, buf),
             #[cfg(feature = "native-tls")]
             MaybeTlsStream::NativeTls(s) => Pin::new(s).poll_read(cx, buf),
         }
@@ -151,6 +192,8 @@ impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for MaybeTlsStream<S> {
             MaybeTlsStream::Plain(s) => Pin::new(s).poll_write(cx, buf),
             #[cfg(feature = "rustls")]
             MaybeTlsStream::Rustls(s) => Pin::new(s).poll_write(cx, buf),
+            #[cfg(feature = "rustls")]
+            MaybeTlsStream::RustlsServer(s) => Pin::new(s).poll_write(cx, buf),
             #[cfg(feature = "native-tls")]
             MaybeTlsStream::NativeTls(s) => Pin::new(s).poll_write(cx, buf),
         }
@@ -161,6 +204,8 @@ impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for MaybeTlsStream<S> {
             MaybeTlsStream::Plain(s) => Pin::new(s).poll_flush(cx),
             #[cfg(feature = "rustls")]
             MaybeTlsStream::Rustls(s) => Pin::new(s).poll_flush(cx),
+            #[cfg(feature = "rustls")]
+            MaybeTlsStream::RustlsServer(s) => Pin::new(s).poll_flush(cx),
             #[cfg(feature = "native-tls")]
             MaybeTlsStream::NativeTls(s) => Pin::new(s).poll_flush(cx),
         }
@@ -174,6 +219,8 @@ impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for MaybeTlsStream<S> {
             MaybeTlsStream::Plain(s) => Pin::new(s).poll_shutdown(cx),
             #[cfg(feature = "rustls")]
             MaybeTlsStream::Rustls(s) => Pin::new(s).poll_shutdown(cx),
+            #[cfg(feature = "rustls")]
+            MaybeTlsStream::RustlsServer(s) => Pin::new(s).poll_shutdown(cx),
             #[cfg(feature = "native-tls")]
             MaybeTlsStream::NativeTls(s) => Pin::new(s).poll_shutdown(cx),
         }
@@ -5,8 +5,9 @@
 //! For more in depth tests, see `connection/tests.rs`.
 
 use std::{
+    str::FromStr,
     sync::{
-        Arc,
+        Arc, Mutex,
         atomic::{AtomicBool, Ordering},
     },
     task::Poll,
@@ -17,15 +18,24 @@ use futures::{SinkExt, StreamExt};
 use rusmpp::{
     Command, CommandId, CommandStatus, Pdu,
     pdus::{
-        AlertNotification, BindReceiverResp, BindTransceiverResp, BindTransmitterResp, SubmitSm,
-        SubmitSmResp,
+        AlertNotification, BindReceiver, BindReceiverResp, BindTransceiver, BindTransceiverResp,
+        BindTransmitter, BindTransmitterResp, BroadcastSm, DataSm, DeliverSm, DeliverSmResp,
+        Outbind, ReplaceSm, SubmitSm, SubmitSmResp,
     },
-    tokio_codec::CommandCodec,
+    session::SessionState,
+    tlvs::{MessageSubmissionRequestTlvValue, TlvTag},
+    tokio_codec::{CommandCodec, DecodeError},
+    types::{AnyOctetString, COctetString, OctetString},
+    validate::Violation,
+    values::{InterfaceVersion, MessagePayload},
 };
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio_util::codec::Framed;
 
-use crate::{ConnectionBuilder, Event, Insight, InsightEvent, error::Error, mock::io::MockIo};
+use crate::{
+    AutoResponse, Balance, ConnectionBuilder, Event, Insight, InsightEvent, PoolEvent, RateLimit,
+    Reconnect, ThrottleRetry, error::Error, mock::io::MockIo,
+};
 
 #[derive(Debug)]
 pub struct Server {
@@ -203,6 +213,7 @@ async fn cancel_request_future_should_remove_pending_response() {
     });
 
     let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .response_timeout(Duration::from_millis(1000))
         .connected(client);
 
@@ -256,6 +267,7 @@ async fn request_timeout_should_remove_pending_response() {
     });
 
     let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .response_timeout(Duration::from_millis(500))
         .connected(client);
 
@@ -303,6 +315,7 @@ async fn no_wait_request_should_pipe_response_through_events() {
     });
 
     let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .response_timeout(Duration::from_millis(1000))
         .connected(client);
 
@@ -355,7 +368,9 @@ async fn drop_events_should_not_close_connection() {
         Server::new().run(server).await;
     });
 
-    let (client, _) = ConnectionBuilder::new().connected(client);
+    let (client, _) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .connected(client);
 
     client
         .submit_sm(SubmitSm::default())
@@ -456,6 +471,7 @@ async fn enquire_link_timeout_busy_sequential_should_close_connection() {
     });
 
     let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .enquire_link_interval(Duration::from_secs(2))
         .enquire_link_response_timeout(Duration::from_secs(1))
         .connected(client);
@@ -494,6 +510,7 @@ async fn enquire_link_timeout_busy_concurrent_should_close_connection() {
     });
 
     let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .enquire_link_interval(Duration::from_secs(2))
         .enquire_link_response_timeout(Duration::from_secs(1))
         .connected(client);
@@ -640,6 +657,7 @@ async fn server_sends_an_operation_with_the_same_sequence_number_of_a_pending_re
     });
 
     let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .response_timeout(Duration::from_millis(500))
         .connected(client);
 
@@ -701,6 +719,7 @@ async fn server_ddos_client_should_still_send_requests_and_connection_should_sti
     });
 
     let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
         .enquire_link_interval(Duration::from_secs(1))
         .enquire_link_response_timeout(Duration::from_millis(500))
         .response_timeout(Duration::from_millis(500))
@@ -931,3 +950,1978 @@ async fn received_enquire_link_and_sent_enquire_link_response_should_be_sent_thr
 
     assert_eq!(events, expected_events);
 }
+
+#[tokio::test]
+async fn reconnect_should_rebind_after_connection_lost() {
+    init_tracing();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .close_connection_delay(Duration::from_millis(500))
+            .run(stream)
+            .await;
+
+        let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(50))
+            .run(stream)
+            .await;
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .enquire_link_interval(Duration::from_secs(10))
+        .reconnect()
+        .initial_delay(Duration::from_millis(100))
+        .disable_jitter()
+        .connect(format!("smpp://{addr}"))
+        .await
+        .expect("Failed to connect");
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    let mut reconnecting = false;
+    let mut reconnected = false;
+
+    loop {
+        match events.next().await.expect("Expected event") {
+            Event::Reconnect(Reconnect::Reconnecting { attempt, .. }) => {
+                assert_eq!(attempt, 1);
+
+                reconnecting = true;
+            }
+            Event::Reconnect(Reconnect::Reconnected { attempt }) => {
+                assert_eq!(attempt, 1);
+
+                reconnected = true;
+            }
+            Event::Reconnect(Reconnect::Rebound { response }) => {
+                assert!(matches!(response.id(), CommandId::BindTransceiverResp));
+
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    assert!(reconnecting, "Expected reconnecting event");
+    assert!(reconnected, "Expected reconnected event");
+
+    client
+        .submit_sm(SubmitSm::default())
+        .await
+        .expect("Failed to submit SM after reconnect");
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn reconnect_should_send_unsent_requests_after_rebind() {
+    init_tracing();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_secs(5))
+            .close_connection_delay(Duration::from_millis(500))
+            .run(stream)
+            .await;
+
+        let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(50))
+            .run(stream)
+            .await;
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .enquire_link_interval(Duration::from_secs(10))
+        .command_rate_limit(
+            CommandId::SubmitSm,
+            RateLimit::new(1, Duration::from_secs(5)),
+        )
+        .reconnect()
+        .initial_delay(Duration::from_millis(100))
+        .disable_jitter()
+        .connect(format!("smpp://{addr}"))
+        .await
+        .expect("Failed to connect");
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    let sent = tokio::spawn({
+        let client = client.clone();
+
+        async move { client.submit_sm(SubmitSm::default()).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // Held back by the rate limiter when the connection is lost.
+    let unsent = tokio::spawn({
+        let client = client.clone();
+
+        async move { client.submit_sm(SubmitSm::default()).await }
+    });
+
+    let sent = sent.await.expect("Failed to join");
+
+    assert!(
+        matches!(sent, Err(Error::ConnectionClosed)),
+        "Expected ConnectionClosed, got {sent:?}"
+    );
+
+    unsent
+        .await
+        .expect("Failed to join")
+        .expect("Failed to submit SM after reconnect");
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn reconnect_should_rebind_with_the_next_sequence_number() {
+    init_tracing();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    let sequence_numbers = Arc::new(Mutex::new(Vec::new()));
+
+    tokio::spawn({
+        let sequence_numbers = sequence_numbers.clone();
+
+        async move {
+            // The first connection leaves the submit sm in flight and is lost.
+            for (respond_to_submit_sm, close_connection_delay) in [
+                (false, Duration::from_millis(300)),
+                (true, Duration::from_secs(10)),
+            ] {
+                let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+                let mut framed = Framed::new(stream, CommandCodec::new());
+
+                let future = async {
+                    while let Some(Ok(command)) = framed.next().await {
+                        sequence_numbers
+                            .lock()
+                            .expect("Sequence numbers lock poisoned")
+                            .push(command.sequence_number());
+
+                        let pdu: Pdu = match command.id() {
+                            CommandId::BindTransceiver => BindTransceiverResp::default().into(),
+                            CommandId::SubmitSm if respond_to_submit_sm => {
+                                SubmitSmResp::default().into()
+                            }
+                            _ => continue,
+                        };
+
+                        let response = Command::builder()
+                            .status(CommandStatus::EsmeRok)
+                            .sequence_number(command.sequence_number())
+                            .pdu(pdu);
+
+                        framed.send(response).await.expect("Failed to send");
+                    }
+                };
+
+                let _ = tokio::time::timeout(close_connection_delay, future).await;
+            }
+        }
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .enquire_link_interval(Duration::from_secs(10))
+        .reconnect()
+        .initial_delay(Duration::from_millis(100))
+        .disable_jitter()
+        .connect(format!("smpp://{addr}"))
+        .await
+        .expect("Failed to connect");
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    let in_flight = tokio::spawn({
+        let client = client.clone();
+
+        async move { client.submit_sm(SubmitSm::default()).await }
+    });
+
+    let mut held = None;
+
+    let rebind_sequence_number = loop {
+        match events.next().await.expect("Expected event") {
+            Event::Reconnect(Reconnect::Reconnecting { .. }) => {
+                // Held until the client is rebound.
+                held = Some(tokio::spawn({
+                    let client = client.clone();
+
+                    async move { client.submit_sm(SubmitSm::default()).await }
+                }));
+            }
+            Event::Reconnect(Reconnect::Rebound { response }) => {
+                break response.sequence_number();
+            }
+            _ => {}
+        }
+    };
+
+    let in_flight = in_flight.await.expect("Failed to join");
+
+    assert!(
+        matches!(in_flight, Err(Error::ConnectionClosed)),
+        "Expected ConnectionClosed, got {in_flight:?}"
+    );
+
+    held.expect("Expected reconnecting event")
+        .await
+        .expect("Failed to join")
+        .expect("Failed to submit SM after reconnect");
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+
+    let _ = events.count().await;
+
+    let sequence_numbers = sequence_numbers
+        .lock()
+        .expect("Sequence numbers lock poisoned")
+        .clone();
+
+    assert_eq!(rebind_sequence_number, 7);
+
+    // bind, in flight submit sm, rebind, held submit sm.
+    assert_eq!(sequence_numbers, vec![1, 3, 7, 5]);
+}
+
+#[tokio::test]
+async fn reconnect_should_not_reconnect_after_server_unbinds() {
+    init_tracing();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    let accepted = Arc::new(AtomicBool::new(false));
+
+    tokio::spawn({
+        let accepted = accepted.clone();
+
+        async move {
+            let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+            let mut framed = Framed::new(stream, CommandCodec::new());
+
+            while let Some(Ok(command)) = framed.next().await {
+                match command.id() {
+                    CommandId::BindTransceiver => {
+                        let response = Command::builder()
+                            .status(CommandStatus::EsmeRok)
+                            .sequence_number(command.sequence_number())
+                            .pdu(BindTransceiverResp::default());
+
+                        framed.send(response).await.expect("Failed to send");
+
+                        let unbind = Command::builder()
+                            .status(CommandStatus::EsmeRok)
+                            .sequence_number(1)
+                            .pdu(Pdu::Unbind);
+
+                        framed.send(unbind).await.expect("Failed to send");
+                    }
+                    CommandId::UnbindResp => break,
+                    _ => {}
+                }
+            }
+
+            drop(framed);
+
+            if listener.accept().await.is_ok() {
+                accepted.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .enable_auto_unbind_response()
+        .reconnect()
+        .initial_delay(Duration::from_millis(50))
+        .disable_jitter()
+        .connect(format!("smpp://{addr}"))
+        .await
+        .expect("Failed to connect");
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    tokio::time::timeout(Duration::from_secs(2), client.closed())
+        .await
+        .expect("Connection not closed after unbind");
+
+    let events = events.collect::<Vec<_>>().await;
+
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, Event::Reconnect(_))),
+        "Unexpected reconnect event"
+    );
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    assert!(!accepted.load(Ordering::Relaxed), "Client reconnected");
+}
+
+async fn pool_listener(servers: Vec<Server>) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    tokio::spawn(async move {
+        for server in servers {
+            let (stream, _) = listener.accept().await.expect("Failed to accept");
+
+            tokio::spawn(server.run(stream));
+        }
+    });
+
+    format!("smpp://{addr}")
+}
+
+#[tokio::test]
+async fn pool_should_distribute_requests_round_robin() {
+    init_tracing();
+
+    let url = pool_listener(vec![
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(500)),
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(500)),
+    ])
+    .await;
+
+    let (pool, events) = ConnectionBuilder::new()
+        .pool()
+        .size(2)
+        .connect([url])
+        .await
+        .expect("Failed to connect pool");
+
+    assert_eq!(pool.size(), 2);
+    assert_eq!(pool.clients().len(), 2);
+
+    let first = pool.client().await.expect("Expected client");
+
+    let submit = tokio::spawn({
+        let first = first.clone();
+
+        async move { first.submit_sm(SubmitSm::default()).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let second = pool.client().await.expect("Expected client");
+
+    assert_eq!(first.pending_responses().await.expect("Closed").len(), 1);
+    assert!(second.pending_responses().await.expect("Closed").is_empty());
+
+    submit
+        .await
+        .expect("Failed to join")
+        .expect("Failed to submit SM");
+
+    pool.close().await;
+
+    assert!(pool.is_closed());
+    assert!(matches!(pool.client().await, Err(Error::NoClientAvailable)));
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn pool_should_pick_least_pending_client() {
+    init_tracing();
+
+    let url = pool_listener(vec![
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(500)),
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(500)),
+    ])
+    .await;
+
+    let (pool, events) = ConnectionBuilder::new()
+        .pool()
+        .size(2)
+        .balance(Balance::LeastPending)
+        .connect([url])
+        .await
+        .expect("Failed to connect pool");
+
+    let first = pool.client().await.expect("Expected client");
+    let _ = pool.client().await.expect("Expected client");
+
+    let submit = tokio::spawn({
+        let first = first.clone();
+
+        async move { first.submit_sm(SubmitSm::default()).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // Round robin would pick the first client again.
+    let least = pool.client().await.expect("Expected client");
+
+    assert!(least.pending_responses().await.expect("Closed").is_empty());
+
+    submit
+        .await
+        .expect("Failed to join")
+        .expect("Failed to submit SM");
+
+    pool.close().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn pool_should_replace_closed_client() {
+    init_tracing();
+
+    let url = pool_listener(vec![
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .close_connection_delay(Duration::from_millis(300)),
+        Server::new().bind_delay(Duration::from_millis(50)),
+        Server::new().bind_delay(Duration::from_millis(50)),
+    ])
+    .await;
+
+    let (pool, mut events) = ConnectionBuilder::new()
+        .pool()
+        .size(2)
+        .initial_delay(Duration::from_millis(100))
+        .connect([url])
+        .await
+        .expect("Failed to connect pool");
+
+    let mut removed = false;
+
+    loop {
+        match events.next().await.expect("Expected event") {
+            PoolEvent::Removed { slot } => {
+                assert_eq!(slot, 0);
+
+                removed = true;
+            }
+            PoolEvent::Replaced { slot, attempt } => {
+                assert_eq!(slot, 0);
+                assert_eq!(attempt, 1);
+
+                break;
+            }
+            PoolEvent::ReplaceFailed { error, .. } => panic!("Failed to replace: {error}"),
+            _ => {}
+        }
+    }
+
+    assert!(removed, "Expected removed event");
+    assert_eq!(pool.clients().len(), 2);
+
+    for _ in 0..2 {
+        pool.client()
+            .await
+            .expect("Expected client")
+            .submit_sm(SubmitSm::default())
+            .await
+            .expect("Failed to submit SM");
+    }
+
+    pool.close().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn session_state_should_follow_bind_and_reject_invalid_requests() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(50))
+            .run(server)
+            .await;
+    });
+
+    let (client, events) = ConnectionBuilder::new().connected(client);
+
+    assert_eq!(client.session_state(), SessionState::Open);
+
+    let Error::InvalidSessionState { id, state } =
+        client.submit_sm(SubmitSm::default()).await.unwrap_err()
+    else {
+        panic!("Expected invalid session state error");
+    };
+
+    assert_eq!(id, CommandId::SubmitSm);
+    assert_eq!(state, SessionState::Open);
+
+    client
+        .bind_receiver(BindReceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    assert_eq!(client.session_state(), SessionState::BoundRx);
+
+    let Error::InvalidSessionState { id, state } =
+        client.submit_sm(SubmitSm::default()).await.unwrap_err()
+    else {
+        panic!("Expected invalid session state error");
+    };
+
+    assert_eq!(id, CommandId::SubmitSm);
+    assert_eq!(state, SessionState::BoundRx);
+
+    client.unbind().await.expect("Failed to unbind");
+
+    assert_eq!(client.session_state(), SessionState::Unbound);
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+
+    assert_eq!(client.session_state(), SessionState::Closed);
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn command_validation_should_reject_invalid_requests() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(50))
+            .run(server)
+            .await;
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .enable_command_validation()
+        .connected(client);
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    let Error::InvalidCommand { id, violations } = client
+        .submit_sm(
+            SubmitSm::builder()
+                .short_message(OctetString::from_static_slice(b"Short Message").unwrap())
+                .push_tlv(MessageSubmissionRequestTlvValue::MessagePayload(
+                    MessagePayload::new(AnyOctetString::from_static_slice(b"Message Payload")),
+                ))
+                .build(),
+        )
+        .await
+        .unwrap_err()
+    else {
+        panic!("Expected invalid command error");
+    };
+
+    assert_eq!(id, CommandId::SubmitSm);
+    assert_eq!(violations, [Violation::ShortMessageAndMessagePayload]);
+
+    client
+        .submit_sm(
+            SubmitSm::builder()
+                .short_message(OctetString::from_static_slice(b"Short Message").unwrap())
+                .build(),
+        )
+        .await
+        .expect("Failed to submit valid command");
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn server_sends_an_operation_in_an_invalid_session_state_should_be_nacked() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        // The client is not bound yet
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(AlertNotification::default()),
+            )
+            .await
+            .expect("Failed to send AlertNotification");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::GenericNack));
+        assert!(matches!(command.status(), CommandStatus::EsmeRinvbndsts));
+        assert_eq!(command.sequence_number(), 1);
+    });
+
+    let (client, mut events) = ConnectionBuilder::new().connected(client);
+
+    let Some(Event::Error(Error::UnexpectedCommand { command, state })) = events.next().await
+    else {
+        panic!("Expected unexpected command error");
+    };
+
+    assert!(matches!(command.id(), CommandId::AlertNotification));
+    assert_eq!(state, SessionState::Open);
+
+    server.await.expect("Server failed");
+
+    // Server closed the connection
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn immediate_auto_response_should_acknowledge_operations() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(DeliverSm::default()),
+            )
+            .await
+            .expect("Failed to send DeliverSm");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::DeliverSmResp));
+        assert!(matches!(command.status(), CommandStatus::EsmeRok));
+        assert_eq!(command.sequence_number(), 1);
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .auto_response(AutoResponse::Immediate)
+        .connected(client);
+
+    let Some(Event::Incoming(command)) = events.next().await else {
+        panic!("Expected command event");
+    };
+
+    assert!(matches!(command.id(), CommandId::DeliverSm));
+
+    server.await.expect("Server failed");
+
+    // Server closed the connection
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn after_handler_auto_response_should_respond_to_expired_operations() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(DeliverSm::default()),
+            )
+            .await
+            .expect("Failed to send DeliverSm");
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(3)
+                    .pdu(DataSm::default()),
+            )
+            .await
+            .expect("Failed to send DataSm");
+
+        // The client responds to the DeliverSm
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::DeliverSmResp));
+        assert!(matches!(command.status(), CommandStatus::EsmeRok));
+        assert_eq!(command.sequence_number(), 1);
+
+        // The connection responds to the DataSm after the timeout
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::DataSmResp));
+        assert!(matches!(command.status(), CommandStatus::EsmeRxTAppn));
+        assert_eq!(command.sequence_number(), 3);
+
+        // No more responses
+        let next = tokio::time::timeout(Duration::from_millis(500), framed.next()).await;
+
+        assert!(next.is_err());
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .auto_response(AutoResponse::after_handler(
+            Duration::from_millis(200),
+            CommandStatus::EsmeRxTAppn,
+        ))
+        .connected(client);
+
+    let Some(Event::Incoming(command)) = events.next().await else {
+        panic!("Expected command event");
+    };
+
+    assert!(matches!(command.id(), CommandId::DeliverSm));
+
+    client
+        .deliver_sm_resp(command.sequence_number(), DeliverSmResp::default())
+        .await
+        .expect("Failed to send DeliverSmResp");
+
+    let Some(Event::Incoming(command)) = events.next().await else {
+        panic!("Expected command event");
+    };
+
+    assert!(matches!(command.id(), CommandId::DataSm));
+
+    server.await.expect("Server failed");
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn auto_unbind_response_should_respond_and_close_connection() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(Pdu::Unbind),
+            )
+            .await
+            .expect("Failed to send Unbind");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::UnbindResp));
+        assert!(matches!(command.status(), CommandStatus::EsmeRok));
+        assert_eq!(command.sequence_number(), 1);
+
+        // The client closed the connection
+        assert!(framed.next().await.is_none());
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .enable_auto_unbind_response()
+        .connected(client);
+
+    let Some(Event::Incoming(command)) = events.next().await else {
+        panic!("Expected command event");
+    };
+
+    assert!(matches!(command.id(), CommandId::Unbind));
+
+    client.closed().await;
+
+    assert_eq!(client.session_state(), SessionState::Closed);
+
+    let error = client.submit_sm(SubmitSm::default()).await.unwrap_err();
+
+    assert!(matches!(error, Error::ConnectionClosed));
+
+    server.await.expect("Server failed");
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn shutdown_should_drain_in_flight_requests_and_unbind() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        Server::new()
+            .bind_delay(Duration::from_millis(50))
+            .response_delay(Duration::from_millis(300))
+            .run(server)
+            .await;
+    });
+
+    let (client, events) = ConnectionBuilder::new().connected(client);
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    let submit_sm = tokio::spawn({
+        let client = client.clone();
+
+        async move { client.submit_sm(SubmitSm::default()).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let shutdown = tokio::spawn({
+        let client = client.clone();
+
+        async move { client.shutdown(Duration::from_secs(2)).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let error = client.submit_sm(SubmitSm::default()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::ShuttingDown {
+            id: CommandId::SubmitSm
+        }
+    ));
+
+    submit_sm
+        .await
+        .unwrap()
+        .expect("In-flight request should be answered");
+
+    let abandoned = shutdown.await.unwrap().expect("Failed to shutdown");
+
+    assert!(abandoned.is_empty());
+    assert!(client.is_closed());
+    assert_eq!(client.session_state(), SessionState::Closed);
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn shutdown_timeout_should_abandon_pending_responses() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        while let Some(Ok(command)) = framed.next().await {
+            let pdu: Pdu = match command.id() {
+                CommandId::BindTransceiver => BindTransceiverResp::default().into(),
+                CommandId::Unbind => Pdu::UnbindResp,
+                // The server never responds to submit_sm
+                _ => continue,
+            };
+
+            framed
+                .send(
+                    Command::builder()
+                        .status(CommandStatus::EsmeRok)
+                        .sequence_number(command.sequence_number())
+                        .pdu(pdu),
+                )
+                .await
+                .expect("Failed to send response");
+        }
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .no_response_timeout()
+        .connected(client);
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    let submit_sm = tokio::spawn({
+        let client = client.clone();
+
+        async move { client.submit_sm(SubmitSm::default()).await }
+    });
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let pending = client
+        .pending_responses()
+        .await
+        .expect("Failed to get pending responses");
+
+    assert_eq!(pending.len(), 1);
+
+    let abandoned = client
+        .shutdown(Duration::from_millis(200))
+        .await
+        .expect("Failed to shutdown");
+
+    assert_eq!(abandoned, pending);
+
+    let error = submit_sm.await.unwrap().unwrap_err();
+
+    assert!(matches!(error, Error::ConnectionClosed));
+
+    server.await.expect("Server failed");
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn shutdown_timeout_should_abandon_unsent_requests() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let mut submitted = Vec::new();
+
+        while let Some(Ok(command)) = framed.next().await {
+            let pdu: Pdu = match command.id() {
+                CommandId::BindTransceiver => BindTransceiverResp::default().into(),
+                CommandId::Unbind => Pdu::UnbindResp,
+                // The server never responds to submit_sm
+                _ => {
+                    submitted.push(command.sequence_number());
+
+                    continue;
+                }
+            };
+
+            framed
+                .send(
+                    Command::builder()
+                        .status(CommandStatus::EsmeRok)
+                        .sequence_number(command.sequence_number())
+                        .pdu(pdu),
+                )
+                .await
+                .expect("Failed to send response");
+        }
+
+        submitted
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .no_response_timeout()
+        .window(2)
+        .command_rate_limit(
+            CommandId::SubmitSm,
+            RateLimit::new(1, Duration::from_secs(60)),
+        )
+        .connected(client);
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    // The first submit_sm is sent, the second one is held back by the rate limiter and fills the window
+    let submit_sms = (0..2)
+        .map(|_| {
+            let client = client.clone();
+
+            tokio::spawn(async move { client.submit_sm(SubmitSm::default()).await })
+        })
+        .collect::<Vec<_>>();
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(client.in_flight(), 2);
+
+    let pending = client
+        .pending_responses()
+        .await
+        .expect("Failed to get pending responses");
+
+    assert_eq!(pending.len(), 1);
+
+    let abandoned = client
+        .shutdown(Duration::from_millis(200))
+        .await
+        .expect("Failed to shutdown");
+
+    assert_eq!(abandoned.len(), 2);
+    assert_eq!(abandoned[0], pending[0]);
+
+    let mut errors = Vec::new();
+
+    for submit_sm in submit_sms {
+        errors.push(submit_sm.await.unwrap().unwrap_err());
+    }
+
+    assert!(
+        errors
+            .iter()
+            .any(|error| matches!(error, Error::ConnectionClosed))
+    );
+    assert!(errors.iter().any(|error| matches!(
+        error,
+        Error::ShuttingDown {
+            id: CommandId::SubmitSm
+        }
+    )));
+
+    // The abandoned request was never sent
+    let submitted = server.await.expect("Server failed");
+
+    assert_eq!(submitted, pending);
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn server_sends_an_unsupported_command_should_be_nacked() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(7)
+                    .pdu(Pdu::Other {
+                        command_id: CommandId::Other(0x00000999),
+                        body: AnyOctetString::from_static_slice(b"Body"),
+                    }),
+            )
+            .await
+            .expect("Failed to send unsupported command");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::GenericNack));
+        assert!(matches!(command.status(), CommandStatus::EsmeRinvcmdid));
+        assert_eq!(command.sequence_number(), 7);
+    });
+
+    let (client, mut events) = ConnectionBuilder::new().connected(client);
+
+    let Some(Event::Error(Error::UnsupportedCommand { command })) = events.next().await else {
+        panic!("Expected unsupported command error");
+    };
+
+    assert_eq!(command.id(), CommandId::Other(0x00000999));
+
+    server.await.expect("Server failed");
+
+    // Server closed the connection
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn server_nacks_a_request_should_fail_with_generic_nack() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRinvcmdlen)
+                    .sequence_number(command.sequence_number())
+                    .pdu(Pdu::GenericNack),
+            )
+            .await
+            .expect("Failed to send GenericNack");
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .connected(client);
+
+    let error = client.submit_sm(SubmitSm::default()).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::GenericNack {
+            sequence_number: 1,
+            status: CommandStatus::EsmeRinvcmdlen
+        }
+    ));
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn server_sends_a_malformed_command_should_be_nacked_and_close_connection() {
+    init_tracing();
+
+    let (mut server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        // command_length is less than the header length
+        let mut malformed = [0u8; 16];
+        malformed[..4].copy_from_slice(&8u32.to_be_bytes());
+
+        server
+            .write_all(&malformed)
+            .await
+            .expect("Failed to send malformed command");
+
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::GenericNack));
+        assert!(matches!(command.status(), CommandStatus::EsmeRinvcmdlen));
+        assert_eq!(command.sequence_number(), 0);
+
+        // The client closed the connection
+        assert!(framed.next().await.is_none());
+    });
+
+    let (client, mut events) = ConnectionBuilder::new().connected(client);
+
+    let Some(Event::Error(Error::Decode(DecodeError::MinLength { actual: 8, .. }))) =
+        events.next().await
+    else {
+        panic!("Expected decode error");
+    };
+
+    client.closed().await;
+
+    server.await.expect("Server failed");
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn server_sends_a_command_with_a_malformed_body_should_be_nacked_and_keep_connection() {
+    init_tracing();
+
+    let (mut server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        // A deliver_sm with a valid header and a body that is not null terminated
+        let mut malformed = Vec::new();
+        malformed.extend_from_slice(&19u32.to_be_bytes());
+        malformed.extend_from_slice(&u32::from(CommandId::DeliverSm).to_be_bytes());
+        malformed.extend_from_slice(&u32::from(CommandStatus::EsmeRok).to_be_bytes());
+        malformed.extend_from_slice(&5u32.to_be_bytes());
+        malformed.extend_from_slice(b"abc");
+
+        server
+            .write_all(&malformed)
+            .await
+            .expect("Failed to send malformed command");
+
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::GenericNack));
+        assert!(matches!(command.status(), CommandStatus::EsmeRsyserr));
+        assert_eq!(command.sequence_number(), 5);
+
+        // The connection is still running
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::SubmitSm));
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(command.sequence_number())
+                    .pdu(SubmitSmResp::default()),
+            )
+            .await
+            .expect("Failed to send SubmitSmResp");
+
+        // The client closed the connection
+        assert!(framed.next().await.is_none());
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .connected(client);
+
+    let Some(Event::Error(Error::MalformedCommand(malformed))) = events.next().await else {
+        panic!("Expected malformed command error");
+    };
+
+    assert_eq!(malformed.id(), CommandId::DeliverSm);
+    assert_eq!(malformed.sequence_number(), 5);
+    assert_eq!(&malformed.body()[..], b"abc");
+
+    client
+        .submit_sm(SubmitSm::default())
+        .await
+        .expect("Failed to submit SM");
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    server.await.expect("Server failed");
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn server_sends_a_malformed_response_should_fail_the_request() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::SubmitSm));
+
+        // A submit_sm_resp with a valid header and a message_id that is not null terminated
+        let mut malformed = Vec::new();
+        malformed.extend_from_slice(&19u32.to_be_bytes());
+        malformed.extend_from_slice(&u32::from(CommandId::SubmitSmResp).to_be_bytes());
+        malformed.extend_from_slice(&u32::from(CommandStatus::EsmeRok).to_be_bytes());
+        malformed.extend_from_slice(&command.sequence_number().to_be_bytes());
+        malformed.extend_from_slice(b"abc");
+
+        framed
+            .get_mut()
+            .write_all(&malformed)
+            .await
+            .expect("Failed to send malformed response");
+
+        // Malformed responses are not answered
+        assert!(framed.next().await.is_none());
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .no_response_timeout()
+        .connected(client);
+
+    let Err(Error::MalformedCommand(malformed)) = client.submit_sm(SubmitSm::default()).await
+    else {
+        panic!("Expected malformed command error");
+    };
+
+    assert_eq!(malformed.id(), CommandId::SubmitSmResp);
+    assert_eq!(&malformed.body()[..], b"abc");
+
+    let pending = client
+        .pending_responses()
+        .await
+        .expect("Failed to get pending responses");
+
+    assert!(pending.is_empty());
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    server.await.expect("Server failed");
+
+    // The error went to the request, not to the event stream
+    let errors = events
+        .filter(|event| std::future::ready(matches!(event, Event::Error(_))))
+        .count()
+        .await;
+
+    assert_eq!(errors, 0);
+}
+
+#[tokio::test]
+async fn full_window_should_delay_requests() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        Server::new()
+            .response_delay(Duration::from_millis(200))
+            .run(server)
+            .await;
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .window(2)
+        .connected(client);
+
+    assert_eq!(client.window_size(), Some(2));
+    assert_eq!(client.in_flight(), 0);
+
+    let requests = (0..4)
+        .map(|_| {
+            let client = client.clone();
+
+            tokio::spawn(async move { client.submit_sm(SubmitSm::default()).await })
+        })
+        .collect::<Vec<_>>();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    assert_eq!(client.in_flight(), 2);
+
+    let pending_responses = client
+        .pending_responses()
+        .await
+        .expect("Failed to get pending responses");
+
+    assert_eq!(pending_responses.len(), 2);
+
+    for request in requests {
+        request
+            .await
+            .expect("Request task failed")
+            .expect("Failed to submit SM");
+    }
+
+    assert_eq!(client.in_flight(), 0);
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn rate_limit_should_delay_requests() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        Server::new()
+            .response_delay(Duration::from_millis(10))
+            .run(server)
+            .await;
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .command_rate_limit(CommandId::SubmitSm, RateLimit::per_second(5).burst(1))
+        .connected(client);
+
+    let now = Instant::now();
+
+    for _ in 0..3 {
+        client
+            .submit_sm(SubmitSm::default())
+            .await
+            .expect("Failed to submit SM");
+    }
+
+    // 1 request is sent immediately, the next 2 requests wait for 200ms each
+    assert!(now.elapsed() >= Duration::from_millis(400));
+
+    client.close().await.expect("Failed to close connection");
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn throttled_request_should_be_retried() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let mut sequence_numbers = Vec::new();
+
+        for status in [CommandStatus::EsmeRthrottled, CommandStatus::EsmeRok] {
+            let Some(Ok(command)) = framed.next().await else {
+                panic!("Expected command");
+            };
+
+            assert!(matches!(command.id(), CommandId::SubmitSm));
+
+            sequence_numbers.push(command.sequence_number());
+
+            framed
+                .send(
+                    Command::builder()
+                        .status(status)
+                        .sequence_number(command.sequence_number())
+                        .pdu(SubmitSmResp::default()),
+                )
+                .await
+                .expect("Failed to send SubmitSmResp");
+        }
+
+        sequence_numbers
+    });
+
+    let (client, events) = ConnectionBuilder::new()
+        .disable_session_state_check()
+        .throttle_retry(ThrottleRetry::new(Duration::from_millis(300), 1))
+        .connected(client);
+
+    let now = Instant::now();
+
+    client
+        .submit_sm(SubmitSm::default())
+        .await
+        .expect("Failed to submit SM");
+
+    assert!(now.elapsed() >= Duration::from_millis(300));
+
+    let sequence_numbers = server.await.expect("Server failed");
+
+    assert_eq!(sequence_numbers.len(), 2);
+    assert_ne!(sequence_numbers[0], sequence_numbers[1]);
+
+    client.close().await.ok();
+
+    client.closed().await;
+
+    let _ = events.count().await;
+}
+
+#[tokio::test]
+async fn outbind_should_bind_receiver() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        // Enquire links before the outbind are answered
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(Pdu::EnquireLink),
+            )
+            .await
+            .expect("Failed to send EnquireLink");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::EnquireLinkResp));
+        assert_eq!(command.sequence_number(), 1);
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(2)
+                    .pdu(
+                        Outbind::builder()
+                            .system_id(COctetString::from_str("SMSC").unwrap())
+                            .password(COctetString::from_str("secret").unwrap())
+                            .build(),
+                    ),
+            )
+            .await
+            .expect("Failed to send Outbind");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        let Some(Pdu::BindReceiver(bind)) = command.pdu() else {
+            panic!("Expected BindReceiver");
+        };
+
+        assert_eq!(bind.system_id.to_string(), "esme");
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(command.sequence_number())
+                    .pdu(BindReceiverResp::default()),
+            )
+            .await
+            .expect("Failed to send BindReceiverResp");
+
+        // Keep the connection open
+        let _ = framed.next().await;
+    });
+
+    let (client, _events) = ConnectionBuilder::new()
+        .no_enquire_link_interval()
+        .outbind()
+        .system_id(COctetString::from_str("SMSC").unwrap())
+        .password(COctetString::from_str("secret").unwrap())
+        .bind_receiver(
+            BindReceiver::builder()
+                .system_id(COctetString::from_str("esme").unwrap())
+                .build(),
+        )
+        .accepted(client)
+        .await
+        .expect("Failed to accept outbind");
+
+    assert_eq!(client.session_state(), SessionState::BoundRx);
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    server.await.expect("Server failed");
+}
+
+#[tokio::test]
+async fn outbind_should_bind_transceiver_on_listener() {
+    init_tracing();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener");
+
+    let addr = listener.local_addr().expect("Failed to get local address");
+
+    let server = tokio::spawn(async move {
+        let stream = tokio::net::TcpStream::connect(addr)
+            .await
+            .expect("Failed to connect");
+
+        let mut framed = Framed::new(stream, CommandCodec::new());
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(Outbind::default()),
+            )
+            .await
+            .expect("Failed to send Outbind");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        assert!(matches!(command.id(), CommandId::BindTransceiver));
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(command.sequence_number())
+                    .pdu(BindTransceiverResp::default()),
+            )
+            .await
+            .expect("Failed to send BindTransceiverResp");
+
+        let _ = framed.next().await;
+    });
+
+    let (client, _events) = ConnectionBuilder::new()
+        .no_enquire_link_interval()
+        .outbind()
+        .bind_transceiver(BindTransceiver::default())
+        .accept(&listener)
+        .await
+        .expect("Failed to accept outbind");
+
+    assert_eq!(client.session_state(), SessionState::BoundTrx);
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    server.await.expect("Server failed");
+}
+
+#[tokio::test]
+async fn outbind_with_wrong_password_should_be_rejected() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(1)
+                    .pdu(
+                        Outbind::builder()
+                            .system_id(COctetString::from_str("SMSC").unwrap())
+                            .password(COctetString::from_str("wrong").unwrap())
+                            .build(),
+                    ),
+            )
+            .await
+            .expect("Failed to send Outbind");
+
+        // The client closes the connection without binding
+        assert!(framed.next().await.is_none());
+    });
+
+    let err = ConnectionBuilder::new()
+        .outbind()
+        .password(COctetString::from_str("secret").unwrap())
+        .accepted(client)
+        .await
+        .map(drop)
+        .unwrap_err();
+
+    let Error::OutbindRejected { system_id } = err else {
+        panic!("Expected OutbindRejected, got: {err:?}");
+    };
+
+    assert_eq!(system_id.to_string(), "SMSC");
+
+    server.await.expect("Server failed");
+}
+
+#[tokio::test]
+async fn outbind_timeout() {
+    init_tracing();
+
+    let (_server, client) = tokio::io::duplex(1024);
+
+    let err = ConnectionBuilder::new()
+        .outbind()
+        .timeout(Duration::from_millis(100))
+        .accepted(client)
+        .await
+        .map(drop)
+        .unwrap_err();
+
+    assert!(matches!(err, Error::OutbindTimeout { .. }));
+}
+
+#[tokio::test]
+async fn smpp_v3_4_session_should_refuse_v5_operations_and_tlvs() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    let server = tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        let Some(Pdu::BindTransceiver(bind)) = command.pdu() else {
+            panic!("Expected BindTransceiver");
+        };
+
+        assert_eq!(bind.interface_version, InterfaceVersion::Smpp3_4);
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(command.sequence_number())
+                    .pdu(
+                        BindTransceiverResp::builder()
+                            .sc_interface_version(Some(InterfaceVersion::Smpp3_4))
+                            .build(),
+                    ),
+            )
+            .await
+            .expect("Failed to send BindTransceiverResp");
+
+        // Nothing else reaches the server
+        assert!(framed.next().await.is_none());
+    });
+
+    let (client, _events) = ConnectionBuilder::new()
+        .no_enquire_link_interval()
+        .interface_version(InterfaceVersion::Smpp3_4)
+        .connected(client);
+
+    assert_eq!(client.interface_version(), None);
+
+    client
+        .bind_transceiver(BindTransceiver::default())
+        .await
+        .expect("Failed to bind");
+
+    assert_eq!(client.interface_version(), Some(InterfaceVersion::Smpp3_4));
+
+    let err = client
+        .broadcast_sm(BroadcastSm::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::UnsupportedByInterfaceVersion {
+            id: CommandId::BroadcastSm,
+            tlv: None,
+            version: InterfaceVersion::Smpp3_4
+        }
+    ));
+
+    let err = client
+        .submit_sm(
+            SubmitSm::builder()
+                .tlvs(vec![
+                    MessageSubmissionRequestTlvValue::BillingIdentification(OctetString::empty()),
+                ])
+                .build(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::UnsupportedByInterfaceVersion {
+            id: CommandId::SubmitSm,
+            tlv: Some(TlvTag::BillingIdentification),
+            version: InterfaceVersion::Smpp3_4
+        }
+    ));
+
+    let err = client
+        .replace_sm(
+            ReplaceSm::builder()
+                .message_payload(Some(MessagePayload::new(
+                    AnyOctetString::from_static_slice(b"Hi"),
+                )))
+                .build(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::UnsupportedByInterfaceVersion {
+            id: CommandId::ReplaceSm,
+            tlv: Some(TlvTag::MessagePayload),
+            version: InterfaceVersion::Smpp3_4
+        }
+    ));
+
+    client.close_and_wait().await.expect("Failed to close");
+
+    server.await.expect("Server failed");
+}
+
+#[tokio::test]
+async fn interface_version_should_be_negotiated_down_to_sc_interface_version() {
+    init_tracing();
+
+    let (server, client) = tokio::io::duplex(1024);
+
+    tokio::spawn(async move {
+        let mut framed = Framed::new(server, CommandCodec::new());
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        let Some(Pdu::BindTransmitter(bind)) = command.pdu() else {
+            panic!("Expected BindTransmitter");
+        };
+
+        assert_eq!(bind.interface_version, InterfaceVersion::Smpp5_0);
+
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(command.sequence_number())
+                    .pdu(
+                        BindTransmitterResp::builder()
+                            .sc_interface_version(Some(InterfaceVersion::Smpp3_4))
+                            .build(),
+                    ),
+            )
+            .await
+            .expect("Failed to send BindTransmitterResp");
+
+        // The server sends a deliver_sm to a transmitter
+        framed
+            .send(
+                Command::builder()
+                    .status(CommandStatus::EsmeRok)
+                    .sequence_number(7)
+                    .pdu(DeliverSm::default()),
+            )
+            .await
+            .expect("Failed to send DeliverSm");
+
+        let Some(Ok(command)) = framed.next().await else {
+            panic!("Expected command");
+        };
+
+        // SMPP v3.4 answers with the matching response instead of a generic_nack
+        assert!(matches!(command.id(), CommandId::DeliverSmResp));
+        assert!(matches!(command.status(), CommandStatus::EsmeRinvbndsts));
+        assert_eq!(command.sequence_number(), 7);
+    });
+
+    let (client, mut events) = ConnectionBuilder::new()
+        .no_enquire_link_interval()
+        .connected(client);
+
+    client
+        .bind_transmitter(BindTransmitter::default())
+        .await
+        .expect("Failed to bind");
+
+    assert_eq!(client.interface_version(), Some(InterfaceVersion::Smpp3_4));
+
+    let Some(Event::Error(Error::UnexpectedCommand { command, .. })) = events.next().await else {
+        panic!("Expected unexpected command error");
+    };
+
+    assert!(matches!(command.id(), CommandId::DeliverSm));
+}
+
+#[tokio::test]
+async fn bind_with_unsupported_interface_version_should_be_rejected() {
+    init_tracing();
+
+    let (_server, client) = tokio::io::duplex(1024);
+
+    let (client, _events) = ConnectionBuilder::new()
+        .no_enquire_link_interval()
+        .connected(client);
+
+    let err = client
+        .bind_transceiver(
+            BindTransceiver::builder()
+                .interface_version(InterfaceVersion::Smpp3_3OrEarlier(0x33))
+                .build(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::UnsupportedInterfaceVersion {
+            version: InterfaceVersion::Smpp3_3OrEarlier(0x33),
+            ..
+        }
+    ));
+}
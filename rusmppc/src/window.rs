@@ -0,0 +1,44 @@
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::error::Error;
+
+/// Limits the number of requests waiting for a response from the server.
+///
+/// A request holds a slot from the moment it is sent to the connection until its response future completes or is dropped.
+#[derive(Debug)]
+pub struct Window {
+    size: Option<usize>,
+    semaphore: Semaphore,
+}
+
+impl Window {
+    /// Creates a new [`Window`].
+    ///
+    /// If `size` is `None`, the number of requests is not limited.
+    /// A size of `0` is treated as `1`.
+    pub fn new(size: Option<usize>) -> Self {
+        let size = size.map(|size| size.max(1));
+
+        Self {
+            size,
+            semaphore: Semaphore::new(size.unwrap_or(Semaphore::MAX_PERMITS)),
+        }
+    }
+
+    /// Waits for a free slot.
+    pub async fn acquire(&self) -> Result<SemaphorePermit<'_>, Error> {
+        self.semaphore
+            .acquire()
+            .await
+            .map_err(|_| Error::ConnectionClosed)
+    }
+
+    pub const fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// Returns the number of occupied slots.
+    pub fn in_flight(&self) -> usize {
+        self.size.unwrap_or(Semaphore::MAX_PERMITS) - self.semaphore.available_permits()
+    }
+}
@@ -0,0 +1,46 @@
+//! Wrapping of accepted connections, e.g. with TLS.
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpStream,
+};
+
+/// Wraps the TCP streams accepted by a [`Server`](crate::server::Server) before the session starts.
+///
+/// The acceptor runs in the task of the connection, a slow handshake does not block the listener.
+pub trait Acceptor: Send + Sync + 'static {
+    /// The stream the session runs on.
+    type Stream: AsyncRead + AsyncWrite + Send + Unpin + 'static;
+
+    /// Wraps an accepted TCP stream.
+    fn accept(
+        &self,
+        stream: TcpStream,
+    ) -> impl Future<Output = std::io::Result<Self::Stream>> + Send;
+}
+
+/// An [`Acceptor`] that runs sessions on plain TCP streams.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl Acceptor for Plain {
+    type Stream = TcpStream;
+
+    async fn accept(&self, stream: TcpStream) -> std::io::Result<Self::Stream> {
+        Ok(stream)
+    }
+}
+
+/// Runs sessions on TLS streams using [`rustls`](https://docs.rs/rustls/latest/rustls/).
+#[cfg(feature = "rustls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+impl Acceptor for tokio_rustls::TlsAcceptor {
+    type Stream = tokio_rustls::server::TlsStream<TcpStream>;
+
+    fn accept(
+        &self,
+        stream: TcpStream,
+    ) -> impl Future<Output = std::io::Result<Self::Stream>> + Send {
+        tokio_rustls::TlsAcceptor::accept(self, stream)
+    }
+}
@@ -0,0 +1,92 @@
+//! Bind negotiation.
+
+use std::net::SocketAddr;
+
+use rusmpp::{
+    CommandStatus,
+    types::COctetString,
+    values::{InterfaceVersion, Npi, Ton},
+};
+
+use crate::bind_mode::BindMode;
+
+/// A bind operation received from an ESME.
+#[derive(Debug, Clone)]
+pub struct BindRequest {
+    /// The requested bind mode.
+    pub bind_mode: BindMode,
+    /// The address of the ESME, if known.
+    pub peer_addr: Option<SocketAddr>,
+    /// Identifies the ESME system requesting to bind with the MC.
+    pub system_id: COctetString<1, 16>,
+    /// The password of the ESME.
+    pub password: COctetString<1, 9>,
+    /// Identifies the type of ESME system requesting to bind with the MC.
+    pub system_type: COctetString<1, 13>,
+    /// The version of the `SMPP` protocol supported by the ESME.
+    pub interface_version: InterfaceVersion,
+    /// Type of Number (TON) for ESME address(es) served via this session.
+    pub addr_ton: Ton,
+    /// Numbering Plan Indicator (NPI) for ESME address(es) served via this session.
+    pub addr_npi: Npi,
+    /// A single ESME address or a range of ESME addresses served via this session.
+    pub address_range: COctetString<1, 41>,
+}
+
+macro_rules! bind_request {
+    ($bind_mode:expr, $peer_addr:expr, $bind:expr) => {{
+        let bind = $bind;
+
+        BindRequest {
+            bind_mode: $bind_mode,
+            peer_addr: $peer_addr,
+            system_id: bind.system_id,
+            password: bind.password,
+            system_type: bind.system_type,
+            interface_version: bind.interface_version,
+            addr_ton: bind.addr_ton,
+            addr_npi: bind.addr_npi,
+            address_range: bind.address_range,
+        }
+    }};
+}
+
+pub(crate) use bind_request;
+
+/// Decides whether an ESME is allowed to bind.
+///
+/// The returned [`CommandStatus`] is sent back in the bind response.
+/// Any status other than [`CommandStatus::EsmeRok`] rejects the bind and closes the connection.
+///
+/// # Example
+///
+/// ```rust
+/// use rusmpp::CommandStatus;
+/// use rusmpps::authenticator::{Authenticator, BindRequest};
+///
+/// struct SinglePassword(String);
+///
+/// impl Authenticator for SinglePassword {
+///     async fn authenticate(&self, bind: &BindRequest) -> CommandStatus {
+///         if bind.password.to_string() == self.0 {
+///             return CommandStatus::EsmeRok;
+///         }
+///
+///         CommandStatus::EsmeRinvpaswd
+///     }
+/// }
+/// ```
+pub trait Authenticator: Send + Sync + 'static {
+    /// Authenticates a bind operation.
+    fn authenticate(&self, bind: &BindRequest) -> impl Future<Output = CommandStatus> + Send;
+}
+
+/// An [`Authenticator`] that accepts every bind.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AcceptAll;
+
+impl Authenticator for AcceptAll {
+    async fn authenticate(&self, _: &BindRequest) -> CommandStatus {
+        CommandStatus::EsmeRok
+    }
+}
@@ -1,12 +1,37 @@
-use rusmpp::session::SessionState;
+use rusmpp::{CommandId, session::SessionState};
 
-#[derive(Debug)]
+/// The mode an ESME requested to bind with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BindMode {
+    /// [`BindTransmitter`](rusmpp::pdus::BindTransmitter).
     Tx,
+    /// [`BindReceiver`](rusmpp::pdus::BindReceiver).
     Rx,
+    /// [`BindTransceiver`](rusmpp::pdus::BindTransceiver).
     Trx,
 }
 
+impl BindMode {
+    /// Returns the [`CommandId`] of the bind operation.
+    pub const fn command_id(self) -> CommandId {
+        match self {
+            BindMode::Tx => CommandId::BindTransmitter,
+            BindMode::Rx => CommandId::BindReceiver,
+            BindMode::Trx => CommandId::BindTransceiver,
+        }
+    }
+
+    /// Returns the [`BindMode`] of a bind operation, `None` if the command is not a bind.
+    pub const fn from_command_id(id: CommandId) -> Option<Self> {
+        match id {
+            CommandId::BindTransmitter => Some(BindMode::Tx),
+            CommandId::BindReceiver => Some(BindMode::Rx),
+            CommandId::BindTransceiver => Some(BindMode::Trx),
+            _ => None,
+        }
+    }
+}
+
 impl From<BindMode> for SessionState {
     fn from(bind_mode: BindMode) -> Self {
         match bind_mode {
@@ -0,0 +1,153 @@
+//! Builder for creating a new `SMPP` server.
+
+use std::time::Duration;
+
+use rusmpp::{types::COctetString, values::InterfaceVersion};
+
+use crate::{
+    acceptor::{Acceptor, Plain},
+    authenticator::{AcceptAll, Authenticator},
+    handler::Handler,
+    server::{Server, ServerConfig},
+};
+
+/// Builder for creating a new `SMPP` server.
+#[derive(Debug)]
+pub struct ServerBuilder<A = AcceptAll, T = Plain> {
+    config: ServerConfig,
+    authenticator: A,
+    acceptor: T,
+}
+
+impl Default for ServerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServerBuilder {
+    /// Creates a new [`ServerBuilder`] with default configurations.
+    ///
+    /// # Defaults
+    /// - `system_id`: empty
+    /// - `max_command_length`: 4096 bytes
+    /// - `session_timeout`: 30 seconds
+    /// - `enquire_link_interval`: 30 seconds
+    /// - `enquire_link_response_timeout`: 5 seconds
+    /// - `response_timeout`: 5 seconds
+    /// - `interface_version`: [`InterfaceVersion::Smpp5_0`]
+    /// - `authenticator`: [`AcceptAll`]
+    /// - `acceptor`: [`Plain`]
+    pub fn new() -> Self {
+        Self {
+            config: ServerConfig {
+                system_id: COctetString::empty(),
+                max_command_length: 4096,
+                session_timeout: Duration::from_secs(30),
+                enquire_link_interval: Some(Duration::from_secs(30)),
+                enquire_link_response_timeout: Duration::from_secs(5),
+                response_timeout: Duration::from_secs(5),
+                interface_version: InterfaceVersion::Smpp5_0,
+            },
+            authenticator: AcceptAll,
+            acceptor: Plain,
+        }
+    }
+}
+
+impl<A, T> ServerBuilder<A, T> {
+    /// Sets the `system_id` of the MC sent in bind responses.
+    pub fn system_id(mut self, system_id: COctetString<1, 16>) -> Self {
+        self.config.system_id = system_id;
+        self
+    }
+
+    /// Sets the maximum command length for incoming commands.
+    pub fn max_command_length(mut self, max_command_length: usize) -> Self {
+        self.config.max_command_length = max_command_length;
+        self
+    }
+
+    /// Sets the time an ESME has to bind after the connection is established.
+    ///
+    /// Connections that are not bound within this time are closed.
+    pub fn session_timeout(mut self, session_timeout: Duration) -> Self {
+        self.config.session_timeout = session_timeout;
+        self
+    }
+
+    /// Sets the interval for sending [`EnquireLink`](rusmpp::Pdu::EnquireLink) commands to bound ESMEs.
+    pub fn enquire_link_interval(mut self, enquire_link_interval: Duration) -> Self {
+        self.config.enquire_link_interval = Some(enquire_link_interval);
+        self
+    }
+
+    /// Disables sending [`EnquireLink`](rusmpp::Pdu::EnquireLink) commands.
+    pub fn no_enquire_link_interval(mut self) -> Self {
+        self.config.enquire_link_interval = None;
+        self
+    }
+
+    /// Sets the timeout for waiting for an [`EnquireLinkResp`](rusmpp::Pdu::EnquireLinkResp) from the ESME.
+    ///
+    /// If the ESME does not respond within this time, the session is closed.
+    pub fn enquire_link_response_timeout(
+        mut self,
+        enquire_link_response_timeout: Duration,
+    ) -> Self {
+        self.config.enquire_link_response_timeout = enquire_link_response_timeout;
+        self
+    }
+
+    /// Sets the timeout for waiting for responses to operations pushed through a [`Session`](crate::session::Session).
+    pub fn response_timeout(mut self, response_timeout: Duration) -> Self {
+        self.config.response_timeout = response_timeout;
+        self
+    }
+
+    /// Sets the latest interface version supported by the server.
+    ///
+    /// Bind responses carry the lower of this version and the `interface_version` of the ESME.
+    pub fn interface_version(mut self, interface_version: InterfaceVersion) -> Self {
+        self.config.interface_version = interface_version;
+        self
+    }
+
+    /// Sets the [`Authenticator`] used to negotiate binds.
+    pub fn authenticator<A2>(self, authenticator: A2) -> ServerBuilder<A2, T> {
+        ServerBuilder {
+            config: self.config,
+            authenticator,
+            acceptor: self.acceptor,
+        }
+    }
+
+    /// Sets the [`Acceptor`] used to wrap accepted TCP streams.
+    pub fn acceptor<T2>(self, acceptor: T2) -> ServerBuilder<A, T2> {
+        ServerBuilder {
+            config: self.config,
+            authenticator: self.authenticator,
+            acceptor,
+        }
+    }
+
+    /// Accepts TLS connections using [`rustls`](https://docs.rs/rustls/latest/rustls/).
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+    pub fn rustls_config(
+        self,
+        config: rustls::ServerConfig,
+    ) -> ServerBuilder<A, tokio_rustls::TlsAcceptor> {
+        self.acceptor(tokio_rustls::TlsAcceptor::from(std::sync::Arc::new(config)))
+    }
+
+    /// Builds the [`Server`] handing bound sessions to `handler`.
+    pub fn build<H>(self, handler: H) -> Server<H, A, T>
+    where
+        H: Handler,
+        A: Authenticator,
+        T: Acceptor,
+    {
+        Server::new(self.config, self.authenticator, self.acceptor, handler)
+    }
+}
@@ -1,87 +1,21 @@
 use std::{collections::HashMap, sync::Arc};
 
-use rusmpp::{Command, session::SessionState};
-use tokio::sync::{RwLock, RwLockReadGuard, mpsc::Sender};
+use rusmpp::{CommandId, pdus::DeliverSm};
+use serde::Deserialize;
+use tokio::sync::{RwLock, RwLockReadGuard};
 
-#[derive(Debug)]
+use crate::session::Session;
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct Client {
     pub system_id: String,
     pub password: String,
 }
 
-#[derive(Debug)]
-pub enum Action {
-    Send(Command),
-}
-
-#[derive(Debug)]
-pub struct SequenceNumber {
-    current: u32,
-}
-
-impl Default for SequenceNumber {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl SequenceNumber {
-    pub fn new() -> Self {
-        Self { current: 1 }
-    }
-
-    pub fn current_and_increment(&mut self) -> u32 {
-        let seq = self.current;
-
-        self.current += 1;
-
-        seq
-    }
-}
-
-#[derive(Debug, Default)]
-pub struct ConnectedClient {
-    sessions: HashMap<u64, ClientSession>,
-}
-
-impl ConnectedClient {
-    pub fn new() -> Self {
-        Self {
-            sessions: HashMap::new(),
-        }
-    }
-
-    pub fn session(&self, session_id: u64) -> Option<&ClientSession> {
-        self.sessions.get(&session_id)
-    }
-
-    fn insert_session(&mut self, session_id: u64, session: ClientSession) {
-        self.sessions.insert(session_id, session);
-    }
-
-    fn remove_session(&mut self, session_id: u64) -> Option<ClientSession> {
-        self.sessions.remove(&session_id)
-    }
-}
-
-#[derive(Debug)]
-pub struct ClientSession {
-    pub tx: Sender<Action>,
-    pub session_state: SessionState,
-}
-
-impl ClientSession {
-    pub fn new(sender: Sender<Action>, session_state: SessionState) -> Self {
-        Self {
-            tx: sender,
-            session_state,
-        }
-    }
-}
-
+/// The bound sessions of the simulator grouped by `system_id`.
 #[derive(Debug, Clone, Default)]
 pub struct ConnectedClients {
-    clients: Arc<RwLock<HashMap<String, ConnectedClient>>>,
+    clients: Arc<RwLock<HashMap<String, HashMap<u64, Session>>>>,
 }
 
 impl ConnectedClients {
@@ -89,26 +23,25 @@ impl ConnectedClients {
         Default::default()
     }
 
-    pub async fn insert_session(&self, system_id: String, session_id: u64, session: ClientSession) {
+    pub async fn insert_session(&self, system_id: String, session: Session) {
         let mut clients = self.clients.write().await;
 
-        let client = clients
+        clients
             .entry(system_id)
-            .or_insert_with(ConnectedClient::new);
-
-        client.insert_session(session_id, session);
+            .or_default()
+            .insert(session.id(), session);
     }
 
-    pub async fn remove_session(&self, system_id: &str, session_id: u64) -> Option<ClientSession> {
+    pub async fn remove_session(&self, system_id: &str, session_id: u64) -> Option<Session> {
         tracing::debug!(system_id, session_id, "Removing session");
 
         let mut clients = self.clients.write().await;
 
         match clients.get_mut(system_id) {
-            Some(client) => {
-                let session = client.remove_session(session_id);
+            Some(sessions) => {
+                let session = sessions.remove(&session_id);
 
-                if client.sessions.is_empty() {
+                if sessions.is_empty() {
                     tracing::debug!(system_id, "Removing client");
 
                     clients.remove(system_id);
@@ -120,7 +53,36 @@ impl ConnectedClients {
         }
     }
 
-    pub async fn clients(&self) -> RwLockReadGuard<'_, HashMap<String, ConnectedClient>> {
+    /// Sends a `deliver_sm` to one of the sessions of `system_id` that is allowed to receive it.
+    ///
+    /// Returns `false` if no such session exists or the `deliver_sm` failed.
+    pub async fn deliver_sm(&self, system_id: &str, deliver_sm: DeliverSm) -> bool {
+        let session = {
+            let clients = self.clients.read().await;
+
+            clients.get(system_id).and_then(|sessions| {
+                sessions
+                    .values()
+                    .find(|session| session.session_state().can_send_as_mc(CommandId::DeliverSm))
+                    .cloned()
+            })
+        };
+
+        match session {
+            Some(session) => session
+                .deliver_sm(deliver_sm)
+                .await
+                .inspect_err(|err| tracing::warn!(system_id, ?err, "Failed to send DeliverSm"))
+                .is_ok(),
+            None => {
+                tracing::warn!(system_id, "No session to send the DeliverSm to");
+
+                false
+            }
+        }
+    }
+
+    pub async fn clients(&self) -> RwLockReadGuard<'_, HashMap<String, HashMap<u64, Session>>> {
         self.clients.read().await
     }
 }
@@ -6,9 +6,17 @@ use std::{
 
 use serde::Deserialize;
 
+use crate::{client::Client, delivery::DeliveryReceiptRule};
+
 #[derive(Debug, Deserialize)]
 pub struct Config {
     pub socket_addr: SocketAddr,
+    /// Clients allowed to bind.
+    #[serde(default)]
+    pub clients: Vec<Client>,
+    /// Accept every bind without authenticating it against `clients`.
+    #[serde(default)]
+    pub accept_all_binds: bool,
     #[serde(with = "humantime_serde")]
     #[serde(default)]
     pub enquire_link_interval: Option<Duration>,
@@ -25,6 +33,18 @@ pub struct Config {
     #[serde(with = "humantime_serde")]
     #[serde(default)]
     pub enquire_link_response_delay: Option<Duration>,
+    /// Delay before sending a delivery receipt for a submitted message.
+    #[serde(with = "humantime_serde")]
+    #[serde(default)]
+    pub delivery_receipt_delay: Option<Duration>,
+    /// Final states reported in the delivery receipts, by destination prefix.
+    ///
+    /// The first matching rule wins, messages matching no rule are delivered.
+    #[serde(default)]
+    pub delivery_receipt_rules: Vec<DeliveryReceiptRule>,
+    /// Echo submitted messages back to the client as mobile originated `deliver_sm`s.
+    #[serde(default)]
+    pub mo_echo: bool,
 }
 
 impl Default for Config {
@@ -36,6 +56,11 @@ impl Default for Config {
             session_timeout: Duration::from_secs(3),
             bind_delay: Some(Duration::from_millis(100)),
             response_delay: Some(Duration::from_millis(100)),
+            delivery_receipt_delay: Some(Duration::from_millis(500)),
+            delivery_receipt_rules: vec![],
+            mo_echo: false,
+            clients: vec![],
+            accept_all_binds: false,
             socket_addr: "127.0.0.1:2775"
                 .parse()
                 .expect("Failed to parse socket address"),
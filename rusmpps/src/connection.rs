@@ -1,200 +1,98 @@
-use std::{str::FromStr, sync::Arc, time::Duration};
+use std::sync::Arc;
 
-use futures::{SinkExt, StreamExt, TryStreamExt, future};
+use futures::{SinkExt, StreamExt};
 use rusmpp::{
     Command, CommandId, CommandStatus, Pdu,
-    pdus::{BindReceiverResp, BindTransceiverResp, BindTransmitterResp, SubmitSmResp},
+    pdus::{BindReceiverResp, BindTransceiverResp, BindTransmitterResp},
+    session::SessionState,
     tokio_codec::CommandCodec,
-    types::COctetString,
     values::InterfaceVersion,
 };
 use tokio::{
     io::{AsyncRead, AsyncWrite},
     sync::mpsc,
+    task::JoinHandle,
 };
-use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::codec::{FramedRead, FramedWrite};
 
 use crate::{
+    authenticator::{Authenticator, BindRequest, bind_request},
     bind_mode::BindMode,
-    client::{Action, Client, ClientSession, ConnectedClients, SequenceNumber},
+    handler::Handler,
+    server::Shared,
+    session::{Action, Session},
     timer::Timer,
 };
 
 #[derive(Debug)]
-pub struct ConnectionConfig {
-    pub connected_clients: ConnectedClients,
-    pub clients: Vec<Client>,
-    pub enquire_link_interval: Option<Duration>,
-    pub enquire_link_response_timeout: Duration,
-    pub session_timeout: Duration,
-    pub bind_delay: Option<Duration>,
-    pub response_delay: Option<Duration>,
-    pub enquire_link_response_delay: Option<Duration>,
+pub(crate) struct Connection<H, A, T> {
+    session: Session,
+    shared: Arc<Shared<H, A, T>>,
+    actions: mpsc::UnboundedReceiver<Action>,
 }
 
-#[derive(Debug)]
-pub struct Connection {
-    session_id: u64,
-    config: Arc<ConnectionConfig>,
-}
-
-impl Connection {
-    pub fn new(session_id: u64, config: Arc<ConnectionConfig>) -> Self {
-        Self { session_id, config }
+impl<H, A, T> Connection<H, A, T>
+where
+    H: Handler,
+    A: Authenticator,
+    T: Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        session: Session,
+        shared: Arc<Shared<H, A, T>>,
+        actions: mpsc::UnboundedReceiver<Action>,
+    ) -> Self {
+        Self {
+            session,
+            shared,
+            actions,
+        }
     }
 
-    pub async fn run<S>(self, stream: S)
+    pub(crate) async fn run<S>(self, stream: S)
     where
         S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
     {
-        let session_id = self.session_id;
+        let Self {
+            session,
+            shared,
+            mut actions,
+        } = self;
+
+        let session_id = session.id();
+        let config = &shared.config;
 
         let (reader, writer) = tokio::io::split(stream);
 
-        let mut reader = FramedRead::new(reader, CommandCodec::new().with_max_length(1024));
-        let mut writer = FramedWrite::new(writer, CommandCodec::new().with_max_length(1024));
+        let mut reader = FramedRead::new(
+            reader,
+            CommandCodec::new()
+                .with_max_length(config.max_command_length)
+                .recoverable(),
+        );
+        let mut writer = FramedWrite::new(writer, CommandCodec::new());
 
         tracing::debug!(session_id, "Awaiting bind operation");
 
-        let mut filtered = (&mut reader).try_filter(|command| {
-            tracing::debug!(session_id, sequence_number=command.sequence_number(), id=?command.id(), "Received command");
-
-            future::ready(matches!(
-                command.id(),
-                CommandId::BindTransmitter | CommandId::BindReceiver | CommandId::BindTransceiver
-            ))
-        });
-
-        let (sequence_number, system_id, _, bind_mode) = tokio::select! {
-            _ = tokio::time::sleep(self.config.session_timeout) => {
-                tracing::warn!(session_id, "Session timeout reached, closing connection");
-
-                return;
-            },
-            command = filtered.next() => {
-                match command {
-                    None => {
-                        tracing::warn!(session_id, "Connection closed before bind command was received");
-
-                        return;
-                    },
-                    Some(Err(err)) => {
-                        tracing::error!(session_id, ?err, "Failed read command");
-
-                        return;
-                    },
-                    Some(Ok(command)) => {
-                        tracing::debug!(session_id, id=?command.id(), "Received bind command");
-                        tracing::trace!(session_id, ?command, "Received bind command");
-
-                        let (_, status, sequence_number, pdu) = command.into_parts().raw();
-
-                        if !(matches!(status, CommandStatus::EsmeRok)) {
-                            // Really?
-
-                            tracing::error!(session_id, ?status, "Received bind command with non-OK status");
-
-                            return;
-                        }
-
-                        let (system_id, password, bind_mode) = match pdu {
-                            None => {
-                                tracing::error!(session_id, "Received bind command without PDU");
-
-                                return;
-                            }
-                            Some(Pdu::BindTransmitter(bind)) => {
-                                (bind.system_id, bind.password, BindMode::Tx)
-                            }
-                            Some(Pdu::BindReceiver(bind)) => {
-                                (bind.system_id, bind.password, BindMode::Rx)
-                            }
-                            Some(Pdu::BindTransceiver(bind)) => {
-                                (bind.system_id, bind.password, BindMode::Trx)
-                            },
-                            _ => {
-                                // Should not happen
-
-                                return;
-                            }
-                        };
-
-                        (sequence_number, system_id, password, bind_mode)
-                    }
-                }
-            }
-        };
-
-        // TODO: Do the verification of system_id and password here
-
-        let mc_system_id = COctetString::from_str("Rusmpps").expect("Must be valid system ID");
-        let sc_interface_version = Some(InterfaceVersion::Smpp5_0);
-
-        let pdu: Pdu = match bind_mode {
-            BindMode::Tx => BindTransmitterResp::builder()
-                .system_id(mc_system_id)
-                .sc_interface_version(sc_interface_version)
-                .build()
-                .into(),
-            BindMode::Rx => BindReceiverResp::builder()
-                .system_id(mc_system_id)
-                .sc_interface_version(sc_interface_version)
-                .build()
-                .into(),
-            BindMode::Trx => BindTransceiverResp::builder()
-                .system_id(mc_system_id)
-                .sc_interface_version(sc_interface_version)
-                .build()
-                .into(),
-        };
-
-        let command = Command::builder()
-            .status(CommandStatus::EsmeRok)
-            .sequence_number(sequence_number)
-            .pdu(pdu);
-
-        if let Some(delay) = self.config.bind_delay {
-            tokio::time::sleep(delay).await;
-        }
-
-        tracing::debug!(session_id, id=?command.id(), "Sending response");
-        tracing::trace!(session_id, ?command, "Sending response");
-
-        if let Err(err) = writer.send(command).await {
-            tracing::error!(session_id, ?err, "Failed to send response");
-
-            return;
-        }
-
-        let (tx, rx) = mpsc::channel(100);
-
-        let mut sequence_number = SequenceNumber::new();
-        let system_id = system_id.to_string();
-        let session = ClientSession::new(tx, bind_mode.into());
-
-        self.config
-            .connected_clients
-            .insert_session(system_id.clone(), session_id, session)
-            .await;
+        let mut last_enquire_link_sequence_number = None;
+        let mut on_bound: Option<JoinHandle<()>> = None;
 
-        let mut actions = ReceiverStream::new(rx);
+        let session_timer = Timer::new().activated(config.session_timeout);
+        tokio::pin!(session_timer);
 
-        let mut last_enquire_link_sequence_number = None;
+        let enquire_link_timer = Timer::new();
+        tokio::pin!(enquire_link_timer);
 
         let enquire_link_resp_timer = Timer::new();
         tokio::pin!(enquire_link_resp_timer);
 
-        let enquire_link_timer = self
-            .config
-            .enquire_link_interval
-            .map(|delay| Timer::new().activated(delay))
-            .unwrap_or_default();
-
-        tokio::pin!(enquire_link_timer);
-
         loop {
             tokio::select! {
+                _ = &mut session_timer => {
+                    tracing::warn!(session_id, "Session timeout reached, closing connection");
+
+                    break
+                }
                 _ = &mut enquire_link_resp_timer => {
                     tracing::warn!(session_id, "EnquireLink response timeout reached, closing connection");
 
@@ -203,14 +101,11 @@ impl Connection {
                 _ = &mut enquire_link_timer => {
                     tracing::debug!(session_id, "Sending EnquireLink command");
 
-                    let sequence_number = sequence_number.current_and_increment();
+                    let sequence_number = session.next_sequence_number();
 
                     last_enquire_link_sequence_number = Some(sequence_number);
 
-                    let command = Command::builder()
-                        .status(CommandStatus::EsmeRok)
-                        .sequence_number(sequence_number)
-                        .pdu(Pdu::EnquireLink);
+                    let command = Command::new(CommandStatus::EsmeRok, sequence_number, Pdu::EnquireLink);
 
                     if let Err(err) = writer.send(command).await {
                         tracing::error!(session_id, sequence_number, ?err, "Failed to send EnquireLink command");
@@ -218,39 +113,63 @@ impl Connection {
                         break
                     }
 
-                    enquire_link_resp_timer.as_mut().activate(self.config.enquire_link_response_timeout);
+                    enquire_link_resp_timer.as_mut().activate(config.enquire_link_response_timeout);
 
-                    if let Some(delay) = self.config.enquire_link_interval {
-                         enquire_link_timer.as_mut().activate(delay);
+                    if let Some(interval) = config.enquire_link_interval {
+                        enquire_link_timer.as_mut().activate(interval);
                     }
-
-                    tracing::debug!(session_id, sequence_number, "EnquireLink response timer activated");
                 }
-                action = actions.next() => {
-                    let action = match action {
-                        None => break,
-                        Some(action) => action,
+                action = actions.recv() => {
+                    let command = match action {
+                        None | Some(Action::Close) => break,
+                        Some(Action::Send(command)) => command,
                     };
 
-                    match action {
-                        Action::Send(command) => {
-                            let sequence_number = command.sequence_number();
+                    let id = command.id();
+                    let sequence_number = command.sequence_number();
 
-                            tracing::debug!(session_id, sequence_number, id=?command.id(), "Sending command");
-                            tracing::trace!(session_id, sequence_number, ?command, "Sending command");
+                    tracing::debug!(session_id, sequence_number, ?id, "Sending command");
+                    tracing::trace!(session_id, sequence_number, ?command, "Sending command");
 
-                            if let Err(err) = writer.send(command).await {
-                                tracing::error!(session_id, ?err, "Failed to send command");
+                    if let Err(err) = writer.send(command).await {
+                        tracing::error!(session_id, sequence_number, ?err, "Failed to send command");
 
-                                break
-                            }
-                        }
+                        break
+                    }
+
+                    match id {
+                        CommandId::Outbind => session.set_session_state(SessionState::Outbound),
+                        CommandId::Unbind => session.set_session_state(SessionState::Unbound),
+                        _ => {}
                     }
                 }
                 command = reader.next() => {
                     let command = match command {
-                        None =>  break,
-                        Some(Ok(command)) => command,
+                        None => {
+                            tracing::debug!(session_id, "Connection closed by peer");
+
+                            break
+                        }
+                        Some(Ok(Ok(command))) => command,
+                        Some(Ok(Err(malformed))) => {
+                            let id = malformed.id();
+                            let sequence_number = malformed.sequence_number();
+
+                            tracing::warn!(session_id, sequence_number, ?id, error=%malformed.error(), "Received malformed command");
+
+                            // Responses are not answered, the pending request times out.
+                            if id.is_response() {
+                                continue
+                            }
+
+                            if let Err(err) = writer.send(Command::new(malformed.generic_nack_status(), sequence_number, Pdu::GenericNack)).await {
+                                tracing::error!(session_id, sequence_number, ?err, "Failed to send response");
+
+                                break
+                            }
+
+                            continue
+                        }
                         Some(Err(err)) => {
                             tracing::error!(session_id, ?err, "Failed to read command");
 
@@ -258,86 +177,245 @@ impl Connection {
                         }
                     };
 
+                    let id = command.id();
                     let sequence_number = command.sequence_number();
 
-                    tracing::debug!(session_id, sequence_number, id=?command.id(), "Received command");
+                    tracing::debug!(session_id, sequence_number, ?id, "Received command");
                     tracing::trace!(session_id, sequence_number, ?command, "Received command");
 
-                    let (id, _, sequence_number, pdu) = command.into_parts().raw();
+                    if id.is_response() {
+                        if id == CommandId::EnquireLinkResp && last_enquire_link_sequence_number == Some(sequence_number) {
+                            last_enquire_link_sequence_number = None;
+
+                            enquire_link_resp_timer.as_mut().disable();
+
+                            continue
+                        }
+
+                        let status = command.status();
 
-                    let pdu: Pdu = match pdu {
+                        if !session.resolve(command) {
+                            tracing::warn!(session_id, sequence_number, ?id, ?status, "Received unexpected response");
+                        }
+
+                        if id == CommandId::UnbindResp {
+                            break
+                        }
+
+                        continue
+                    }
+
+                    let state = session.session_state();
+
+                    if !state.can_receive_as_mc(id) {
+                        tracing::warn!(session_id, sequence_number, ?id, ?state, "Received command not allowed in session state");
+
+                        // Binds are answered with their own response, other operations with a generic_nack.
+                        let response = match BindMode::from_command_id(id) {
+                            Some(bind_mode) => {
+                                let status = if state.is_bound() { CommandStatus::EsmeRalybnd } else { CommandStatus::EsmeRinvbndsts };
+
+                                let interface_version = bind_interface_version(command.pdu());
+
+                                Command::new(status, sequence_number, bind_response(bind_mode, interface_version, &shared))
+                            }
+                            None => Command::new(CommandStatus::EsmeRinvbndsts, sequence_number, Pdu::GenericNack),
+                        };
+
+                        if let Err(err) = writer.send(response).await {
+                            tracing::error!(session_id, sequence_number, ?err, "Failed to send response");
+
+                            break
+                        }
+
+                        continue
+                    }
+
+                    let peer_addr = session.peer_addr();
+
+                    let bind = match command.into_parts().raw().3 {
+                        Some(Pdu::BindTransmitter(bind)) => bind_request!(BindMode::Tx, peer_addr, bind),
+                        Some(Pdu::BindReceiver(bind)) => bind_request!(BindMode::Rx, peer_addr, bind),
+                        Some(Pdu::BindTransceiver(bind)) => bind_request!(BindMode::Trx, peer_addr, bind),
                         Some(Pdu::Unbind) => {
-                            Pdu::UnbindResp
-                        },
-                        Some(Pdu::EnquireLink) => {
-                            Pdu::EnquireLinkResp
-                        },
-                        Some(Pdu::SubmitSm(_)) => {
-                            SubmitSmResp::builder()
-                                .build()
-                                .into()
-                        },
-                        Some(Pdu::EnquireLinkResp) => {
-                            match last_enquire_link_sequence_number {
-                                Some(seq) => {
-                                    if sequence_number != seq {
-                                        tracing::warn!(session_id, id=?id, expected=seq, got=sequence_number, "Received EnquireLinkResp with unexpected sequence number");
-
-                                        return
-                                    }
-
-                                    tracing::trace!(session_id, sequence_number, id=?id, "Received EnquireLinkResp");
-
-                                    last_enquire_link_sequence_number = None;
-
-                                    enquire_link_resp_timer.as_mut().disable();
-
-                                    tracing::debug!(session_id, sequence_number, "EnquireLink response timer disabled");
-                                }
-                                None => {
-                                    tracing::warn!(session_id, sequence_number, "Received EnquireLinkResp without a previous EnquireLink");
-                                }
+                            session.set_session_state(SessionState::Unbound);
+
+                            if let Err(err) = writer.send(Command::new(CommandStatus::EsmeRok, sequence_number, Pdu::UnbindResp)).await {
+                                tracing::error!(session_id, sequence_number, ?err, "Failed to send response");
                             }
 
+                            break
+                        }
+                        Some(Pdu::EnquireLink) => {
+                            let shared = shared.clone();
+                            let session = session.clone();
+
+                            tokio::spawn(async move {
+                                shared.handler.on_enquire_link(&session).await;
+
+                                let _ = session.send(Command::new(CommandStatus::EsmeRok, sequence_number, Pdu::EnquireLinkResp));
+                            });
+
                             continue
                         }
-                        _ => {
-                            tracing::warn!(session_id, sequence_number, id=?id, "Received unsupported command");
+                        Some(pdu) => {
+                            let shared = shared.clone();
+                            let session = session.clone();
+
+                            tokio::spawn(async move {
+                                let (status, pdu) = dispatch(&shared.handler, &session, pdu).await;
+
+                                let _ = session.send(Command::new(status, sequence_number, pdu));
+                            });
 
                             continue
                         }
-                    };
+                        None => {
+                            tracing::warn!(session_id, sequence_number, ?id, "Received command without PDU");
 
-                    let delay = match pdu {
-                        Pdu::EnquireLinkResp => self.config.enquire_link_response_delay,
-                        _ => self.config.response_delay,
+                            continue
+                        }
                     };
 
-                    let command = Command::builder()
-                        .status(CommandStatus::EsmeRok)
-                        .sequence_number(sequence_number)
-                        .pdu(pdu);
+                    let bind_mode = bind.bind_mode;
+                    let system_id = bind.system_id.to_string();
 
-                    if let Some(delay) = delay {
-                        // XXX: This will block the reading process
-                        tokio::time::sleep(delay).await;
-                    }
+                    let status = shared.authenticator.authenticate(&bind).await;
 
-                    tracing::debug!(session_id, sequence_number, id=?command.id(), "Sending response");
-                    tracing::trace!(session_id, sequence_number, ?command, "Sending response");
+                    let pdu = bind_response(bind_mode, Some(bind.interface_version), &shared);
 
-                    if let Err(err) = writer.send(command).await {
+                    if let Err(err) = writer.send(Command::new(status, sequence_number, pdu)).await {
                         tracing::error!(session_id, sequence_number, ?err, "Failed to send response");
 
-                        break;
+                        break
+                    }
+
+                    if status != CommandStatus::EsmeRok {
+                        tracing::warn!(session_id, system_id, ?status, "Bind rejected");
+
+                        break
+                    }
+
+                    tracing::info!(session_id, system_id, ?bind_mode, "Bound");
+
+                    session.set_system_id(system_id);
+                    session.set_session_state(bind_mode.into());
+
+                    session_timer.as_mut().disable();
+
+                    if let Some(interval) = config.enquire_link_interval {
+                        enquire_link_timer.as_mut().activate(interval);
                     }
+
+                    let shared = shared.clone();
+                    let session = session.clone();
+
+                    on_bound = Some(tokio::spawn(async move {
+                        shared.handler.on_bound(&session).await;
+                    }));
                 }
-            };
+            }
         }
 
-        self.config
-            .connected_clients
-            .remove_session(&system_id, session_id)
-            .await;
+        session.set_session_state(SessionState::Closed);
+        session.clear_pending();
+
+        actions.close();
+
+        if let Some(on_bound) = on_bound {
+            let _ = on_bound.await;
+
+            shared.handler.on_closed(&session).await;
+        }
+    }
+}
+
+/// Creates the response to a bind operation.
+///
+/// The `sc_interface_version` is the lower of the server's interface version and the `interface_version` of the ESME.
+fn bind_response<H, A, T>(
+    bind_mode: BindMode,
+    interface_version: Option<InterfaceVersion>,
+    shared: &Shared<H, A, T>,
+) -> Pdu {
+    let system_id = shared.config.system_id.clone();
+    let supported = shared.config.interface_version;
+
+    let sc_interface_version = Some(match interface_version {
+        Some(version) if u8::from(version) < u8::from(supported) => version,
+        _ => supported,
+    });
+
+    match bind_mode {
+        BindMode::Tx => BindTransmitterResp::builder()
+            .system_id(system_id)
+            .sc_interface_version(sc_interface_version)
+            .build()
+            .into(),
+        BindMode::Rx => BindReceiverResp::builder()
+            .system_id(system_id)
+            .sc_interface_version(sc_interface_version)
+            .build()
+            .into(),
+        BindMode::Trx => BindTransceiverResp::builder()
+            .system_id(system_id)
+            .sc_interface_version(sc_interface_version)
+            .build()
+            .into(),
+    }
+}
+
+/// Returns the `interface_version` of a bind operation.
+fn bind_interface_version(pdu: Option<&Pdu>) -> Option<InterfaceVersion> {
+    match pdu {
+        Some(Pdu::BindTransmitter(bind)) => Some(bind.interface_version),
+        Some(Pdu::BindReceiver(bind)) => Some(bind.interface_version),
+        Some(Pdu::BindTransceiver(bind)) => Some(bind.interface_version),
+        _ => None,
+    }
+}
+
+/// Passes an operation to the handler and returns the status and the PDU of the response.
+async fn dispatch<H: Handler>(handler: &H, session: &Session, pdu: Pdu) -> (CommandStatus, Pdu) {
+    match pdu {
+        Pdu::SubmitSm(pdu) => response(handler.on_submit_sm(session, pdu).await),
+        Pdu::DataSm(pdu) => response(handler.on_data_sm(session, pdu).await),
+        Pdu::SubmitMulti(pdu) => response(handler.on_submit_multi(session, pdu).await),
+        Pdu::QuerySm(pdu) => response(handler.on_query_sm(session, pdu).await),
+        Pdu::BroadcastSm(pdu) => response(handler.on_broadcast_sm(session, pdu).await),
+        Pdu::QueryBroadcastSm(pdu) => response(handler.on_query_broadcast_sm(session, pdu).await),
+        Pdu::CancelSm(pdu) => {
+            empty_response(handler.on_cancel_sm(session, pdu).await, Pdu::CancelSmResp)
+        }
+        Pdu::ReplaceSm(pdu) => empty_response(
+            handler.on_replace_sm(session, pdu).await,
+            Pdu::ReplaceSmResp,
+        ),
+        Pdu::CancelBroadcastSm(pdu) => empty_response(
+            handler.on_cancel_broadcast_sm(session, pdu).await,
+            Pdu::CancelBroadcastSmResp,
+        ),
+        pdu => {
+            tracing::warn!(session_id = session.id(), id = ?pdu.command_id(), "Received unsupported command");
+
+            (CommandStatus::EsmeRinvcmdid, Pdu::GenericNack)
+        }
+    }
+}
+
+fn response<R>(result: Result<R, CommandStatus>) -> (CommandStatus, Pdu)
+where
+    R: Default + Into<Pdu>,
+{
+    match result {
+        Ok(response) => (CommandStatus::EsmeRok, response.into()),
+        Err(status) => (status, R::default().into()),
+    }
+}
+
+fn empty_response(result: Result<(), CommandStatus>, pdu: Pdu) -> (CommandStatus, Pdu) {
+    match result {
+        Ok(()) => (CommandStatus::EsmeRok, pdu),
+        Err(status) => (status, pdu),
     }
 }
@@ -0,0 +1,173 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusmpp::{
+    extra::delivery_receipt::{DeliveryReceipt, DeliveryReceiptDate},
+    pdus::{DeliverSm, SubmitSm},
+    values::{
+        Ansi41Specific, EsmClass, MCDeliveryReceipt, MessageState, MessageType, MessagingMode,
+    },
+};
+use serde::Deserialize;
+
+/// Final state of a submitted message, reported in its delivery receipt.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinalState {
+    #[default]
+    Delivered,
+    Expired,
+    Deleted,
+    Undeliverable,
+    Rejected,
+}
+
+impl FinalState {
+    const fn is_success(self) -> bool {
+        matches!(self, Self::Delivered)
+    }
+}
+
+impl From<FinalState> for MessageState {
+    fn from(state: FinalState) -> Self {
+        match state {
+            FinalState::Delivered => MessageState::Delivered,
+            FinalState::Expired => MessageState::Expired,
+            FinalState::Deleted => MessageState::Deleted,
+            FinalState::Undeliverable => MessageState::Undeliverable,
+            FinalState::Rejected => MessageState::Rejected,
+        }
+    }
+}
+
+/// Final state of the messages submitted to destinations starting with `destination_prefix`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeliveryReceiptRule {
+    pub destination_prefix: String,
+    pub state: FinalState,
+}
+
+/// Returns the final state of the first rule matching the destination of a submitted message.
+///
+/// Messages matching no rule are delivered.
+pub fn final_state(rules: &[DeliveryReceiptRule], submit_sm: &SubmitSm) -> FinalState {
+    let destination_addr = submit_sm.destination_addr.to_string();
+
+    rules
+        .iter()
+        .find(|rule| destination_addr.starts_with(&rule.destination_prefix))
+        .map(|rule| rule.state)
+        .unwrap_or_default()
+}
+
+/// Builds a `deliver_sm` delivery receipt for a submitted message.
+///
+/// Returns `None` if the `registered_delivery` of the submitted message does not request a receipt for the final state.
+pub fn receipt(
+    message_id: &str,
+    submit_sm: &SubmitSm,
+    state: FinalState,
+    submit_date: SystemTime,
+    done_date: SystemTime,
+) -> Option<DeliverSm> {
+    let requested = match submit_sm.registered_delivery.mc_delivery_receipt() {
+        MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccessOrFailure => {
+            true
+        }
+        MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsSuccess => {
+            state.is_success()
+        }
+        MCDeliveryReceipt::McDeliveryReceiptRequestedWhereFinalDeliveryOutcomeIsFailure => {
+            !state.is_success()
+        }
+        _ => false,
+    };
+
+    if !requested {
+        return None;
+    }
+
+    let short_message = submit_sm.short_message();
+
+    let receipt = DeliveryReceipt::builder()
+        .id(message_id)
+        .submitted(1)
+        .delivered(u32::from(state.is_success()))
+        .submit_date(date(submit_date))
+        .done_date(date(done_date))
+        .state(state.into())
+        .error("000")
+        .text(&short_message[..short_message.len().min(20)])
+        .build();
+
+    let builder = DeliverSm::builder()
+        .source_addr_ton(submit_sm.dest_addr_ton)
+        .source_addr_npi(submit_sm.dest_addr_npi)
+        .source_addr(submit_sm.destination_addr.clone())
+        .dest_addr_ton(submit_sm.source_addr_ton)
+        .dest_addr_npi(submit_sm.source_addr_npi)
+        .destination_addr(submit_sm.source_addr.clone());
+
+    receipt
+        .to_deliver_sm(builder)
+        .inspect_err(|err| tracing::error!(message_id, ?err, "Failed to build delivery receipt"))
+        .ok()
+}
+
+/// Builds a mobile originated `deliver_sm` echoing a submitted message back to its sender.
+pub fn mo(submit_sm: &SubmitSm) -> DeliverSm {
+    DeliverSm::builder()
+        .service_type(submit_sm.service_type.clone())
+        .source_addr_ton(submit_sm.dest_addr_ton)
+        .source_addr_npi(submit_sm.dest_addr_npi)
+        .source_addr(submit_sm.destination_addr.clone())
+        .dest_addr_ton(submit_sm.source_addr_ton)
+        .dest_addr_npi(submit_sm.source_addr_npi)
+        .destination_addr(submit_sm.source_addr.clone())
+        .esm_class(EsmClass::new(
+            MessagingMode::Default,
+            MessageType::Default,
+            // Keeps bits 5-2 clear, the default flags a delivery acknowledgement.
+            Ansi41Specific::Other(0),
+            submit_sm.esm_class.gsm_features,
+        ))
+        .protocol_id(submit_sm.protocol_id)
+        .data_coding(submit_sm.data_coding)
+        .short_message(submit_sm.short_message().clone())
+        .build()
+}
+
+/// Converts a time to a [`DeliveryReceiptDate`] in UTC.
+fn date(time: SystemTime) -> DeliveryReceiptDate {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let secs = secs % 86_400;
+
+    DeliveryReceiptDate::new(
+        (year % 100) as u8,
+        month as u8,
+        day as u8,
+        (secs / 3_600) as u8,
+        (secs % 3_600 / 60) as u8,
+    )
+}
+
+/// Converts days since the unix epoch to a `(year, month, day)` civil date.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
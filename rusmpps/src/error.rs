@@ -0,0 +1,102 @@
+//! `SMPP` server error type.
+
+use std::time::Duration;
+
+use rusmpp::{
+    Command, CommandId,
+    session::SessionState,
+    tokio_codec::{DecodeError, EncodeError},
+};
+
+/// Errors that can occur during `SMPP` server operations.
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Binding or accepting on the listener failed.
+    ///
+    /// This error is returned by [`Server::listen`](crate::server::Server::listen) and [`Server::run`](crate::server::Server::run).
+    #[error("Failed to listen: {0}")]
+    Listen(#[source] std::io::Error),
+    /// I/O error occurred.
+    ///
+    /// This error can occur during reading from or writing to the network stream.
+    #[error("I/O error: {0}")]
+    Io(#[source] std::io::Error),
+    /// The session is closed.
+    ///
+    /// This error is returned by [`Session`](crate::session::Session) methods that send commands on a closed session.
+    #[error("Session closed")]
+    SessionClosed,
+    /// Protocol encode error.
+    #[error("Protocol encode error: {0}")]
+    Encode(#[source] EncodeError),
+    /// Protocol decode error.
+    #[error("Protocol decode error: {0}")]
+    Decode(#[source] DecodeError),
+    /// The ESME did not respond to the request within the configured timeout.
+    ///
+    /// See [`ServerBuilder::response_timeout`](crate::builder::ServerBuilder::response_timeout).
+    #[error("Response timed out: sequence number: {sequence_number}, timeout: {timeout:?}")]
+    ResponseTimeout {
+        /// The sequence number of the request that timed out.
+        sequence_number: u32,
+        /// The timeout duration.
+        timeout: Duration,
+    },
+    /// The ESME responded with an error response.
+    ///
+    /// Error responses are responses with the status code other than [`EsmeRok`](rusmpp::CommandStatus::EsmeRok).
+    #[error("Unexpected response from the ESME: response: {response:?}")]
+    UnexpectedResponse {
+        /// The response that was received from the ESME.
+        response: Box<Command>,
+    },
+    /// The command is not allowed in the current session state.
+    ///
+    /// The command was rejected locally and was not sent to the ESME.
+    /// For example, a [`DeliverSm`](rusmpp::pdus::DeliverSm) can not be sent to an ESME bound as a transmitter.
+    #[error("Command not allowed in session state: id: {id:?}, state: {state:?}")]
+    InvalidSessionState {
+        /// The id of the rejected command.
+        id: CommandId,
+        /// The session state at the time of the request.
+        state: SessionState,
+    },
+}
+
+impl Error {
+    pub(crate) fn unexpected_response(response: impl Into<Box<Command>>) -> Self {
+        Self::UnexpectedResponse {
+            response: response.into(),
+        }
+    }
+
+    pub(crate) const fn invalid_session_state(id: CommandId, state: SessionState) -> Self {
+        Self::InvalidSessionState { id, state }
+    }
+
+    pub(crate) const fn response_timeout(sequence_number: u32, timeout: Duration) -> Self {
+        Self::ResponseTimeout {
+            sequence_number,
+            timeout,
+        }
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(value: DecodeError) -> Self {
+        match value {
+            DecodeError::Io(error) => Error::Io(error),
+            error => Error::Decode(error),
+        }
+    }
+}
+
+impl From<EncodeError> for Error {
+    fn from(value: EncodeError) -> Self {
+        match value {
+            EncodeError::Io(error) => Error::Io(error),
+            error => Error::Encode(error),
+        }
+    }
+}
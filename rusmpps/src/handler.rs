@@ -0,0 +1,154 @@
+//! Handling of bound sessions.
+
+use rusmpp::{
+    CommandStatus,
+    pdus::{
+        BroadcastSm, BroadcastSmResp, CancelBroadcastSm, CancelSm, DataSm, DataSmResp,
+        QueryBroadcastSm, QueryBroadcastSmResp, QuerySm, QuerySmResp, ReplaceSm, SubmitMulti,
+        SubmitMultiResp, SubmitSm, SubmitSmResp,
+    },
+};
+
+use crate::session::Session;
+
+/// Handles the operations of bound sessions.
+///
+/// Every operation sent by an ESME is handled in its own task, the returned value is sent back as the response.
+///
+/// - `Ok(response)` is sent with the status [`CommandStatus::EsmeRok`].
+/// - `Err(status)` is sent as an empty response with the given status.
+///
+/// Operations that are not implemented are rejected with [`CommandStatus::EsmeRinvcmdid`].
+///
+/// Session management operations ([`BindTransmitter`](rusmpp::pdus::BindTransmitter), [`Unbind`](rusmpp::Pdu::Unbind),
+/// [`EnquireLink`](rusmpp::Pdu::EnquireLink), ...) are handled by the server.
+/// Binds are negotiated with the [`Authenticator`](crate::authenticator::Authenticator).
+///
+/// # Example
+///
+/// ```rust
+/// use std::str::FromStr;
+///
+/// use rusmpp::{
+///     CommandStatus,
+///     pdus::{SubmitSm, SubmitSmResp},
+///     types::COctetString,
+/// };
+/// use rusmpps::{handler::Handler, session::Session};
+///
+/// struct Accept;
+///
+/// impl Handler for Accept {
+///     async fn on_submit_sm(
+///         &self,
+///         _: &Session,
+///         _: SubmitSm,
+///     ) -> Result<SubmitSmResp, CommandStatus> {
+///         Ok(SubmitSmResp::builder()
+///             .message_id(COctetString::from_str("1").expect("Valid message id"))
+///             .build())
+///     }
+/// }
+/// ```
+#[allow(unused_variables)]
+pub trait Handler: Send + Sync + 'static {
+    /// Called when a session is bound.
+    ///
+    /// The session can be used to push operations to the ESME.
+    fn on_bound(&self, session: &Session) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Called when a session is closed.
+    ///
+    /// Only sessions that were bound are reported.
+    fn on_closed(&self, session: &Session) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Called when the ESME sends an [`EnquireLink`](rusmpp::Pdu::EnquireLink), before the [`EnquireLinkResp`](rusmpp::Pdu::EnquireLinkResp) is sent.
+    fn on_enquire_link(&self, session: &Session) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+
+    /// Handles a [`SubmitSm`].
+    fn on_submit_sm(
+        &self,
+        session: &Session,
+        submit_sm: SubmitSm,
+    ) -> impl Future<Output = Result<SubmitSmResp, CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`DataSm`].
+    fn on_data_sm(
+        &self,
+        session: &Session,
+        data_sm: DataSm,
+    ) -> impl Future<Output = Result<DataSmResp, CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`SubmitMulti`].
+    fn on_submit_multi(
+        &self,
+        session: &Session,
+        submit_multi: SubmitMulti,
+    ) -> impl Future<Output = Result<SubmitMultiResp, CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`QuerySm`].
+    fn on_query_sm(
+        &self,
+        session: &Session,
+        query_sm: QuerySm,
+    ) -> impl Future<Output = Result<QuerySmResp, CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`CancelSm`].
+    fn on_cancel_sm(
+        &self,
+        session: &Session,
+        cancel_sm: CancelSm,
+    ) -> impl Future<Output = Result<(), CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`ReplaceSm`].
+    fn on_replace_sm(
+        &self,
+        session: &Session,
+        replace_sm: ReplaceSm,
+    ) -> impl Future<Output = Result<(), CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`BroadcastSm`].
+    fn on_broadcast_sm(
+        &self,
+        session: &Session,
+        broadcast_sm: BroadcastSm,
+    ) -> impl Future<Output = Result<BroadcastSmResp, CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`QueryBroadcastSm`].
+    fn on_query_broadcast_sm(
+        &self,
+        session: &Session,
+        query_broadcast_sm: QueryBroadcastSm,
+    ) -> impl Future<Output = Result<QueryBroadcastSmResp, CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+
+    /// Handles a [`CancelBroadcastSm`].
+    fn on_cancel_broadcast_sm(
+        &self,
+        session: &Session,
+        cancel_broadcast_sm: CancelBroadcastSm,
+    ) -> impl Future<Output = Result<(), CommandStatus>> + Send {
+        async { Err(CommandStatus::EsmeRinvcmdid) }
+    }
+}
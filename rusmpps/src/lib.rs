@@ -1,7 +1,103 @@
-pub mod args;
+//! # Rusmpps
+//!
+//! A [`tokio`](https://docs.rs/tokio/latest/tokio/) based [SMPP v5](https://smpp.org/SMPP_v5.pdf) server (MC) and simulator.
+//!
+//! The [`Server`](server::Server) accepts connections, negotiates binds with an [`Authenticator`](authenticator::Authenticator),
+//! tracks the [`SessionState`](rusmpp::session::SessionState) of every session, sends enquire links and hands bound sessions to a [`Handler`](handler::Handler).
+//! Operations can be pushed to the ESME through the [`Session`](session::Session) handle.
+//!
+//! ## Features
+//!
+//! - `rustls`: Enables TLS support via [`rustls`](https://docs.rs/rustls/latest/rustls/). See [`ServerBuilder::rustls_config`](builder::ServerBuilder::rustls_config).
+//! - `simulator`: Enables the simulator, its configuration and the `rusmpps` binary.
+//!
+//! # Example
+//!
+//!```rust, no_run
+//! use std::{str::FromStr, time::Duration};
+//!
+//! use rusmpp::{
+//!     CommandStatus,
+//!     pdus::{DeliverSm, SubmitSm, SubmitSmResp},
+//!     types::COctetString,
+//! };
+//! use rusmpps::{builder::ServerBuilder, handler::Handler, session::Session};
+//!
+//! struct Echo;
+//!
+//! impl Handler for Echo {
+//!     async fn on_submit_sm(
+//!         &self,
+//!         session: &Session,
+//!         submit_sm: SubmitSm,
+//!     ) -> Result<SubmitSmResp, CommandStatus> {
+//!         let session = session.clone();
+//!
+//!         // Echo the message back to the ESME.
+//!         tokio::spawn(async move {
+//!             let deliver_sm = DeliverSm::builder()
+//!                 .source_addr(submit_sm.destination_addr.clone())
+//!                 .destination_addr(submit_sm.source_addr.clone())
+//!                 .short_message(submit_sm.short_message().clone())
+//!                 .build();
+//!
+//!             let _ = session.deliver_sm(deliver_sm).await;
+//!         });
+//!
+//!         Ok(SubmitSmResp::builder()
+//!             .message_id(COctetString::from_str("1").expect("Valid message id"))
+//!             .build())
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let server = ServerBuilder::new()
+//!         .system_id(COctetString::from_str("Echo")?)
+//!         // Every 5 seconds send an enquire link command to bound ESMEs.
+//!         .enquire_link_interval(Duration::from_secs(5))
+//!         // ESMEs have 10 seconds to bind.
+//!         .session_timeout(Duration::from_secs(10))
+//!         .build(Echo);
+//!
+//!     server.listen("127.0.0.1:2775").await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_debug_implementations)]
+
+pub mod acceptor;
+pub mod authenticator;
 pub mod bind_mode;
+pub mod builder;
+mod connection;
+pub mod error;
+pub mod handler;
+pub mod server;
+pub mod session;
+mod timer;
+
+#[cfg(feature = "simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simulator")))]
+pub mod args;
+#[cfg(feature = "simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simulator")))]
 pub mod client;
+#[cfg(feature = "simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simulator")))]
 pub mod config;
-pub mod connection;
-pub mod server;
-pub mod timer;
+#[cfg(feature = "simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simulator")))]
+pub mod delivery;
+#[cfg(feature = "simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simulator")))]
+pub mod message_id;
+#[cfg(feature = "simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "simulator")))]
+pub mod simulator;
+
+#[cfg(test)]
+mod tests;
@@ -2,7 +2,7 @@ use clap::Parser;
 use rusmpps::{
     args::Args,
     config::Config,
-    server::{Server, ServerParameters},
+    simulator::{Simulator, SimulatorParameters},
 };
 
 #[tokio::main]
@@ -24,23 +24,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     tracing::info!(?config);
 
-    let parameters = ServerParameters {
-        clients: vec![],
+    let parameters = SimulatorParameters {
+        clients: config.clients,
+        accept_all_binds: config.accept_all_binds,
         enquire_link_interval: config.enquire_link_interval,
         enquire_link_response_timeout: config.enquire_link_response_timeout,
         enquire_link_response_delay: config.enquire_link_response_delay,
         session_timeout: config.session_timeout,
         bind_delay: config.bind_delay,
         response_delay: config.response_delay,
+        delivery_receipt_delay: config.delivery_receipt_delay,
+        delivery_receipt_rules: config.delivery_receipt_rules,
+        mo_echo: config.mo_echo,
         socket_addr: config.socket_addr,
     };
 
-    let server = Server::new(parameters);
+    let simulator = Simulator::new(parameters);
 
     tracing::info!("Starting server");
 
     tokio::select! {
-        result = server.run() => {
+        result = simulator.run() => {
             result?;
         }
         _ = shutdown_signal() => {
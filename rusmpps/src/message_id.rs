@@ -0,0 +1,26 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Assigns unique message ids to submitted messages.
+#[derive(Debug)]
+pub struct MessageIds {
+    next: AtomicU64,
+}
+
+impl Default for MessageIds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageIds {
+    pub fn new() -> Self {
+        Self {
+            next: AtomicU64::new(1),
+        }
+    }
+
+    /// Returns the next message id, formatted as a hexadecimal string.
+    pub fn next_message_id(&self) -> String {
+        format!("{:010x}", self.next.fetch_add(1, Ordering::Relaxed))
+    }
+}
@@ -1,85 +1,200 @@
-use std::{net::SocketAddr, sync::Arc, time::Duration};
-
-use anyhow::Context;
+//! `SMPP` server.
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
-use tokio::net::TcpListener;
+use rusmpp::{types::COctetString, values::InterfaceVersion};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::{TcpListener, ToSocketAddrs},
+    sync::mpsc,
+};
 
 use crate::{
-    client::{Client, ConnectedClients},
-    connection::{Connection, ConnectionConfig},
+    acceptor::Acceptor, authenticator::Authenticator, connection::Connection, error::Error,
+    handler::Handler, session::Session,
 };
 
 #[derive(Debug)]
-pub struct ServerParameters {
-    pub clients: Vec<Client>,
-    pub enquire_link_interval: Option<Duration>,
-    pub enquire_link_response_timeout: Duration,
-    pub enquire_link_response_delay: Option<Duration>,
-    pub session_timeout: Duration,
-    pub bind_delay: Option<Duration>,
-    pub response_delay: Option<Duration>,
-    pub socket_addr: SocketAddr,
+pub(crate) struct ServerConfig {
+    pub(crate) system_id: COctetString<1, 16>,
+    pub(crate) max_command_length: usize,
+    /// Time an ESME has to bind after the connection is established.
+    pub(crate) session_timeout: Duration,
+    pub(crate) enquire_link_interval: Option<Duration>,
+    pub(crate) enquire_link_response_timeout: Duration,
+    /// Timeout for waiting for responses to operations pushed through a session.
+    pub(crate) response_timeout: Duration,
+    /// The latest interface version supported by the server.
+    pub(crate) interface_version: InterfaceVersion,
 }
 
 #[derive(Debug)]
-pub struct Server {
-    config: Arc<ConnectionConfig>,
-    socket_addr: SocketAddr,
-    session_id: u64,
+pub(crate) struct Shared<H, A, T> {
+    pub(crate) config: ServerConfig,
+    pub(crate) authenticator: A,
+    pub(crate) acceptor: T,
+    pub(crate) handler: H,
+    session_id: AtomicU64,
 }
 
-impl Server {
-    pub fn new(parameters: ServerParameters) -> Self {
-        let config = Arc::new(ConnectionConfig {
-            connected_clients: ConnectedClients::new(),
-            clients: parameters.clients,
-            enquire_link_interval: parameters.enquire_link_interval,
-            enquire_link_response_timeout: parameters.enquire_link_response_timeout,
-            session_timeout: parameters.session_timeout,
-            bind_delay: parameters.bind_delay,
-            response_delay: parameters.response_delay,
-            enquire_link_response_delay: parameters.enquire_link_response_delay,
-        });
+/// An `SMPP` server acting as an MC.
+///
+/// The server accepts connections, negotiates binds with its [`Authenticator`] and hands bound sessions to its [`Handler`].
+///
+/// The server is cheap to clone.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use std::time::Duration;
+///
+/// use rusmpps::{builder::ServerBuilder, handler::Handler};
+///
+/// struct Reject;
+///
+/// impl Handler for Reject {}
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let server = ServerBuilder::new()
+///         .enquire_link_interval(Duration::from_secs(10))
+///         .build(Reject);
+///
+///     server.listen("127.0.0.1:2775").await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Server<H, A, T> {
+    shared: Arc<Shared<H, A, T>>,
+}
 
+impl<H, A, T> Clone for Server<H, A, T> {
+    fn clone(&self) -> Self {
         Self {
-            config,
-            socket_addr: parameters.socket_addr,
-            session_id: 0,
+            shared: self.shared.clone(),
         }
     }
+}
 
-    fn next_session_id(&mut self) -> u64 {
-        let session_id = self.session_id;
-
-        self.session_id += 1;
+impl<H, A, T> Server<H, A, T>
+where
+    H: Handler,
+    A: Authenticator,
+    T: Acceptor,
+{
+    pub(crate) fn new(config: ServerConfig, authenticator: A, acceptor: T, handler: H) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                config,
+                authenticator,
+                acceptor,
+                handler,
+                session_id: AtomicU64::new(0),
+            }),
+        }
+    }
 
-        session_id
+    /// Returns the [`Handler`] of the server.
+    pub fn handler(&self) -> &H {
+        &self.shared.handler
     }
 
-    pub async fn run(mut self) -> Result<(), anyhow::Error> {
-        let listener = TcpListener::bind(self.socket_addr)
-            .await
-            .context("Failed to bind")?;
+    /// Binds a listener to `addr` and serves incoming connections.
+    ///
+    /// See [`Server::run`].
+    pub async fn listen(&self, addr: impl ToSocketAddrs) -> Result<(), Error> {
+        let listener = TcpListener::bind(addr).await.map_err(Error::Listen)?;
 
-        tracing::info!(socket_addr=%self.socket_addr, "Listening");
+        self.run(listener).await
+    }
 
-        loop {
-            let (stream, addr) = listener
-                .accept()
-                .await
-                .context("Failed to accept connection")?;
+    /// Serves incoming connections of `listener`.
+    ///
+    /// Every accepted connection is wrapped by the [`Acceptor`] and served in its own task.
+    ///
+    /// Returns only if accepting a connection fails.
+    pub async fn run(&self, listener: TcpListener) -> Result<(), Error> {
+        if let Ok(addr) = listener.local_addr() {
+            tracing::info!(%addr, "Listening");
+        }
 
-            let session_id = self.next_session_id();
+        loop {
+            let (stream, addr) = listener.accept().await.map_err(Error::Listen)?;
 
-            tracing::info!(%addr, session_id, "Accepted connection");
+            tracing::debug!(%addr, "Accepted connection");
 
-            let connection = Connection::new(session_id, self.config.clone());
+            let server = self.clone();
 
             tokio::spawn(async move {
-                connection.run(stream).await;
-
-                tracing::info!(%addr, session_id, "Connection closed");
+                match server.shared.acceptor.accept(stream).await {
+                    Ok(stream) => {
+                        server.serve(stream, Some(addr)).closed().await;
+                    }
+                    Err(err) => {
+                        tracing::warn!(%addr, ?err, "Failed to accept connection");
+                    }
+                }
             });
         }
     }
+
+    /// Serves a single connection and returns the handle of its session.
+    ///
+    /// The connection can be accepted by the MC or established by the MC itself, e.g. to send an [`Outbind`](rusmpp::pdus::Outbind).
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// # use rusmpp::pdus::Outbind;
+    /// # use rusmpps::{builder::ServerBuilder, handler::Handler};
+    /// # struct Reject;
+    /// # impl Handler for Reject {}
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let server = ServerBuilder::new().build(Reject);
+    ///
+    /// let stream = tokio::net::TcpStream::connect("127.0.0.1:2776").await?;
+    /// let peer_addr = stream.peer_addr().ok();
+    ///
+    /// // Ask the ESME to bind on the connection.
+    /// server
+    ///     .serve(stream, peer_addr)
+    ///     .outbind(Outbind::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn serve<S>(&self, stream: S, peer_addr: Option<SocketAddr>) -> Session
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+    {
+        let session_id = self.shared.session_id.fetch_add(1, Ordering::Relaxed);
+
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let session = Session::new(
+            session_id,
+            peer_addr,
+            tx,
+            self.shared.config.response_timeout,
+        );
+
+        let connection = Connection::new(session.clone(), self.shared.clone(), rx);
+
+        tokio::spawn(async move {
+            connection.run(stream).await;
+
+            tracing::debug!(session_id, "Connection closed");
+        });
+
+        session
+    }
 }
@@ -0,0 +1,262 @@
+//! Handle to a session with an ESME.
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex, OnceLock,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::Duration,
+};
+
+use rusmpp::{
+    Command, CommandStatus, Pdu,
+    pdus::{AlertNotification, DataSm, DataSmResp, DeliverSm, DeliverSmResp, Outbind},
+    session::SessionState,
+};
+use tokio::sync::{mpsc, oneshot, watch};
+
+use crate::error::Error;
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub(crate) enum Action {
+    /// Send a command as is.
+    Send(Command),
+    /// Close the connection.
+    Close,
+}
+
+/// A handle to a session with an ESME.
+///
+/// The handle is cheap to clone and can be used to push operations to the ESME.
+/// Operations are checked against the [`SessionState`] before they are sent.
+#[derive(Debug, Clone)]
+pub struct Session {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    id: u64,
+    peer_addr: Option<SocketAddr>,
+    system_id: OnceLock<String>,
+    state: watch::Sender<SessionState>,
+    sequence_number: AtomicU32,
+    pending: Mutex<HashMap<u32, oneshot::Sender<Command>>>,
+    actions: mpsc::UnboundedSender<Action>,
+    response_timeout: Duration,
+}
+
+impl Session {
+    pub(crate) fn new(
+        id: u64,
+        peer_addr: Option<SocketAddr>,
+        actions: mpsc::UnboundedSender<Action>,
+        response_timeout: Duration,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                id,
+                peer_addr,
+                system_id: OnceLock::new(),
+                state: watch::Sender::new(SessionState::Open),
+                sequence_number: AtomicU32::new(1),
+                pending: Mutex::new(HashMap::new()),
+                actions,
+                response_timeout,
+            }),
+        }
+    }
+
+    /// Returns the id of the session, unique within a [`Server`](crate::server::Server).
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    /// Returns the address of the ESME, if known.
+    pub fn peer_addr(&self) -> Option<SocketAddr> {
+        self.inner.peer_addr
+    }
+
+    /// Returns the `system_id` the ESME is bound with.
+    ///
+    /// Returns `None` if the session was never bound.
+    pub fn system_id(&self) -> Option<&str> {
+        self.inner.system_id.get().map(String::as_str)
+    }
+
+    /// Returns the current session state.
+    pub fn session_state(&self) -> SessionState {
+        *self.inner.state.borrow()
+    }
+
+    /// Checks if the session is closed.
+    pub fn is_closed(&self) -> bool {
+        self.inner.actions.is_closed()
+    }
+
+    /// Completes when the session is closed.
+    pub async fn closed(&self) {
+        self.inner.actions.closed().await
+    }
+
+    /// Sends a [`DeliverSm`] to the ESME and waits for a successful [`DeliverSmResp`].
+    pub async fn deliver_sm(
+        &self,
+        deliver_sm: impl Into<DeliverSm>,
+    ) -> Result<DeliverSmResp, Error> {
+        let response = self.request(Pdu::DeliverSm(deliver_sm.into())).await?;
+
+        match response.into_parts().raw().3 {
+            Some(Pdu::DeliverSmResp(response)) => Ok(response),
+            _ => Ok(DeliverSmResp::default()),
+        }
+    }
+
+    /// Sends a [`DataSm`] to the ESME and waits for a successful [`DataSmResp`].
+    pub async fn data_sm(&self, data_sm: impl Into<DataSm>) -> Result<DataSmResp, Error> {
+        let response = self.request(Pdu::DataSm(data_sm.into())).await?;
+
+        match response.into_parts().raw().3 {
+            Some(Pdu::DataSmResp(response)) => Ok(response),
+            _ => Ok(DataSmResp::default()),
+        }
+    }
+
+    /// Sends an [`AlertNotification`] to the ESME.
+    ///
+    /// [`AlertNotification`] has no response.
+    pub async fn alert_notification(
+        &self,
+        alert_notification: impl Into<AlertNotification>,
+    ) -> Result<(), Error> {
+        self.send_operation(Pdu::AlertNotification(alert_notification.into()))
+            .map(|_| ())
+    }
+
+    /// Sends an [`Outbind`] to the ESME.
+    ///
+    /// An [`Outbind`] can only be sent on an open session, i.e. a connection that was established by the MC and is not bound yet.
+    /// The session moves to [`SessionState::Outbound`] and waits for the ESME to bind. See [`Server::serve`](crate::server::Server::serve).
+    ///
+    /// [`Outbind`] has no response.
+    pub async fn outbind(&self, outbind: impl Into<Outbind>) -> Result<(), Error> {
+        self.send_operation(Pdu::Outbind(outbind.into()))
+            .map(|_| ())
+    }
+
+    /// Sends an [`Unbind`](Pdu::Unbind) to the ESME, waits for the [`UnbindResp`](Pdu::UnbindResp) and closes the session.
+    pub async fn unbind(&self) -> Result<(), Error> {
+        let result = self.request(Pdu::Unbind).await.map(|_| ());
+
+        self.close();
+
+        result
+    }
+
+    /// Closes the session.
+    ///
+    /// Pending requests fail with [`Error::SessionClosed`].
+    pub fn close(&self) {
+        let _ = self.inner.actions.send(Action::Close);
+    }
+
+    /// Sends an operation with the next sequence number of the session and returns its sequence number.
+    fn send_operation(&self, pdu: Pdu) -> Result<u32, Error> {
+        let id = pdu.command_id();
+        let state = self.session_state();
+
+        if !state.can_send_as_mc(id) {
+            return Err(Error::invalid_session_state(id, state));
+        }
+
+        let sequence_number = self.next_sequence_number();
+
+        self.send(Command::new(CommandStatus::EsmeRok, sequence_number, pdu))?;
+
+        Ok(sequence_number)
+    }
+
+    /// Sends an operation and waits for a successful response.
+    async fn request(&self, pdu: Pdu) -> Result<Command, Error> {
+        let id = pdu.command_id();
+        let state = self.session_state();
+
+        if !state.can_send_as_mc(id) {
+            return Err(Error::invalid_session_state(id, state));
+        }
+
+        let sequence_number = self.next_sequence_number();
+
+        let (tx, rx) = oneshot::channel();
+
+        self.pending().insert(sequence_number, tx);
+
+        if let Err(err) = self.send(Command::new(CommandStatus::EsmeRok, sequence_number, pdu)) {
+            self.pending().remove(&sequence_number);
+
+            return Err(err);
+        }
+
+        let timeout = self.inner.response_timeout;
+
+        let response = match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => return Err(Error::SessionClosed),
+            Err(_) => {
+                self.pending().remove(&sequence_number);
+
+                return Err(Error::response_timeout(sequence_number, timeout));
+            }
+        };
+
+        if response.status() != CommandStatus::EsmeRok || response.id() != id.matching_response() {
+            return Err(Error::unexpected_response(response));
+        }
+
+        Ok(response)
+    }
+
+    pub(crate) fn send(&self, command: Command) -> Result<(), Error> {
+        self.inner
+            .actions
+            .send(Action::Send(command))
+            .map_err(|_| Error::SessionClosed)
+    }
+
+    pub(crate) fn next_sequence_number(&self) -> u32 {
+        self.inner.sequence_number.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_session_state(&self, state: SessionState) {
+        self.inner.state.send_replace(state);
+    }
+
+    pub(crate) fn set_system_id(&self, system_id: String) {
+        let _ = self.inner.system_id.set(system_id);
+    }
+
+    /// Passes a response to the request waiting for it.
+    ///
+    /// Returns `false` if no request is waiting for it.
+    pub(crate) fn resolve(&self, response: Command) -> bool {
+        match self.pending().remove(&response.sequence_number()) {
+            Some(tx) => tx.send(response).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Fails all pending requests with [`Error::SessionClosed`].
+    pub(crate) fn clear_pending(&self) {
+        self.pending().clear();
+    }
+
+    fn pending(&self) -> std::sync::MutexGuard<'_, HashMap<u32, oneshot::Sender<Command>>> {
+        self.inner
+            .pending
+            .lock()
+            .expect("Pending responses lock poisoned")
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    net::SocketAddr,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use rusmpp::{
+    CommandStatus,
+    pdus::{SubmitSm, SubmitSmResp},
+    types::COctetString,
+};
+
+use crate::{
+    authenticator::{Authenticator, BindRequest},
+    builder::ServerBuilder,
+    client::{Client, ConnectedClients},
+    delivery::{self, DeliveryReceiptRule},
+    error::Error,
+    handler::Handler,
+    message_id::MessageIds,
+    session::Session,
+};
+
+#[derive(Debug)]
+pub struct SimulatorParameters {
+    pub clients: Vec<Client>,
+    pub accept_all_binds: bool,
+    pub enquire_link_interval: Option<Duration>,
+    pub enquire_link_response_timeout: Duration,
+    pub enquire_link_response_delay: Option<Duration>,
+    pub session_timeout: Duration,
+    pub bind_delay: Option<Duration>,
+    pub response_delay: Option<Duration>,
+    pub delivery_receipt_delay: Option<Duration>,
+    pub delivery_receipt_rules: Vec<DeliveryReceiptRule>,
+    pub mo_echo: bool,
+    pub socket_addr: SocketAddr,
+}
+
+/// An SMPP simulator built on top of [`Server`](crate::server::Server).
+#[derive(Debug)]
+pub struct Simulator {
+    parameters: SimulatorParameters,
+}
+
+impl Simulator {
+    pub fn new(parameters: SimulatorParameters) -> Self {
+        Self { parameters }
+    }
+
+    pub async fn run(self) -> Result<(), Error> {
+        let parameters = self.parameters;
+
+        if parameters.clients.is_empty() && !parameters.accept_all_binds {
+            tracing::warn!("No clients configured, every bind is rejected");
+        }
+
+        let authenticator = ClientsAuthenticator {
+            clients: parameters.clients,
+            accept_all_binds: parameters.accept_all_binds,
+            bind_delay: parameters.bind_delay,
+        };
+
+        let handler = SimulatorHandler {
+            connected_clients: ConnectedClients::new(),
+            message_ids: MessageIds::new(),
+            response_delay: parameters.response_delay,
+            enquire_link_response_delay: parameters.enquire_link_response_delay,
+            delivery_receipt_delay: parameters.delivery_receipt_delay,
+            delivery_receipt_rules: parameters.delivery_receipt_rules,
+            mo_echo: parameters.mo_echo,
+        };
+
+        let mut builder = ServerBuilder::new()
+            .system_id(COctetString::from_str("Rusmpps").expect("Must be valid system ID"))
+            .max_command_length(1024)
+            .session_timeout(parameters.session_timeout)
+            .enquire_link_response_timeout(parameters.enquire_link_response_timeout)
+            .authenticator(authenticator);
+
+        builder = match parameters.enquire_link_interval {
+            Some(interval) => builder.enquire_link_interval(interval),
+            None => builder.no_enquire_link_interval(),
+        };
+
+        builder
+            .build(Arc::new(handler))
+            .listen(parameters.socket_addr)
+            .await
+    }
+}
+
+/// Authenticates binds against the configured clients, unless every bind is accepted.
+#[derive(Debug)]
+struct ClientsAuthenticator {
+    clients: Vec<Client>,
+    accept_all_binds: bool,
+    bind_delay: Option<Duration>,
+}
+
+impl Authenticator for ClientsAuthenticator {
+    async fn authenticate(&self, bind: &BindRequest) -> CommandStatus {
+        if let Some(delay) = self.bind_delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        if self.accept_all_binds {
+            return CommandStatus::EsmeRok;
+        }
+
+        let system_id = bind.system_id.to_string();
+
+        match self
+            .clients
+            .iter()
+            .find(|client| client.system_id == system_id)
+        {
+            None => CommandStatus::EsmeRinvsysid,
+            Some(client) if client.password != bind.password.to_string() => {
+                CommandStatus::EsmeRinvpaswd
+            }
+            Some(_) => CommandStatus::EsmeRok,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SimulatorHandler {
+    connected_clients: ConnectedClients,
+    message_ids: MessageIds,
+    response_delay: Option<Duration>,
+    enquire_link_response_delay: Option<Duration>,
+    delivery_receipt_delay: Option<Duration>,
+    delivery_receipt_rules: Vec<DeliveryReceiptRule>,
+    mo_echo: bool,
+}
+
+impl Handler for Arc<SimulatorHandler> {
+    async fn on_bound(&self, session: &Session) {
+        if let Some(system_id) = session.system_id() {
+            self.connected_clients
+                .insert_session(system_id.to_string(), session.clone())
+                .await;
+        }
+    }
+
+    async fn on_closed(&self, session: &Session) {
+        if let Some(system_id) = session.system_id() {
+            self.connected_clients
+                .remove_session(system_id, session.id())
+                .await;
+        }
+    }
+
+    async fn on_enquire_link(&self, _: &Session) {
+        if let Some(delay) = self.enquire_link_response_delay {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn on_submit_sm(
+        &self,
+        session: &Session,
+        submit_sm: SubmitSm,
+    ) -> Result<SubmitSmResp, CommandStatus> {
+        if let Some(delay) = self.response_delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let message_id = self.message_ids.next_message_id();
+
+        if let Some(system_id) = session.system_id() {
+            self.deliver(system_id.to_string(), message_id.clone(), submit_sm);
+        }
+
+        Ok(SubmitSmResp::builder()
+            .message_id(
+                COctetString::from_str(&message_id)
+                    .expect("Message id must be a valid COctetString"),
+            )
+            .build())
+    }
+}
+
+impl SimulatorHandler {
+    /// Sends the mobile originated echo and the delivery receipt of a submitted message, if configured and requested.
+    fn deliver(self: &Arc<Self>, system_id: String, message_id: String, submit_sm: SubmitSm) {
+        let this = self.clone();
+        let submit_date = SystemTime::now();
+
+        tokio::spawn(async move {
+            if let Some(delay) = this.delivery_receipt_delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            if this.mo_echo {
+                this.connected_clients
+                    .deliver_sm(&system_id, delivery::mo(&submit_sm))
+                    .await;
+            }
+
+            let state = delivery::final_state(&this.delivery_receipt_rules, &submit_sm);
+
+            if let Some(receipt) = delivery::receipt(
+                &message_id,
+                &submit_sm,
+                state,
+                submit_date,
+                SystemTime::now(),
+            ) {
+                tracing::debug!(system_id, message_id, ?state, "Sending delivery receipt");
+
+                this.connected_clients.deliver_sm(&system_id, receipt).await;
+            }
+        });
+    }
+}
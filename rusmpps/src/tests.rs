@@ -0,0 +1,522 @@
+//! Tests in this module test the server based on the public API.
+//!
+//! They create in-memory connections between a server and a `rusmppc` client.
+
+use std::{str::FromStr, time::Duration};
+
+use futures::{SinkExt, StreamExt};
+use rusmpp::{
+    Command, CommandId, CommandStatus, Pdu,
+    pdus::{
+        BindReceiver, BindTransceiver, BindTransmitter, DeliverSm, DeliverSmResp, Outbind, QuerySm,
+        SubmitSm, SubmitSmResp,
+    },
+    session::SessionState,
+    tokio_codec::CommandCodec,
+    types::COctetString,
+    values::InterfaceVersion,
+};
+use rusmppc::{ConnectionBuilder, Event, error::Error as ClientError};
+use tokio::{io::AsyncWriteExt, sync::mpsc};
+use tokio_util::codec::Framed;
+
+use crate::{
+    authenticator::{Authenticator, BindRequest},
+    builder::ServerBuilder,
+    error::Error,
+    handler::Handler,
+    session::Session,
+};
+
+#[derive(Debug)]
+struct TestHandler {
+    bound: mpsc::UnboundedSender<Session>,
+    closed: mpsc::UnboundedSender<Session>,
+}
+
+impl Handler for TestHandler {
+    async fn on_bound(&self, session: &Session) {
+        let _ = self.bound.send(session.clone());
+    }
+
+    async fn on_closed(&self, session: &Session) {
+        let _ = self.closed.send(session.clone());
+    }
+
+    async fn on_submit_sm(
+        &self,
+        _: &Session,
+        submit_sm: SubmitSm,
+    ) -> Result<SubmitSmResp, CommandStatus> {
+        Ok(SubmitSmResp::builder()
+            .message_id(
+                COctetString::from_str(&submit_sm.destination_addr.to_string())
+                    .expect("Valid message id"),
+            )
+            .build())
+    }
+}
+
+#[derive(Debug)]
+struct Password;
+
+impl Authenticator for Password {
+    async fn authenticate(&self, bind: &BindRequest) -> CommandStatus {
+        if bind.password.to_string() == "secret" {
+            return CommandStatus::EsmeRok;
+        }
+
+        CommandStatus::EsmeRinvpaswd
+    }
+}
+
+struct Harness {
+    session: Session,
+    client: rusmppc::Client,
+    events: std::pin::Pin<Box<dyn futures::Stream<Item = Event> + Send>>,
+    bound: mpsc::UnboundedReceiver<Session>,
+    closed: mpsc::UnboundedReceiver<Session>,
+}
+
+fn harness(builder: ServerBuilder<Password>) -> Harness {
+    let (server_stream, client_stream) = tokio::io::duplex(4096);
+
+    let (bound_tx, bound) = mpsc::unbounded_channel();
+    let (closed_tx, closed) = mpsc::unbounded_channel();
+
+    let server = builder.build(TestHandler {
+        bound: bound_tx,
+        closed: closed_tx,
+    });
+
+    let session = server.serve(server_stream, None);
+
+    let (client, events) = ConnectionBuilder::new()
+        .no_enquire_link_interval()
+        // Let the server check the session state.
+        .disable_session_state_check()
+        .connected(client_stream);
+
+    Harness {
+        session,
+        client,
+        events: Box::pin(events),
+        bound,
+        closed,
+    }
+}
+
+fn builder() -> ServerBuilder<Password> {
+    ServerBuilder::new()
+        .system_id(COctetString::from_str("Server").unwrap())
+        .no_enquire_link_interval()
+        .authenticator(Password)
+}
+
+fn bind_transceiver(password: &str) -> BindTransceiver {
+    BindTransceiver::builder()
+        .system_id(COctetString::from_str("client").unwrap())
+        .password(COctetString::from_str(password).unwrap())
+        .build()
+}
+
+#[tokio::test]
+async fn bind_and_submit_sm() {
+    let mut harness = harness(builder());
+
+    let response = harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.system_id.to_string(), "Server");
+
+    let session = harness.bound.recv().await.unwrap();
+
+    assert_eq!(session.id(), harness.session.id());
+    assert_eq!(session.system_id(), Some("client"));
+    assert_eq!(session.session_state(), SessionState::BoundTrx);
+
+    let response = harness
+        .client
+        .submit_sm(
+            SubmitSm::builder()
+                .destination_addr(COctetString::from_str("1234").unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.message_id().to_string(), "1234");
+}
+
+#[tokio::test]
+async fn bind_rejected() {
+    let mut harness = harness(builder());
+
+    let err = harness
+        .client
+        .bind_transceiver(bind_transceiver("wrong"))
+        .await
+        .unwrap_err();
+
+    let ClientError::UnexpectedResponse { response } = err else {
+        panic!("Expected UnexpectedResponse, got: {err:?}");
+    };
+
+    assert_eq!(response.status(), CommandStatus::EsmeRinvpaswd);
+
+    harness.session.closed().await;
+
+    assert!(harness.bound.try_recv().is_err());
+    assert!(harness.closed.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn bind_response_interface_version() {
+    for (server, esme, expected) in [
+        (
+            InterfaceVersion::Smpp5_0,
+            InterfaceVersion::Smpp5_0,
+            InterfaceVersion::Smpp5_0,
+        ),
+        (
+            InterfaceVersion::Smpp5_0,
+            InterfaceVersion::Smpp3_4,
+            InterfaceVersion::Smpp3_4,
+        ),
+        (
+            InterfaceVersion::Smpp3_4,
+            InterfaceVersion::Smpp5_0,
+            InterfaceVersion::Smpp3_4,
+        ),
+    ] {
+        let harness = harness(builder().interface_version(server));
+
+        let response = harness
+            .client
+            .bind_transceiver(
+                BindTransceiver::builder()
+                    .password(COctetString::from_str("secret").unwrap())
+                    .interface_version(esme)
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.sc_interface_version(), Some(expected));
+    }
+}
+
+#[tokio::test]
+async fn bind_twice_is_rejected_with_bind_response() {
+    let harness = harness(builder());
+
+    harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap();
+
+    let err = harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap_err();
+
+    let ClientError::UnexpectedResponse { response } = err else {
+        panic!("Expected UnexpectedResponse, got: {err:?}");
+    };
+
+    assert_eq!(response.id(), CommandId::BindTransceiverResp);
+    assert_eq!(response.status(), CommandStatus::EsmeRalybnd);
+
+    // The session is still bound
+    harness
+        .client
+        .submit_sm(
+            SubmitSm::builder()
+                .destination_addr(COctetString::from_str("1234").unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn unimplemented_operation_is_rejected() {
+    let harness = harness(builder());
+
+    harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap();
+
+    let err = harness
+        .client
+        .query_sm(QuerySm::default())
+        .await
+        .unwrap_err();
+
+    let ClientError::UnexpectedResponse { response } = err else {
+        panic!("Expected UnexpectedResponse, got: {err:?}");
+    };
+
+    assert_eq!(response.id(), CommandId::QuerySmResp);
+    assert_eq!(response.status(), CommandStatus::EsmeRinvcmdid);
+}
+
+#[tokio::test]
+async fn operation_not_allowed_before_bind() {
+    let harness = harness(builder());
+
+    let err = harness.client.submit_sm(SubmitSm::default()).await;
+
+    let Err(ClientError::GenericNack { status, .. }) = err else {
+        panic!("Expected GenericNack, got: {err:?}");
+    };
+
+    assert_eq!(status, CommandStatus::EsmeRinvbndsts);
+}
+
+#[tokio::test]
+async fn malformed_operation_is_nacked() {
+    let (server_stream, mut client_stream) = tokio::io::duplex(4096);
+
+    let (bound, _) = mpsc::unbounded_channel();
+    let (closed, _) = mpsc::unbounded_channel();
+
+    let _session = builder()
+        .build(TestHandler { bound, closed })
+        .serve(server_stream, None);
+
+    // A bind_transceiver with a valid header and a body that is not null terminated
+    let mut malformed = Vec::new();
+    malformed.extend_from_slice(&19u32.to_be_bytes());
+    malformed.extend_from_slice(&u32::from(CommandId::BindTransceiver).to_be_bytes());
+    malformed.extend_from_slice(&u32::from(CommandStatus::EsmeRok).to_be_bytes());
+    malformed.extend_from_slice(&1u32.to_be_bytes());
+    malformed.extend_from_slice(b"abc");
+
+    client_stream.write_all(&malformed).await.unwrap();
+
+    let mut framed = Framed::new(client_stream, CommandCodec::new());
+
+    let command = framed.next().await.unwrap().unwrap();
+
+    assert_eq!(command.id(), CommandId::GenericNack);
+    assert_eq!(command.status(), CommandStatus::EsmeRsyserr);
+    assert_eq!(command.sequence_number(), 1);
+
+    // The session is still running
+    framed
+        .send(Command::new(
+            CommandStatus::EsmeRok,
+            2,
+            bind_transceiver("secret"),
+        ))
+        .await
+        .unwrap();
+
+    let command = framed.next().await.unwrap().unwrap();
+
+    assert_eq!(command.id(), CommandId::BindTransceiverResp);
+    assert_eq!(command.status(), CommandStatus::EsmeRok);
+    assert_eq!(command.sequence_number(), 2);
+}
+
+#[tokio::test]
+async fn deliver_sm() {
+    let mut harness = harness(builder());
+
+    harness
+        .client
+        .bind_receiver(
+            BindReceiver::builder()
+                .password(COctetString::from_str("secret").unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let session = harness.bound.recv().await.unwrap();
+
+    let deliver = tokio::spawn(async move { session.deliver_sm(DeliverSm::default()).await });
+
+    let sequence_number = loop {
+        match harness.events.next().await.unwrap() {
+            Event::Incoming(command) if command.id() == CommandId::DeliverSm => {
+                break command.sequence_number();
+            }
+            _ => {}
+        }
+    };
+
+    harness
+        .client
+        .deliver_sm_resp(sequence_number, DeliverSmResp::default())
+        .await
+        .unwrap();
+
+    deliver.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn deliver_sm_not_allowed_for_transmitter() {
+    let mut harness = harness(builder());
+
+    harness
+        .client
+        .bind_transmitter(
+            BindTransmitter::builder()
+                .password(COctetString::from_str("secret").unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let session = harness.bound.recv().await.unwrap();
+
+    let err = session.deliver_sm(DeliverSm::default()).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::InvalidSessionState {
+            id: CommandId::DeliverSm,
+            state: SessionState::BoundTx
+        }
+    ));
+}
+
+#[tokio::test]
+async fn deliver_sm_response_timeout() {
+    let mut harness = harness(builder().response_timeout(Duration::from_millis(100)));
+
+    harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap();
+
+    let session = harness.bound.recv().await.unwrap();
+
+    let err = session.deliver_sm(DeliverSm::default()).await.unwrap_err();
+
+    assert!(matches!(err, Error::ResponseTimeout { .. }));
+}
+
+#[tokio::test]
+async fn unbind_by_client() {
+    let mut harness = harness(builder());
+
+    harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap();
+
+    harness.client.unbind().await.unwrap();
+
+    let session = harness.closed.recv().await.unwrap();
+
+    assert!(session.is_closed());
+    assert_eq!(session.session_state(), SessionState::Closed);
+}
+
+#[tokio::test]
+async fn unbind_by_server() {
+    let mut harness = harness(builder());
+
+    harness
+        .client
+        .bind_transceiver(bind_transceiver("secret"))
+        .await
+        .unwrap();
+
+    let session = harness.bound.recv().await.unwrap();
+
+    let unbind = tokio::spawn(async move { session.unbind().await });
+
+    let sequence_number = loop {
+        match harness.events.next().await.unwrap() {
+            Event::Incoming(command) if command.id() == CommandId::Unbind => {
+                break command.sequence_number();
+            }
+            _ => {}
+        }
+    };
+
+    harness.client.unbind_resp(sequence_number).await.unwrap();
+
+    unbind.await.unwrap().unwrap();
+
+    harness.closed.recv().await.unwrap();
+}
+
+#[tokio::test]
+async fn session_timeout() {
+    let harness = harness(builder().session_timeout(Duration::from_millis(100)));
+
+    tokio::time::timeout(Duration::from_secs(1), harness.session.closed())
+        .await
+        .expect("Session should be closed");
+}
+
+#[tokio::test]
+async fn enquire_link_only_sent_to_bound_sessions() {
+    let (server_stream, _client_stream) = tokio::io::duplex(4096);
+
+    let (bound, _) = mpsc::unbounded_channel();
+    let (closed, mut closed_rx) = mpsc::unbounded_channel();
+
+    let session = ServerBuilder::new()
+        .enquire_link_interval(Duration::from_millis(50))
+        .enquire_link_response_timeout(Duration::from_millis(50))
+        .build(TestHandler { bound, closed })
+        .serve(server_stream, None);
+
+    // The session would be closed by the enquire link response timeout if it was bound.
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    assert!(!session.is_closed());
+    assert!(closed_rx.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn outbind() {
+    let mut harness = harness(builder());
+
+    harness
+        .session
+        .outbind(
+            Outbind::builder()
+                .system_id(COctetString::from_str("Server").unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    loop {
+        if let Event::Incoming(command) = harness.events.next().await.unwrap() {
+            assert!(matches!(command.pdu(), Some(Pdu::Outbind(_))));
+
+            break;
+        }
+    }
+
+    harness
+        .client
+        .bind_receiver(
+            BindReceiver::builder()
+                .password(COctetString::from_str("secret").unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let session = harness.bound.recv().await.unwrap();
+
+    assert_eq!(session.session_state(), SessionState::BoundRx);
+}
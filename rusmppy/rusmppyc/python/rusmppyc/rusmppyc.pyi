@@ -2912,6 +2912,18 @@ class Event:
         def __len__(self) -> builtins.int: ...
         def __getitem__(self, key: builtins.int) -> typing.Any: ...
     
+    @typing.final
+    class Reconnect(Event):
+        r"""
+        A reconnect event.
+        """
+        __match_args__ = ("_0",)
+        @property
+        def _0(self) -> Reconnect: ...
+        def __new__(cls, _0: Reconnect) -> Event.Reconnect: ...
+        def __len__(self) -> builtins.int: ...
+        def __getitem__(self, key: builtins.int) -> typing.Any: ...
+    
     ...
 
 class Gsm7BitAlphabet:
@@ -5170,6 +5182,59 @@ class QuerySmResp:
     def default(cls) -> QuerySmResp: ...
     def __repr__(self) -> builtins.str: ...
 
+class Reconnect:
+    r"""
+    Reconnect event.
+    """
+    def __repr__(self) -> builtins.str: ...
+    @typing.final
+    class Reconnecting(Reconnect):
+        r"""
+        The connection was lost, the client will try to reconnect after the given delay.
+        """
+        __match_args__ = ("attempt", "delay",)
+        @property
+        def attempt(self) -> builtins.int: ...
+        @property
+        def delay(self) -> builtins.str: ...
+        def __new__(cls, attempt: builtins.int, delay: builtins.str) -> Reconnect.Reconnecting: ...
+    
+    @typing.final
+    class Reconnected(Reconnect):
+        r"""
+        The client reconnected to the server.
+        """
+        __match_args__ = ("attempt",)
+        @property
+        def attempt(self) -> builtins.int: ...
+        def __new__(cls, attempt: builtins.int) -> Reconnect.Reconnected: ...
+    
+    @typing.final
+    class Rebound(Reconnect):
+        r"""
+        The client replayed the last successful bind command and the server accepted it.
+        """
+        __match_args__ = ("response",)
+        @property
+        def response(self) -> Command: ...
+        def __new__(cls, response: Command) -> Reconnect.Rebound: ...
+    
+    @typing.final
+    class Other(Reconnect):
+        r"""
+        Other reconnect event.
+        
+        Rusmppc reconnect event type is non-exhaustive.
+        This event should not be returned by this library and if so it should be considered a bug.
+        """
+        __match_args__ = ("_0",)
+        @property
+        def _0(self) -> builtins.str: ...
+        def __new__(cls, _0: builtins.str) -> Reconnect.Other: ...
+        def __len__(self) -> builtins.int: ...
+        def __getitem__(self, key: builtins.int) -> typing.Any: ...
+    
+
 @typing.final
 class RegisteredDelivery:
     @property
@@ -1,11 +1,12 @@
 use std::{pin::Pin, sync::Arc};
 
 use futures::{Stream, StreamExt};
+use humantime::format_duration;
 use pyo3::{
     exceptions::PyStopAsyncIteration, pyclass, pymethods, Bound, PyAny, PyRef, PyResult, Python,
 };
 use pyo3_async_runtimes::tokio::future_into_py;
-use pyo3_stub_gen_derive::gen_stub_pyclass_complex_enum;
+use pyo3_stub_gen_derive::{gen_stub_pyclass_complex_enum, gen_stub_pymethods};
 use tokio::sync::RwLock;
 
 use crate::error::Error;
@@ -21,6 +22,8 @@ pub enum Event {
     Incoming(crate::generated::Command),
     /// An error occurred.
     Error(Error),
+    /// A reconnect event.
+    Reconnect(Reconnect),
 }
 
 impl From<rusmppc::Event> for Event {
@@ -30,10 +33,54 @@ impl From<rusmppc::Event> for Event {
                 Event::Incoming(crate::generated::Command::from(command))
             }
             rusmppc::Event::Error(error) => Event::Error(Error::from(error)),
+            rusmppc::Event::Reconnect(reconnect) => Event::Reconnect(Reconnect::from(reconnect)),
         }
     }
 }
 
+/// Reconnect event.
+#[pyclass]
+#[gen_stub_pyclass_complex_enum]
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum Reconnect {
+    /// The connection was lost, the client will try to reconnect after the given delay.
+    Reconnecting { attempt: u32, delay: String },
+    /// The client reconnected to the server.
+    Reconnected { attempt: u32 },
+    /// The client replayed the last successful bind command and the server accepted it.
+    Rebound { response: crate::generated::Command },
+    /// Other reconnect event.
+    ///
+    /// Rusmppc reconnect event type is non-exhaustive.
+    /// This event should not be returned by this library and if so it should be considered a bug.
+    Other(String),
+}
+
+impl From<rusmppc::Reconnect> for Reconnect {
+    fn from(reconnect: rusmppc::Reconnect) -> Self {
+        match reconnect {
+            rusmppc::Reconnect::Reconnecting { attempt, delay } => Reconnect::Reconnecting {
+                attempt,
+                delay: format_duration(delay).to_string(),
+            },
+            rusmppc::Reconnect::Reconnected { attempt } => Reconnect::Reconnected { attempt },
+            rusmppc::Reconnect::Rebound { response } => Reconnect::Rebound {
+                response: crate::generated::Command::from(response),
+            },
+            _ => Reconnect::Other(format!("{reconnect:?}")),
+        }
+    }
+}
+
+#[pymethods]
+#[gen_stub_pymethods]
+impl Reconnect {
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
 /// An async stream of `Event`s.
 ///
 /// This class represents a stream of events that can be iterated over asynchronously using `async for`.
@@ -25,6 +25,7 @@ fn rusmppyc(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<client::Client>()?;
     m.add_class::<event::Event>()?;
     m.add_class::<event::Events>()?;
+    m.add_class::<event::Reconnect>()?;
     m.add_class::<error::Error>()?;
 
     m.add_class::<encoder::Encoder>()?;